@@ -0,0 +1,613 @@
+//! Autocovariance, autocorrelation and related diagnostics of time series.
+use crate::errors::EmptyInput;
+use crate::fft::lagged_products;
+use crate::special::chi_squared_sf;
+use ndarray::{Array, Array1, ArrayBase, Axis, Data, Dimension, Ix1, RemoveAxis, Zip};
+use num_traits::{Float, FromPrimitive};
+
+/// How the lagged sums of products are normalised when estimating the
+/// autocovariance of a series of length `n` at lag `k`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AutocovarianceNormalization {
+    /// Divide by `n` for every lag.
+    ///
+    /// The resulting estimator is biased, but the autocovariance sequence is
+    /// guaranteed to be positive semi-definite and has a lower mean squared error
+    /// for large lags. This is the usual choice for correlograms.
+    Biased,
+    /// Divide by `n - k`, the number of terms in the sum at lag `k`.
+    Unbiased,
+}
+
+/// The outcome of a [portmanteau test] (e.g. Ljung–Box) performed
+/// on every series of an array.
+///
+/// [portmanteau test]: https://en.wikipedia.org/wiki/Portmanteau_test
+#[derive(Clone, Debug, PartialEq)]
+pub struct PortmanteauTest<A, D: Dimension> {
+    /// The test statistic of each series.
+    pub statistic: Array<A, D>,
+    /// The p-value of each series under the null hypothesis of no autocorrelation,
+    /// i.e. the probability of observing a statistic at least as large as the
+    /// one computed if the series were white noise.
+    pub p_value: Array<A, D>,
+}
+
+/// Extension trait for `ArrayBase` providing methods to compute
+/// the autocovariance and autocorrelation of time series.
+pub trait AutocorrelationExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Returns the [autocovariance] of every series along `axis`,
+    /// for all lags from `0` to `max_lag` (extremes included).
+    ///
+    /// Each lane of the array along `axis` is a time series *x* of length *n*;
+    /// its autocovariance at lag *k* is defined as
+    ///
+    /// ```text
+    ///           1   n-k-1
+    /// γ(k) =  ―――――   ∑   (xₜ₊ₖ - x̅)(xₜ - x̅)
+    ///           m    t=0
+    /// ```
+    ///
+    /// where `m = n` if `normalization` is `AutocovarianceNormalization::Biased` and
+    /// `m = n - k` if it is `AutocovarianceNormalization::Unbiased`.
+    ///
+    /// The returned array has the same shape as `self`, except for the length
+    /// along `axis`, which is `max_lag + 1`: the `k`-th element along `axis`
+    /// is the autocovariance at lag `k`.
+    ///
+    /// The lagged sums are computed directly, in *O(n · max_lag)* time, when
+    /// `max_lag` is small; for larger lags they are computed using a fast
+    /// Fourier transform, in *O(n log n)* time.
+    ///
+    /// If the length of the array along `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `max_lag` is greater than or equal
+    /// to the length of the array along `axis` or if `A::from_usize()` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{AutocorrelationExt, AutocovarianceNormalization};
+    /// use ndarray::Axis;
+    ///
+    /// let a = array![[1., 2., 3., 4.],
+    ///                [4., 3., 2., 1.]];
+    /// let acov = a
+    ///     .autocovariance_axis(Axis(1), 2, AutocovarianceNormalization::Biased)
+    ///     .unwrap();
+    /// assert_eq!(acov, array![[1.25, 0.3125, -0.375],
+    ///                         [1.25, 0.3125, -0.375]]);
+    /// ```
+    ///
+    /// [autocovariance]: https://en.wikipedia.org/wiki/Autocovariance
+    fn autocovariance_axis(
+        &self,
+        axis: Axis,
+        max_lag: usize,
+        normalization: AutocovarianceNormalization,
+    ) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the [autocorrelation] of every series along `axis`,
+    /// for all lags from `0` to `max_lag` (extremes included).
+    ///
+    /// The autocorrelation at lag *k* is the autocovariance at lag *k*
+    /// (see [`autocovariance_axis`]) divided by the autocovariance at lag 0
+    /// (i.e. the variance of the series):
+    ///
+    /// ```text
+    ///        γ(k)
+    /// ρ(k) = ――――
+    ///        γ(0)
+    /// ```
+    ///
+    /// hence the first element along `axis` is always 1. The autocorrelation
+    /// of a constant series is NaN for every lag.
+    ///
+    /// The returned array has the same shape as `self`, except for the length
+    /// along `axis`, which is `max_lag + 1`.
+    ///
+    /// If the length of the array along `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `max_lag` is greater than or equal
+    /// to the length of the array along `axis` or if `A::from_usize()` fails.
+    ///
+    /// [autocorrelation]: https://en.wikipedia.org/wiki/Autocorrelation#Estimation
+    /// [`autocovariance_axis`]: #tymethod.autocovariance_axis
+    fn autocorrelation_axis(
+        &self,
+        axis: Axis,
+        max_lag: usize,
+        normalization: AutocovarianceNormalization,
+    ) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// Returns the [partial autocorrelation] of every series along `axis`,
+    /// for all lags from `0` to `max_lag` (extremes included).
+    ///
+    /// The partial autocorrelation at lag *k*, φₖₖ, is the correlation between
+    /// *xₜ₊ₖ* and *xₜ* once the linear dependence on *xₜ₊₁, …, xₜ₊ₖ₋₁*
+    /// has been removed. It is computed from the (biased) autocorrelation *ρ*
+    /// using the [Durbin–Levinson recursion]:
+    ///
+    /// ```text
+    ///        ρ(k) - ∑ⱼ φₖ₋₁,ⱼ ρ(k-j)
+    /// φₖₖ = ――――――――――――――――――――――――
+    ///         1 - ∑ⱼ φₖ₋₁,ⱼ ρ(j)
+    ///
+    /// φₖⱼ = φₖ₋₁,ⱼ - φₖₖ φₖ₋₁,ₖ₋ⱼ
+    /// ```
+    ///
+    /// where the sums run over `j = 1, …, k-1` and `φ₁₁ = ρ(1)`.
+    /// The first element along `axis` (lag 0) is always 1.
+    ///
+    /// The returned array has the same shape as `self`, except for the length
+    /// along `axis`, which is `max_lag + 1`.
+    ///
+    /// If the length of the array along `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `max_lag` is greater than or equal
+    /// to the length of the array along `axis` or if `A::from_usize()` fails.
+    ///
+    /// [partial autocorrelation]: https://en.wikipedia.org/wiki/Partial_autocorrelation_function
+    /// [Durbin–Levinson recursion]: https://en.wikipedia.org/wiki/Levinson_recursion
+    fn partial_autocorrelation_axis(
+        &self,
+        axis: Axis,
+        max_lag: usize,
+    ) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// Performs the [Ljung–Box test] on every series along `axis`,
+    /// using the (biased) autocorrelation *ρ* at lags `1` to `max_lag`:
+    ///
+    /// ```text
+    ///                h    ρ(k)²
+    /// Q = n (n + 2)  ∑  ―――――――
+    ///               k=1  n - k
+    /// ```
+    ///
+    /// where `n` is the length of the series and `h = max_lag`.
+    ///
+    /// Under the null hypothesis that the series is white noise, *Q* follows a
+    /// χ² distribution with `max_lag - fitted_params` degrees of freedom.
+    /// When testing the residuals of a fitted model (e.g. an ARMA(*p*, *q*) model),
+    /// `fitted_params` should be the number of estimated parameters (e.g. *p + q*);
+    /// otherwise it should be zero.
+    ///
+    /// The returned statistics and p-values have the same shape as `self`,
+    /// with `axis` removed.
+    ///
+    /// If the length of the array along `axis` is zero, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds, if `max_lag` is zero, if `max_lag` is greater
+    /// than or equal to the length of the array along `axis`, if `fitted_params` is greater
+    /// than or equal to `max_lag` or if `A::from_usize()` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::AutocorrelationExt;
+    ///
+    /// let residuals = array![0.3, -1.2, 0.8, 0.1, -0.4, 1.5, -0.9, 0.2, -0.1, 0.6];
+    /// let test = residuals.ljung_box_axis(Axis(0), 3, 0).unwrap();
+    /// // No evidence of autocorrelation at the 5% significance level
+    /// assert!(test.p_value[()] > 0.05);
+    /// ```
+    ///
+    /// [Ljung–Box test]: https://en.wikipedia.org/wiki/Ljung%E2%80%93Box_test
+    fn ljung_box_axis(
+        &self,
+        axis: Axis,
+        max_lag: usize,
+        fitted_params: usize,
+    ) -> Result<PortmanteauTest<A, D::Smaller>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    /// Performs the [Box–Pierce test] on every series along `axis`,
+    /// using the (biased) autocorrelation *ρ* at lags `1` to `max_lag`:
+    ///
+    /// ```text
+    ///       h
+    /// Q = n ∑ ρ(k)²
+    ///      k=1
+    /// ```
+    ///
+    /// where `n` is the length of the series and `h = max_lag`.
+    ///
+    /// The Box–Pierce statistic is asymptotically equivalent to the Ljung–Box
+    /// statistic (see [`ljung_box_axis`]), which has better small-sample properties.
+    /// The same conventions apply to `fitted_params`, the returned values,
+    /// errors and panics.
+    ///
+    /// [Box–Pierce test]: https://en.wikipedia.org/wiki/Ljung%E2%80%93Box_test#Box%E2%80%93Pierce_test
+    /// [`ljung_box_axis`]: #tymethod.ljung_box_axis
+    fn box_pierce_axis(
+        &self,
+        axis: Axis,
+        max_lag: usize,
+        fitted_params: usize,
+    ) -> Result<PortmanteauTest<A, D::Smaller>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis;
+
+    private_decl! {}
+}
+
+impl<A, S, D> AutocorrelationExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn autocovariance_axis(
+        &self,
+        axis: Axis,
+        max_lag: usize,
+        normalization: AutocovarianceNormalization,
+    ) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        let n = self.len_of(axis);
+        if n == 0 {
+            return Err(EmptyInput);
+        }
+        assert!(
+            max_lag < n,
+            "`max_lag` needs to be strictly smaller than the length of the series."
+        );
+        let mut shape = self.raw_dim();
+        shape[axis.index()] = max_lag + 1;
+        let mut autocovariance = Array::zeros(shape);
+        Zip::from(autocovariance.lanes_mut(axis))
+            .and(self.lanes(axis))
+            .apply(|mut out, series| {
+                out.assign(&autocovariance_1d(&series, max_lag, normalization))
+            });
+        Ok(autocovariance)
+    }
+
+    fn autocorrelation_axis(
+        &self,
+        axis: Axis,
+        max_lag: usize,
+        normalization: AutocovarianceNormalization,
+    ) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        let mut autocorrelation = self.autocovariance_axis(axis, max_lag, normalization)?;
+        for mut lane in autocorrelation.lanes_mut(axis) {
+            let variance = lane[0];
+            lane.mapv_inplace(|x| x / variance);
+        }
+        Ok(autocorrelation)
+    }
+
+    fn partial_autocorrelation_axis(
+        &self,
+        axis: Axis,
+        max_lag: usize,
+    ) -> Result<Array<A, D>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        let mut partial_autocorrelation =
+            self.autocorrelation_axis(axis, max_lag, AutocovarianceNormalization::Biased)?;
+        for mut lane in partial_autocorrelation.lanes_mut(axis) {
+            let pacf = durbin_levinson(&lane);
+            lane.assign(&pacf);
+        }
+        Ok(partial_autocorrelation)
+    }
+
+    fn ljung_box_axis(
+        &self,
+        axis: Axis,
+        max_lag: usize,
+        fitted_params: usize,
+    ) -> Result<PortmanteauTest<A, D::Smaller>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        let n = self.len_of(axis);
+        portmanteau_test(self, axis, max_lag, fitted_params, |k| {
+            A::from_usize(n * (n + 2)).unwrap() / A::from_usize(n - k).unwrap()
+        })
+    }
+
+    fn box_pierce_axis(
+        &self,
+        axis: Axis,
+        max_lag: usize,
+        fitted_params: usize,
+    ) -> Result<PortmanteauTest<A, D::Smaller>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        let n = self.len_of(axis);
+        portmanteau_test(self, axis, max_lag, fitted_params, |_| {
+            A::from_usize(n).unwrap()
+        })
+    }
+
+    private_impl! {}
+}
+
+/// Autocovariance of a single non-empty series for lags `0..=max_lag`.
+pub(crate) fn autocovariance_1d<A, S>(
+    series: &ArrayBase<S, Ix1>,
+    max_lag: usize,
+    normalization: AutocovarianceNormalization,
+) -> Array1<A>
+where
+    A: Float + FromPrimitive,
+    S: Data<Elem = A>,
+{
+    let n = series.len();
+    let mean = series.sum() / A::from_usize(n).unwrap();
+    let centered: Vec<A> = series.iter().map(|&x| x - mean).collect();
+    let sums = lagged_products(&centered, &centered, 0, max_lag as isize);
+    Array1::from(sums)
+        .indexed_iter()
+        .map(|(k, &sum)| {
+            let m = match normalization {
+                AutocovarianceNormalization::Biased => n,
+                AutocovarianceNormalization::Unbiased => n - k,
+            };
+            sum / A::from_usize(m).unwrap()
+        })
+        .collect()
+}
+
+/// Partial autocorrelation for lags `0..acf.len()`, given the autocorrelation
+/// `acf` of a series (with `acf[0] = 1`), using the Durbin–Levinson recursion.
+fn durbin_levinson<A, S>(acf: &ArrayBase<S, Ix1>) -> Array1<A>
+where
+    A: Float,
+    S: Data<Elem = A>,
+{
+    let max_lag = acf.len() - 1;
+    let mut pacf = Array1::ones(max_lag + 1);
+    // Coefficients φₖ₋₁,ⱼ of the AR(k-1) fit, for j = 1, …, k-1 (index 0 is unused)
+    let mut phi = vec![A::zero(); max_lag + 1];
+    let mut previous = phi.clone();
+    for k in 1..=max_lag {
+        let (numerator, denominator) = (1..k).fold((acf[k], A::one()), |(num, den), j| {
+            (num - previous[j] * acf[k - j], den - previous[j] * acf[j])
+        });
+        let phi_kk = numerator / denominator;
+        for j in 1..k {
+            phi[j] = previous[j] - phi_kk * previous[k - j];
+        }
+        phi[k] = phi_kk;
+        pacf[k] = phi_kk;
+        previous.copy_from_slice(&phi);
+    }
+    pacf
+}
+
+/// Shared implementation of the Ljung–Box and Box–Pierce tests: the statistic is
+/// `∑ₖ weight(k) ρ(k)²`, compared to a χ² distribution with `max_lag - fitted_params`
+/// degrees of freedom.
+fn portmanteau_test<A, S, D, F>(
+    array: &ArrayBase<S, D>,
+    axis: Axis,
+    max_lag: usize,
+    fitted_params: usize,
+    weight: F,
+) -> Result<PortmanteauTest<A, D::Smaller>, EmptyInput>
+where
+    A: Float + FromPrimitive,
+    S: Data<Elem = A>,
+    D: RemoveAxis,
+    F: Fn(usize) -> A,
+{
+    assert!(max_lag > 0, "`max_lag` needs to be strictly positive.");
+    assert!(
+        fitted_params < max_lag,
+        "`fitted_params` needs to be strictly smaller than `max_lag`."
+    );
+    let acf = array.autocorrelation_axis(axis, max_lag, AutocovarianceNormalization::Biased)?;
+    let statistic = acf.map_axis(axis, |lane| {
+        (1..=max_lag).fold(A::zero(), |acc, k| acc + weight(k) * lane[k] * lane[k])
+    });
+    let degrees_of_freedom = (max_lag - fitted_params) as f64;
+    let p_value = statistic.mapv(|q| {
+        let q = q.to_f64().expect("failed cast from type A to f64");
+        A::from_f64(chi_squared_sf(q, degrees_of_freedom)).unwrap()
+    });
+    Ok(PortmanteauTest { statistic, p_value })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::linalg::Cholesky;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, s, Array2, Axis};
+    use ndarray_rand::RandomExt;
+    use rand::distributions::Uniform;
+
+    #[test]
+    fn test_autocovariance_biased_and_unbiased() {
+        let a = array![1., 2., 3., 4.];
+        let biased = a
+            .autocovariance_axis(Axis(0), 3, AutocovarianceNormalization::Biased)
+            .unwrap();
+        assert_abs_diff_eq!(
+            biased,
+            array![1.25, 0.3125, -0.375, -0.5625],
+            epsilon = 1e-12
+        );
+        let unbiased = a
+            .autocovariance_axis(Axis(0), 3, AutocovarianceNormalization::Unbiased)
+            .unwrap();
+        assert_abs_diff_eq!(
+            unbiased,
+            array![1.25, 0.4166666666666667, -0.75, -2.25],
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_autocorrelation_along_both_axes() {
+        let a = Array::random((3, 40), Uniform::new(-1., 1.));
+        let by_row = a
+            .autocorrelation_axis(Axis(1), 5, AutocovarianceNormalization::Biased)
+            .unwrap();
+        let by_column = a
+            .t()
+            .autocorrelation_axis(Axis(0), 5, AutocovarianceNormalization::Biased)
+            .unwrap();
+        assert_eq!(by_row.shape(), &[3, 6]);
+        assert_abs_diff_eq!(by_row, by_column.t(), epsilon = 1e-12);
+        assert_abs_diff_eq!(by_row.column(0), Array::ones(3), epsilon = 1e-12);
+        assert!(by_row.iter().all(|x| x.abs() <= 1. + 1e-12));
+    }
+
+    #[test]
+    fn test_autocorrelation_of_alternating_series() {
+        let a = array![1., -1., 1., -1., 1., -1.];
+        let acf = a
+            .autocorrelation_axis(Axis(0), 2, AutocovarianceNormalization::Unbiased)
+            .unwrap();
+        assert_abs_diff_eq!(acf, array![1., -1., 1.], epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_autocorrelation_of_constant_series_is_nan() {
+        let a = Array2::from_elem((2, 5), 3.);
+        let acf = a
+            .autocorrelation_axis(Axis(1), 2, AutocovarianceNormalization::Biased)
+            .unwrap();
+        assert!(acf.iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_fft_and_direct_paths_agree() {
+        let a = Array::random((2, 300), Uniform::new(-1., 1.));
+        // 300 · 300 lagged products are computed using the FFT,
+        // 300 · 3 of them directly
+        let fft = a
+            .autocovariance_axis(Axis(1), 299, AutocovarianceNormalization::Unbiased)
+            .unwrap();
+        let direct = a
+            .autocovariance_axis(Axis(1), 2, AutocovarianceNormalization::Unbiased)
+            .unwrap();
+        assert_abs_diff_eq!(fft.slice(s![.., ..3]), direct, epsilon = 1e-9);
+        for (series, acov) in a.outer_iter().zip(fft.outer_iter()) {
+            let mean = series.mean().unwrap();
+            let centered: Vec<f64> = series.iter().map(|x| x - mean).collect();
+            for k in (0..300).step_by(50) {
+                let sum: f64 = centered
+                    .iter()
+                    .zip(&centered[k..])
+                    .map(|(x, y)| x * y)
+                    .sum();
+                assert_abs_diff_eq!(acov[k], sum / (300 - k) as f64, epsilon = 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty_series() {
+        let a = Array2::<f64>::zeros((3, 0));
+        assert_eq!(
+            a.autocovariance_axis(Axis(1), 0, AutocovarianceNormalization::Biased),
+            Err(EmptyInput)
+        );
+        let b = Array2::<f64>::zeros((0, 4));
+        let acov = b
+            .autocovariance_axis(Axis(1), 2, AutocovarianceNormalization::Biased)
+            .unwrap();
+        assert_eq!(acov.shape(), &[0, 3]);
+    }
+
+    #[test]
+    fn test_partial_autocorrelation_matches_yule_walker() {
+        let a = Array::random((2, 60), Uniform::new(-1., 1.));
+        let max_lag = 5;
+        let pacf = a.partial_autocorrelation_axis(Axis(1), max_lag).unwrap();
+        let acf = a
+            .autocorrelation_axis(Axis(1), max_lag, AutocovarianceNormalization::Biased)
+            .unwrap();
+        for (pacf, acf) in pacf.outer_iter().zip(acf.outer_iter()) {
+            assert_eq!(pacf[0], 1.);
+            assert_abs_diff_eq!(pacf[1], acf[1], epsilon = 1e-12);
+            // φₖₖ is the last coefficient of the AR(k) fit given by the
+            // Yule–Walker equations R φ = r, with R the Toeplitz matrix of ρ
+            for k in 2..=max_lag {
+                let toeplitz = Array2::from_shape_fn((k, k), |(i, j)| acf[i.max(j) - i.min(j)]);
+                let rhs = acf.slice(s![1..=k]);
+                let phi = Cholesky::new(&toeplitz).unwrap().solve(&rhs);
+                assert_abs_diff_eq!(pacf[k], phi[k - 1], epsilon = 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ljung_box_and_box_pierce() {
+        let a = array![0.3, -1.2, 0.8, 0.1, -0.4, 1.5, -0.9, 0.2, -0.1, 0.6];
+
+        let ljung_box = a.ljung_box_axis(Axis(0), 2, 0).unwrap();
+        assert_abs_diff_eq!(ljung_box.statistic[()], 5.186593170401427, epsilon = 1e-12);
+        assert_abs_diff_eq!(ljung_box.p_value[()], 0.07477313732302161, epsilon = 1e-12);
+
+        let ljung_box = a.ljung_box_axis(Axis(0), 4, 0).unwrap();
+        assert_abs_diff_eq!(ljung_box.statistic[()], 7.519190316273235, epsilon = 1e-12);
+        assert_abs_diff_eq!(ljung_box.p_value[()], 0.11086605208670537, epsilon = 1e-12);
+
+        let ljung_box = a.ljung_box_axis(Axis(0), 4, 2).unwrap();
+        assert_abs_diff_eq!(ljung_box.p_value[()], 0.02329316851606124, epsilon = 1e-12);
+
+        let box_pierce = a.box_pierce_axis(Axis(0), 4, 0).unwrap();
+        assert_abs_diff_eq!(box_pierce.statistic[()], 5.066044936905726, epsilon = 1e-12);
+        assert_abs_diff_eq!(box_pierce.p_value[()], 0.2805877579173976, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_portmanteau_tests_along_axis() {
+        let a = Array::random((4, 30), Uniform::new(-1., 1.));
+        let by_row = a.ljung_box_axis(Axis(1), 5, 1).unwrap();
+        let by_column = a.t().ljung_box_axis(Axis(0), 5, 1).unwrap();
+        assert_eq!(by_row.statistic.shape(), &[4]);
+        assert_eq!(by_row, by_column);
+        assert!(by_row.p_value.iter().all(|p| (0. ..=1.).contains(p)));
+        let box_pierce = a.box_pierce_axis(Axis(1), 5, 1).unwrap();
+        // The Ljung–Box weights n(n+2)/(n-k) are always larger than n
+        assert!(box_pierce
+            .statistic
+            .iter()
+            .zip(&by_row.statistic)
+            .all(|(bp, lb)| bp < lb));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_portmanteau_test_with_too_many_fitted_params() {
+        let a = array![1., 3., 2., 5., 4.];
+        let _ = a.ljung_box_axis(Axis(0), 2, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_max_lag_too_large() {
+        let a = array![1., 2., 3.];
+        let _ = a.autocovariance_axis(Axis(0), 3, AutocovarianceNormalization::Biased);
+    }
+}
@@ -0,0 +1,642 @@
+use crate::errors::{MultiInputError, ShapeMismatch};
+use indexmap::IndexMap;
+use ndarray::prelude::*;
+use ndarray::{Data, Zip};
+use num_traits::Float;
+use std::hash::Hash;
+
+/// The strategy used to combine a per-class metric (e.g. precision) into
+/// a single value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Average {
+    /// Compute the metric globally, from the total counts of true positives, false
+    /// positives and false negatives. For single-label classification, the micro-averaged
+    /// precision, recall and F-score are all equal to the accuracy.
+    Micro,
+    /// Compute the unweighted mean of the per-class values.
+    Macro,
+    /// Compute the mean of the per-class values, weighted by the number of samples
+    /// of each class (its support).
+    Weighted,
+}
+
+/// A [confusion matrix] of a classifier: the entry `(i, j)` counts the samples whose
+/// true label is the `i`-th label and whose predicted label is the `j`-th label.
+///
+/// Labels are ordered by first appearance among the true labels, followed by the labels
+/// that only appear among the predicted ones.
+///
+/// Per-class metrics whose denominator is zero (e.g. the precision of a class that is never
+/// predicted) are taken to be 0.
+///
+/// [confusion matrix]: https://en.wikipedia.org/wiki/Confusion_matrix
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfusionMatrix<L>
+where
+    L: Hash + Eq,
+{
+    labels: IndexMap<L, usize>,
+    counts: Array2<usize>,
+}
+
+impl<L> ConfusionMatrix<L>
+where
+    L: Hash + Eq,
+{
+    /// Returns the labels, mapped to their index in the rows and columns of the matrix.
+    pub fn labels(&self) -> &IndexMap<L, usize> {
+        &self.labels
+    }
+
+    /// Returns the counts: rows correspond to true labels, columns to predicted labels.
+    pub fn counts(&self) -> ArrayView2<'_, usize> {
+        self.counts.view()
+    }
+
+    /// Returns the count of samples with true label `truth` and predicted label
+    /// `prediction`, or 0 if any of the two labels is unknown.
+    pub fn get(&self, truth: &L, prediction: &L) -> usize {
+        match (self.labels.get(truth), self.labels.get(prediction)) {
+            (Some(&i), Some(&j)) => self.counts[(i, j)],
+            _ => 0,
+        }
+    }
+
+    /// Returns the number of samples.
+    pub fn n_samples(&self) -> usize {
+        self.counts.sum()
+    }
+
+    /// Returns the number of samples of each class (the row sums), i.e. the support
+    /// of each label.
+    pub fn support(&self) -> Array1<usize> {
+        self.counts.sum_axis(Axis(1))
+    }
+
+    /// Returns the [accuracy], the fraction of correctly classified samples.
+    ///
+    /// [accuracy]: https://en.wikipedia.org/wiki/Accuracy_and_precision#In_binary_classification
+    pub fn accuracy(&self) -> f64 {
+        self.counts.diag().sum() as f64 / self.n_samples() as f64
+    }
+
+    /// Returns the balanced accuracy, the mean of the recalls of the classes that
+    /// appear among the true labels.
+    pub fn balanced_accuracy(&self) -> f64 {
+        let support = self.support();
+        let recalls: Vec<f64> = self
+            .recall_per_class()
+            .iter()
+            .zip(&support)
+            .filter(|&(_, &s)| s > 0)
+            .map(|(&r, _)| r)
+            .collect();
+        recalls.iter().sum::<f64>() / recalls.len() as f64
+    }
+
+    /// Returns the [precision] of each class: the fraction of the samples predicted to be in
+    /// the class that truly are.
+    ///
+    /// [precision]: https://en.wikipedia.org/wiki/Precision_and_recall
+    pub fn precision_per_class(&self) -> Array1<f64> {
+        let predicted = self.counts.sum_axis(Axis(0));
+        ratios(self.counts.diag(), predicted.view())
+    }
+
+    /// Returns the [recall] of each class: the fraction of the samples in the class that
+    /// are predicted to be.
+    ///
+    /// [recall]: https://en.wikipedia.org/wiki/Precision_and_recall
+    pub fn recall_per_class(&self) -> Array1<f64> {
+        ratios(self.counts.diag(), self.support().view())
+    }
+
+    /// Returns the [F-score] of each class, with recall considered `beta` times as
+    /// important as precision:
+    ///
+    /// ```text
+    ///              precision · recall
+    /// (1 + β²) ――――――――――――――――――――――――
+    ///          β² · precision + recall
+    /// ```
+    ///
+    /// **Panics** if `beta` is negative or NaN.
+    ///
+    /// [F-score]: https://en.wikipedia.org/wiki/F-score
+    pub fn f_beta_score_per_class(&self, beta: f64) -> Array1<f64> {
+        assert!(beta >= 0., "`beta` must be non-negative.");
+        let mut scores = self.precision_per_class();
+        Zip::from(&mut scores)
+            .and(&self.recall_per_class())
+            .apply(|score, &recall| *score = f_beta(*score, recall, beta));
+        scores
+    }
+
+    /// Returns the precision, averaged over the classes according to `average`
+    /// (see [`precision_per_class`]).
+    ///
+    /// [`precision_per_class`]: #method.precision_per_class
+    pub fn precision(&self, average: Average) -> f64 {
+        self.average(self.precision_per_class(), average)
+    }
+
+    /// Returns the recall, averaged over the classes according to `average`
+    /// (see [`recall_per_class`]).
+    ///
+    /// [`recall_per_class`]: #method.recall_per_class
+    pub fn recall(&self, average: Average) -> f64 {
+        self.average(self.recall_per_class(), average)
+    }
+
+    /// Returns the F-score, averaged over the classes according to `average`
+    /// (see [`f_beta_score_per_class`]).
+    ///
+    /// **Panics** if `beta` is negative or NaN.
+    ///
+    /// [`f_beta_score_per_class`]: #method.f_beta_score_per_class
+    pub fn f_beta_score(&self, beta: f64, average: Average) -> f64 {
+        self.average(self.f_beta_score_per_class(beta), average)
+    }
+
+    /// Returns the F₁-score (the harmonic mean of precision and recall), averaged over
+    /// the classes according to `average`.
+    pub fn f1_score(&self, average: Average) -> f64 {
+        self.f_beta_score(1., average)
+    }
+
+    /// Returns the (multiclass) [Matthews correlation coefficient]:
+    ///
+    /// ```text
+    ///                  c s - ∑ pₖ tₖ
+    /// MCC = ――――――――――――――――――――――――――――――
+    ///       √((s² - ∑ pₖ²) (s² - ∑ tₖ²))
+    /// ```
+    ///
+    /// where `c` is the number of correctly classified samples, `s` the number of samples,
+    /// `tₖ` the number of samples of class `k` and `pₖ` the number of samples predicted to be
+    /// in class `k`. It is 0 if the denominator is zero.
+    ///
+    /// [Matthews correlation coefficient]: https://en.wikipedia.org/wiki/Matthews_correlation_coefficient
+    pub fn matthews_corrcoef(&self) -> f64 {
+        let (c, s, t, p) = self.marginals();
+        let denominator = ((s * s - p.dot(&p)) * (s * s - t.dot(&t))).sqrt();
+        if denominator == 0. {
+            0.
+        } else {
+            (c * s - p.dot(&t)) / denominator
+        }
+    }
+
+    /// Returns [Cohen's kappa], the agreement between true and predicted labels
+    /// corrected for the agreement expected by chance:
+    ///
+    /// ```text
+    ///     pₒ - pₑ
+    /// κ = ―――――――
+    ///     1 - pₑ
+    /// ```
+    ///
+    /// where `pₒ` is the accuracy and `pₑ = ∑ pₖ tₖ / s²` (see [`matthews_corrcoef`]).
+    /// It is NaN if `pₑ = 1`.
+    ///
+    /// [Cohen's kappa]: https://en.wikipedia.org/wiki/Cohen%27s_kappa
+    /// [`matthews_corrcoef`]: #method.matthews_corrcoef
+    pub fn cohen_kappa(&self) -> f64 {
+        let (c, s, t, p) = self.marginals();
+        let observed = c / s;
+        let expected = p.dot(&t) / (s * s);
+        (observed - expected) / (1. - expected)
+    }
+
+    /// Returns the number of correctly classified samples, the number of samples,
+    /// the number of samples of each class and the number of predictions of each class.
+    fn marginals(&self) -> (f64, f64, Array1<f64>, Array1<f64>) {
+        let counts = self.counts.mapv(|c| c as f64);
+        (
+            counts.diag().sum(),
+            counts.sum(),
+            counts.sum_axis(Axis(1)),
+            counts.sum_axis(Axis(0)),
+        )
+    }
+
+    fn average(&self, per_class: Array1<f64>, average: Average) -> f64 {
+        match average {
+            // Every misclassified sample is both a false positive and a false negative
+            Average::Micro => self.accuracy(),
+            Average::Macro => per_class.mean().unwrap(),
+            Average::Weighted => {
+                let support = self.support().mapv(|s| s as f64);
+                per_class.dot(&support) / support.sum()
+            }
+        }
+    }
+}
+
+fn ratios(numerators: ArrayView1<usize>, denominators: ArrayView1<usize>) -> Array1<f64> {
+    let mut ratios = Array1::zeros(numerators.len());
+    Zip::from(&mut ratios)
+        .and(numerators)
+        .and(denominators)
+        .apply(|r, &n, &d| {
+            if d > 0 {
+                *r = n as f64 / d as f64;
+            }
+        });
+    ratios
+}
+
+fn f_beta(precision: f64, recall: f64, beta: f64) -> f64 {
+    let beta2 = beta * beta;
+    let denominator = beta2 * precision + recall;
+    if denominator > 0. {
+        (1. + beta2) * precision * recall / denominator
+    } else {
+        0.
+    }
+}
+
+/// A [receiver operating characteristic] curve, returned by
+/// [`roc_curve`](trait.ClassificationExt.html#tymethod.roc_curve).
+///
+/// The `i`-th point of the curve is obtained by predicting as positive the samples
+/// whose score is at least `thresholds[i]`. Thresholds are the distinct scores in
+/// decreasing order, preceded by +∞ so that the curve starts at `(0, 0)`.
+///
+/// [receiver operating characteristic]: https://en.wikipedia.org/wiki/Receiver_operating_characteristic
+#[derive(Clone, Debug, PartialEq)]
+pub struct RocCurve<A> {
+    /// The (weighted) fraction of negative samples predicted as positive.
+    pub false_positive_rate: Array1<A>,
+    /// The (weighted) fraction of positive samples predicted as positive.
+    pub true_positive_rate: Array1<A>,
+    /// The decreasing score thresholds.
+    pub thresholds: Array1<A>,
+}
+
+/// A [precision–recall] curve, returned by
+/// [`precision_recall_curve`](trait.ClassificationExt.html#tymethod.precision_recall_curve).
+///
+/// As for [`RocCurve`], the `i`-th point of the curve is obtained by predicting
+/// as positive the samples whose score is at least `thresholds[i]`, and the first
+/// threshold is +∞: by convention, the corresponding precision is 1 and the recall 0.
+///
+/// [precision–recall]: https://en.wikipedia.org/wiki/Precision_and_recall
+/// [`RocCurve`]: struct.RocCurve.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrecisionRecallCurve<A> {
+    /// The (weighted) fraction of samples predicted as positive that are positive.
+    pub precision: Array1<A>,
+    /// The (weighted) fraction of positive samples predicted as positive.
+    pub recall: Array1<A>,
+    /// The decreasing score thresholds.
+    pub thresholds: Array1<A>,
+}
+
+/// An extension trait for `ArrayBase` providing functions to evaluate
+/// the predictions of a classifier.
+///
+/// As for [`RegressionMetricsExt`], `self` holds the predictions (labels or scores)
+/// and `other` the true labels.
+///
+/// [`RegressionMetricsExt`]: trait.RegressionMetricsExt.html
+pub trait ClassificationExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Builds the confusion matrix of the predicted labels `self` with respect to
+    /// the true labels `other`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{Average, ClassificationExt};
+    ///
+    /// let truth = array!["cat", "dog", "cat", "bird", "dog", "cat", "bird", "cat"];
+    /// let prediction = array!["cat", "cat", "cat", "bird", "dog", "dog", "cat", "cat"];
+    /// let confusion = prediction.confusion_matrix(&truth).unwrap();
+    ///
+    /// assert_eq!(confusion.labels().keys().collect::<Vec<_>>(), vec![&"cat", &"dog", &"bird"]);
+    /// assert_eq!(confusion.counts(), array![[3, 1, 0], [1, 1, 0], [1, 0, 1]]);
+    /// assert_eq!(confusion.get(&"dog", &"cat"), 1);
+    /// assert_eq!(confusion.accuracy(), 0.625);
+    /// assert_eq!(confusion.recall(Average::Weighted), 0.625);
+    /// ```
+    fn confusion_matrix<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+    ) -> Result<ConfusionMatrix<A>, MultiInputError>
+    where
+        A: Hash + Eq + Clone,
+        T: Data<Elem = A>;
+
+    /// Computes the [ROC curve](struct.RocCurve.html) of the scores `self` of a binary
+    /// classifier, with respect to the true labels `labels` (`true` for the positive class).
+    /// Higher scores must indicate the positive class.
+    ///
+    /// Samples with tied scores are predicted together, i.e. they only give a single point
+    /// of the curve. If `sample_weight` is provided, each sample counts as many times as
+    /// its weight.
+    ///
+    /// If there are no positive (resp. negative) samples, the true (resp. false) positive
+    /// rates are NaN.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `labels` or `sample_weight` don't have the
+    ///   same shape as `self`
+    ///
+    /// **Panics** if any score is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::ClassificationExt;
+    /// use std::f64;
+    ///
+    /// let labels = array![false, false, true, true];
+    /// let scores = array![0.1, 0.4, 0.35, 0.8];
+    /// let roc = scores.roc_curve(&labels, None).unwrap();
+    ///
+    /// assert_eq!(roc.false_positive_rate, array![0., 0., 0.5, 0.5, 1.]);
+    /// assert_eq!(roc.true_positive_rate, array![0., 0.5, 0.5, 1., 1.]);
+    /// assert_eq!(roc.thresholds, array![f64::INFINITY, 0.8, 0.4, 0.35, 0.1]);
+    /// assert_eq!(scores.roc_auc_score(&labels, None).unwrap(), 0.75);
+    /// ```
+    fn roc_curve<T>(
+        &self,
+        labels: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<RocCurve<A>, MultiInputError>
+    where
+        A: Float,
+        T: Data<Elem = bool>;
+
+    /// Returns the area under the [ROC curve](#tymethod.roc_curve), computed with the
+    /// trapezoidal rule.
+    ///
+    /// It is the probability that a random positive sample has a higher score than a
+    /// random negative sample, ties counting for one half.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `labels` or `sample_weight` don't have the
+    ///   same shape as `self`
+    ///
+    /// **Panics** if any score is NaN.
+    fn roc_auc_score<T>(
+        &self,
+        labels: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float,
+        T: Data<Elem = bool>;
+
+    /// Computes the [precision–recall curve](struct.PrecisionRecallCurve.html) of the
+    /// scores `self` of a binary classifier, with respect to the true labels `labels`
+    /// (`true` for the positive class). Higher scores must indicate the positive class.
+    ///
+    /// Tied scores and sample weights are handled as in [`roc_curve`]. The precision is
+    /// taken to be 1 at the thresholds where the samples predicted as positive have
+    /// a total weight of zero.
+    ///
+    /// If there are no positive samples, the recall is NaN.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `labels` or `sample_weight` don't have the
+    ///   same shape as `self`
+    ///
+    /// **Panics** if any score is NaN.
+    ///
+    /// [`roc_curve`]: #tymethod.roc_curve
+    fn precision_recall_curve<T>(
+        &self,
+        labels: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<PrecisionRecallCurve<A>, MultiInputError>
+    where
+        A: Float,
+        T: Data<Elem = bool>;
+
+    /// Returns the [average precision] of the scores `self`, the mean of the precisions
+    /// along the [precision–recall curve](#tymethod.precision_recall_curve) weighted by
+    /// the increase in recall:
+    ///
+    /// ```text
+    /// AP = ∑ (Rᵢ - Rᵢ₋₁) Pᵢ
+    /// ```
+    ///
+    /// No interpolation between the points of the curve is performed.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `labels` or `sample_weight` don't have the
+    ///   same shape as `self`
+    ///
+    /// **Panics** if any score is NaN.
+    ///
+    /// [average precision]: https://en.wikipedia.org/wiki/Evaluation_measures_(information_retrieval)#Average_precision
+    fn average_precision_score<T>(
+        &self,
+        labels: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float,
+        T: Data<Elem = bool>;
+
+    private_decl! {}
+}
+
+impl<A, S, D> ClassificationExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn confusion_matrix<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+    ) -> Result<ConfusionMatrix<A>, MultiInputError>
+    where
+        A: Hash + Eq + Clone,
+        T: Data<Elem = A>,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, other);
+
+        let mut labels = IndexMap::new();
+        for label in other.iter().chain(self.iter()) {
+            if !labels.contains_key(label) {
+                let index = labels.len();
+                labels.insert(label.clone(), index);
+            }
+        }
+        let mut counts = Array2::zeros((labels.len(), labels.len()));
+        Zip::from(other).and(self).apply(|truth, prediction| {
+            counts[(labels[truth], labels[prediction])] += 1;
+        });
+        Ok(ConfusionMatrix { labels, counts })
+    }
+
+    fn roc_curve<T>(
+        &self,
+        labels: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<RocCurve<A>, MultiInputError>
+    where
+        A: Float,
+        T: Data<Elem = bool>,
+    {
+        let (thresholds, true_positives, false_positives) =
+            cumulative_counts(self, labels, sample_weight)?;
+        let n_positives = *true_positives.last().unwrap();
+        let n_negatives = *false_positives.last().unwrap();
+        Ok(RocCurve {
+            false_positive_rate: Array1::from(false_positives).mapv(|fp| fp / n_negatives),
+            true_positive_rate: Array1::from(true_positives).mapv(|tp| tp / n_positives),
+            thresholds: Array1::from(thresholds),
+        })
+    }
+
+    fn roc_auc_score<T>(
+        &self,
+        labels: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float,
+        T: Data<Elem = bool>,
+    {
+        let roc = self.roc_curve(labels, sample_weight)?;
+        let x = roc.false_positive_rate;
+        let y = roc.true_positive_rate;
+        let two = A::one() + A::one();
+        Ok((1..x.len()).fold(A::zero(), |area, i| {
+            area + (x[i] - x[i - 1]) * (y[i] + y[i - 1]) / two
+        }))
+    }
+
+    fn precision_recall_curve<T>(
+        &self,
+        labels: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<PrecisionRecallCurve<A>, MultiInputError>
+    where
+        A: Float,
+        T: Data<Elem = bool>,
+    {
+        let (thresholds, true_positives, false_positives) =
+            cumulative_counts(self, labels, sample_weight)?;
+        let n_positives = *true_positives.last().unwrap();
+        let precision = true_positives
+            .iter()
+            .zip(&false_positives)
+            .map(|(&tp, &fp)| {
+                let predicted = tp + fp;
+                if predicted > A::zero() {
+                    tp / predicted
+                } else {
+                    A::one()
+                }
+            })
+            .collect::<Vec<_>>();
+        Ok(PrecisionRecallCurve {
+            precision: Array1::from(precision),
+            recall: Array1::from(true_positives).mapv(|tp| tp / n_positives),
+            thresholds: Array1::from(thresholds),
+        })
+    }
+
+    fn average_precision_score<T>(
+        &self,
+        labels: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float,
+        T: Data<Elem = bool>,
+    {
+        let curve = self.precision_recall_curve(labels, sample_weight)?;
+        let recall = curve.recall;
+        let precision = curve.precision;
+        Ok((1..recall.len()).fold(A::zero(), |ap, i| {
+            ap + (recall[i] - recall[i - 1]) * precision[i]
+        }))
+    }
+
+    private_impl! {}
+}
+
+/// The thresholds, true positives and false positives of a binary classifier.
+type CumulativeCounts<A> = (Vec<A>, Vec<A>, Vec<A>);
+
+/// Sorts the samples by decreasing score and returns the distinct scores, preceded by +∞,
+/// together with the total weight of the positive and negative samples whose score
+/// is at least each of them.
+fn cumulative_counts<A, S, T, D>(
+    scores: &ArrayBase<S, D>,
+    labels: &ArrayBase<T, D>,
+    sample_weight: Option<ArrayView<A, D>>,
+) -> Result<CumulativeCounts<A>, MultiInputError>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    T: Data<Elem = bool>,
+    D: Dimension,
+{
+    return_err_if_empty!(scores);
+    return_err_unless_same_shape!(scores, labels);
+
+    let mut samples = Vec::with_capacity(scores.len());
+    match sample_weight {
+        Some(weights) => {
+            if weights.shape() != scores.shape() {
+                return Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+                    first_shape: scores.shape().to_vec(),
+                    second_shape: weights.shape().to_vec(),
+                }));
+            }
+            Zip::from(scores)
+                .and(labels)
+                .and(&weights)
+                .apply(|&score, &label, &w| samples.push((score, label, w)));
+        }
+        None => Zip::from(scores)
+            .and(labels)
+            .apply(|&score, &label| samples.push((score, label, A::one()))),
+    }
+    samples.sort_by(|a, b| b.0.partial_cmp(&a.0).expect("Scores must not be NaN."));
+
+    let mut thresholds = vec![A::infinity()];
+    let mut true_positives = vec![A::zero()];
+    let mut false_positives = vec![A::zero()];
+    let (mut tp, mut fp) = (A::zero(), A::zero());
+    for (i, &(score, label, w)) in samples.iter().enumerate() {
+        if label {
+            tp = tp + w;
+        } else {
+            fp = fp + w;
+        }
+        // Tied scores give a single point, after all of them have been counted
+        if samples.get(i + 1).map(|next| next.0) != Some(score) {
+            thresholds.push(score);
+            true_positives.push(tp);
+            false_positives.push(fp);
+        }
+    }
+    Ok((thresholds, true_positives, false_positives))
+}
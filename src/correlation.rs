@@ -1,7 +1,54 @@
-use crate::errors::EmptyInput;
+use crate::errors::{CovarianceError, EmptyInput, MinMaxError};
+use crate::fft::lagged_products;
+use crate::linalg::Cholesky;
+use crate::maybe_nan::MaybeNan;
+use crate::special::{normal_quantile, normal_two_sided_p_value, student_t_two_sided_p_value};
 use ndarray::prelude::*;
 use ndarray::Data;
-use num_traits::{Float, FromPrimitive};
+use num_traits::{Float, FromPrimitive, Zero};
+use std::f64;
+
+/// A matrix of statistics computed for every pair of random variables
+/// using only the observations where both random variables are not NaN
+/// ([pairwise deletion]), together with the number of such observations.
+///
+/// [pairwise deletion]: https://en.wikipedia.org/wiki/Missing_data#Partial_deletion
+#[derive(Clone, Debug, PartialEq)]
+pub struct PairwiseComplete<A> {
+    /// The statistic for each pair of random variables.
+    pub values: Array2<A>,
+    /// The number of observations used to compute the statistic
+    /// for each pair of random variables.
+    pub counts: Array2<usize>,
+}
+
+/// The outcome of a significance test on the correlation coefficient
+/// of every pair of random variables.
+///
+/// The null hypothesis is that the two random variables are uncorrelated.
+/// The entries on the diagonal (a random variable compared with itself)
+/// have a coefficient of 1, an infinite statistic, a p-value of 0 and
+/// a confidence interval reduced to `[1, 1]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CorrelationTest<A> {
+    /// The correlation coefficient of each pair of random variables.
+    pub coefficient: Array2<A>,
+    /// The test statistic of each pair of random variables
+    /// (a *t*-statistic or a *z*-score, depending on the test).
+    pub statistic: Array2<A>,
+    /// The two-sided p-value of each pair of random variables.
+    pub p_value: Array2<A>,
+    /// The lower bound of the confidence interval of each correlation coefficient,
+    /// obtained with the [Fisher transformation].
+    ///
+    /// [Fisher transformation]: https://en.wikipedia.org/wiki/Fisher_transformation
+    pub lower_bound: Array2<A>,
+    /// The upper bound of the confidence interval of each correlation coefficient,
+    /// obtained with the [Fisher transformation].
+    ///
+    /// [Fisher transformation]: https://en.wikipedia.org/wiki/Fisher_transformation
+    pub upper_bound: Array2<A>,
+}
 
 /// Extension trait for `ArrayBase` providing functions
 /// to compute different correlation measures.
@@ -122,6 +169,315 @@ where
     where
         A: Float + FromPrimitive;
 
+    /// Return the [partial correlation coefficients](https://en.wikipedia.org/wiki/Partial_correlation)
+    /// for a 2-dimensional array of observations `M`: the correlation
+    /// between each pair of random variables, controlling for all the others.
+    ///
+    /// Let `(r, o)` be the shape of `M`:
+    /// - `r` is the number of random variables;
+    /// - `o` is the number of observations we have collected
+    ///   for each random variable.
+    ///
+    /// Every column in `M` is an experiment: a single observation for each
+    /// random variable.
+    /// Each row in `M` contains all the observations for a certain random variable.
+    ///
+    /// Let `P` be the inverse of the covariance matrix of `M` (the precision matrix)
+    /// and `R` be the matrix returned by this function. Then
+    ///
+    /// ```text
+    ///              P_ij
+    /// R_ij = - ――――――――――――
+    ///          √(P_ii P_jj)
+    /// ```
+    ///
+    /// for `i ≠ j`, while `R_ii = 1`.
+    ///
+    /// The covariance matrix is inverted using a [Cholesky factorisation]
+    /// implemented in this crate, hence no LAPACK backend is required.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `CovarianceError::EmptyInput` if `M` is empty (either zero observations
+    ///   or zero random variables)
+    /// * `CovarianceError::NotPositiveDefinite` if the covariance matrix of `M` is
+    ///   singular (e.g. if a random variable is constant, if some random variables are
+    ///   linearly dependent or if there are fewer observations than random variables)
+    ///
+    /// **Panics** if the type cast of `n_observations` from `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    /// use ndarray_stats::CorrelationExt;
+    /// use approx::AbsDiffEq;
+    ///
+    /// let a = arr2(&[[1., 2., 3., 4., 5.],
+    ///                [2., 1., 4., 3., 6.],
+    ///                [1., 3., 2., 5., 4.]]);
+    /// let partial_corr = a.partial_correlation().unwrap();
+    /// assert!(partial_corr.abs_diff_eq(&partial_corr.t(), 1e-12));
+    /// assert!(partial_corr.diag().iter().all(|&x| x == 1.));
+    /// ```
+    ///
+    /// [Cholesky factorisation]: https://en.wikipedia.org/wiki/Cholesky_decomposition
+    fn partial_correlation(&self) -> Result<Array2<A>, CovarianceError>
+    where
+        A: Float + FromPrimitive;
+
+    /// Return the covariance matrix `C` for a 2-dimensional array of observations `M`,
+    /// skipping NaN values with [pairwise deletion]: each entry `C_ij` is computed
+    /// (as in [`cov`]) using only the observations where both the `i`-th and the
+    /// `j`-th random variables are not NaN.
+    ///
+    /// The number of observations used for each pair of random variables is
+    /// returned alongside the covariance matrix (see [`PairwiseComplete`]).
+    /// This matches the behaviour of `pandas.DataFrame.cov`.
+    ///
+    /// `C_ij` is NaN if the number of observations used for the pair `(i, j)`
+    /// is not strictly greater than `ddof`.
+    ///
+    /// Note that, unlike the output of [`cov`], the resulting matrix is not
+    /// guaranteed to be positive semi-definite.
+    ///
+    /// If `M` is empty (either zero observations or zero random variables), it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if `ddof` is NaN or if the type cast of the number of observations
+    /// from `usize` to `A::NotNan` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{arr2, aview2};
+    /// use ndarray_stats::CorrelationExt;
+    /// use std::f64;
+    ///
+    /// let a = arr2(&[[1., 3., 5., f64::NAN],
+    ///                [2., f64::NAN, 6., 7.]]);
+    /// let covariance = a.cov_skipnan(1.).unwrap();
+    /// assert_eq!(covariance.values, aview2(&[[4., 8.], [8., 7.]]));
+    /// assert_eq!(covariance.counts, aview2(&[[3, 2], [2, 3]]));
+    /// ```
+    ///
+    /// [pairwise deletion]: https://en.wikipedia.org/wiki/Missing_data#Partial_deletion
+    /// [`cov`]: #tymethod.cov
+    /// [`PairwiseComplete`]: struct.PairwiseComplete.html
+    fn cov_skipnan(&self, ddof: A) -> Result<PairwiseComplete<A>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Float + FromPrimitive;
+
+    /// Return the [Pearson correlation coefficients](https://en.wikipedia.org/wiki/Pearson_correlation_coefficient)
+    /// for a 2-dimensional array of observations `M`, skipping NaN values with
+    /// pairwise deletion: each entry `R_ij` is computed (as in [`pearson_correlation`])
+    /// using only the observations where both the `i`-th and the `j`-th random
+    /// variables are not NaN.
+    ///
+    /// The number of observations used for each pair of random variables is
+    /// returned alongside the correlation matrix (see [`PairwiseComplete`]).
+    /// This matches the behaviour of `pandas.DataFrame.corr`.
+    ///
+    /// `R_ij` is NaN if no observation is available for the pair `(i, j)` or if
+    /// either random variable is constant over the available observations.
+    ///
+    /// If `M` is empty (either zero observations or zero random variables), it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if the type cast of the number of observations from `usize`
+    /// to `A::NotNan` fails.
+    ///
+    /// [`pearson_correlation`]: #tymethod.pearson_correlation
+    /// [`PairwiseComplete`]: struct.PairwiseComplete.html
+    fn pearson_correlation_skipnan(&self) -> Result<PairwiseComplete<A>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Float + FromPrimitive;
+
+    /// Return the [Spearman rank correlation coefficients](https://en.wikipedia.org/wiki/Spearman%27s_rank_correlation_coefficient)
+    /// for a 2-dimensional array of observations `M`.
+    ///
+    /// Each row of `M` contains all the observations for a certain random variable.
+    /// The Spearman correlation coefficient is the Pearson correlation coefficient
+    /// (see [`pearson_correlation`]) of the ranks of the observations of each random
+    /// variable. Tied observations are assigned the average of their ranks.
+    ///
+    /// The coefficients of a random variable with any NaN observation are NaN.
+    ///
+    /// If `M` is empty (either zero observations or zero random variables), it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if the type cast of the ranks from `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::{arr2, aview2};
+    /// use ndarray_stats::CorrelationExt;
+    ///
+    /// // A monotonic (although non-linear) relationship
+    /// let a = arr2(&[[1., 2., 3., 4.],
+    ///                [1., 8., 27., 64.]]);
+    /// let corr = a.spearman_correlation().unwrap();
+    /// assert_abs_diff_eq!(corr, aview2(&[[1., 1.], [1., 1.]]), epsilon = 1e-12);
+    /// ```
+    ///
+    /// [`pearson_correlation`]: #tymethod.pearson_correlation
+    fn spearman_correlation(&self) -> Result<Array2<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// Return the [Kendall rank correlation coefficients](https://en.wikipedia.org/wiki/Kendall_rank_correlation_coefficient)
+    /// (τ-b, which accounts for ties) for a 2-dimensional array of observations `M`.
+    ///
+    /// Each row of `M` contains all the observations for a certain random variable.
+    /// For two random variables observed `n` times,
+    ///
+    /// ```text
+    ///                nc - nd
+    /// τ_b = ――――――――――――――――――――――――
+    ///       √((n0 - n1)(n0 - n2))
+    /// ```
+    ///
+    /// where `nc` (respectively `nd`) is the number of concordant (respectively discordant)
+    /// pairs of observations, `n0 = n(n-1)/2` and `n1` (respectively `n2`) is the number
+    /// of pairs of observations that are tied for the first (respectively second)
+    /// random variable.
+    ///
+    /// The computation takes *O(n²)* time for each pair of random variables.
+    ///
+    /// The coefficients of a random variable with any NaN observation are NaN.
+    ///
+    /// If `M` is empty (either zero observations or zero random variables), it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if the type cast of `n` from `usize` to `A` fails.
+    fn kendall_correlation(&self) -> Result<Array2<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// Tests the significance of the Pearson correlation coefficient
+    /// (see [`pearson_correlation`]) of every pair of random variables,
+    /// for a 2-dimensional array of observations `M`.
+    ///
+    /// For a coefficient `r` computed from `n` observations, the test statistic is
+    ///
+    /// ```text
+    ///         √(n - 2)
+    /// t = r ――――――――――
+    ///        √(1 - r²)
+    /// ```
+    ///
+    /// and the p-value is computed assuming that `t` follows a Student's *t*-distribution
+    /// with `n - 2` degrees of freedom (i.e. that the data is normally distributed).
+    ///
+    /// The confidence interval, with confidence level `confidence_level` (e.g. 0.95),
+    /// is obtained with the Fisher transformation, using `1 / √(n - 3)` as standard error:
+    ///
+    /// ```text
+    /// tanh(atanh(r) ± z / √(n - 3))
+    /// ```
+    ///
+    /// where `z` is the `(1 + confidence_level) / 2` quantile of the standard normal distribution.
+    ///
+    /// Off-diagonal p-values are NaN if `n ≤ 2`; confidence bounds are NaN if `n ≤ 3`.
+    ///
+    /// On the diagonal, the coefficients and confidence bounds are 1, the statistics are
+    /// infinite and the p-values are 0, unless the coefficient is undefined (e.g. for a
+    /// constant random variable or one with NaN observations): then they are all NaN.
+    ///
+    /// If `M` is empty (either zero observations or zero random variables), it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if `confidence_level` is not strictly between 0 and 1, or if the type
+    /// casts between `A` and `f64` fail.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    /// use ndarray_stats::CorrelationExt;
+    ///
+    /// let a = arr2(&[[1., 2., 3., 4., 5., 6., 7., 8.],
+    ///                [1., 3., 2., 5., 4., 7., 6., 8.]]);
+    /// let test = a.pearson_correlation_test(0.95).unwrap();
+    /// assert!(test.p_value[(0, 1)] < 0.01);
+    /// assert!(test.lower_bound[(0, 1)] < test.coefficient[(0, 1)]);
+    /// assert!(test.coefficient[(0, 1)] < test.upper_bound[(0, 1)]);
+    /// ```
+    ///
+    /// [`pearson_correlation`]: #tymethod.pearson_correlation
+    fn pearson_correlation_test(
+        &self,
+        confidence_level: A,
+    ) -> Result<CorrelationTest<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// Tests the significance of the Spearman rank correlation coefficient
+    /// (see [`spearman_correlation`]) of every pair of random variables,
+    /// for a 2-dimensional array of observations `M`.
+    ///
+    /// The test statistic and p-value are computed as in [`pearson_correlation_test`],
+    /// using the Spearman coefficient in place of the Pearson one (this is an approximation,
+    /// accurate for `n` larger than about 10).
+    ///
+    /// The confidence interval, with confidence level `confidence_level`, is obtained with
+    /// the Fisher transformation using `√(1.06 / (n - 3))` as standard error
+    /// ([Fieller et al., 1957]).
+    ///
+    /// Off-diagonal p-values are NaN if `n ≤ 2`; confidence bounds are NaN if `n ≤ 3`.
+    /// The diagonal is filled as in [`pearson_correlation_test`].
+    ///
+    /// If `M` is empty (either zero observations or zero random variables), it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if `confidence_level` is not strictly between 0 and 1, or if the type
+    /// casts between `A` and `f64` fail.
+    ///
+    /// [`spearman_correlation`]: #tymethod.spearman_correlation
+    /// [`pearson_correlation_test`]: #tymethod.pearson_correlation_test
+    /// [Fieller et al., 1957]: https://doi.org/10.1093/biomet/44.3-4.470
+    fn spearman_correlation_test(
+        &self,
+        confidence_level: A,
+    ) -> Result<CorrelationTest<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
+    /// Tests the significance of the Kendall rank correlation coefficient
+    /// (see [`kendall_correlation`]) of every pair of random variables,
+    /// for a 2-dimensional array of observations `M`.
+    ///
+    /// The test statistic is the *z*-score of `nc - nd` under the null hypothesis of
+    /// independence, using the variance corrected for ties:
+    ///
+    /// ```text
+    /// var = (v₀ - vₜ - vᵤ) / 18 + v₁ / (2n(n - 1)) + v₂ / (9n(n - 1)(n - 2))
+    /// ```
+    ///
+    /// (see [Kendall rank correlation coefficient]), and the p-value is computed
+    /// assuming that it follows a standard normal distribution.
+    ///
+    /// The confidence interval, with confidence level `confidence_level`, is obtained with
+    /// the Fisher transformation using `√(0.437 / (n - 4))` as standard error
+    /// ([Fieller et al., 1957]).
+    ///
+    /// Off-diagonal confidence bounds are NaN if `n ≤ 4`.
+    /// The diagonal is filled as in [`pearson_correlation_test`].
+    ///
+    /// If `M` is empty (either zero observations or zero random variables), it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if `confidence_level` is not strictly between 0 and 1, or if the type
+    /// casts between `A` and `f64` fail.
+    ///
+    /// [`kendall_correlation`]: #tymethod.kendall_correlation
+    /// [`pearson_correlation_test`]: #tymethod.pearson_correlation_test
+    /// [Kendall rank correlation coefficient]: https://en.wikipedia.org/wiki/Kendall_rank_correlation_coefficient#Significance_tests
+    /// [Fieller et al., 1957]: https://doi.org/10.1093/biomet/44.3-4.470
+    fn kendall_correlation_test(
+        &self,
+        confidence_level: A,
+    ) -> Result<CorrelationTest<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive;
+
     private_decl! {}
 }
 
@@ -178,9 +534,653 @@ where
         }
     }
 
+    fn partial_correlation(&self) -> Result<Array2<A>, CovarianceError>
+    where
+        A: Float + FromPrimitive,
+    {
+        if self.is_empty() {
+            return Err(CovarianceError::EmptyInput);
+        }
+        let n_observations = self.len_of(Axis(1));
+        if n_observations < 2 {
+            return Err(CovarianceError::NotPositiveDefinite);
+        }
+        // The normalisation of the covariance matrix is irrelevant,
+        // it cancels out in the ratio below
+        let cov = self.cov(A::one())?;
+        let precision = Cholesky::new(&cov)
+            .ok_or(CovarianceError::NotPositiveDefinite)?
+            .inverse();
+        let scale = precision.diag().mapv(|x| x.sqrt());
+        let mut partial_correlation = Array2::zeros(precision.raw_dim());
+        for ((i, j), r) in partial_correlation.indexed_iter_mut() {
+            *r = if i == j {
+                A::one()
+            } else {
+                -precision[(i, j)] / (scale[i] * scale[j])
+            };
+        }
+        Ok(partial_correlation)
+    }
+
+    fn cov_skipnan(&self, ddof: A) -> Result<PairwiseComplete<A>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Float + FromPrimitive,
+    {
+        let ddof = *ddof.try_as_not_nan().expect("`ddof` must not be NaN.");
+        pairwise_complete(self, |pairs| {
+            let n = A::NotNan::from_usize(pairs.len()).unwrap();
+            if n <= ddof {
+                return None;
+            }
+            let (mean_x, mean_y) = pairwise_means(pairs, n);
+            let sum = pairs.iter().fold(A::NotNan::zero(), |acc, &(x, y)| {
+                acc + (x - mean_x) * (y - mean_y)
+            });
+            Some(sum / (n - ddof))
+        })
+    }
+
+    fn pearson_correlation_skipnan(&self) -> Result<PairwiseComplete<A>, EmptyInput>
+    where
+        A: MaybeNan,
+        A::NotNan: Float + FromPrimitive,
+    {
+        pairwise_complete(self, |pairs| {
+            if pairs.is_empty() {
+                return None;
+            }
+            let n = A::NotNan::from_usize(pairs.len()).unwrap();
+            let (mean_x, mean_y) = pairwise_means(pairs, n);
+            let zero = A::NotNan::zero();
+            let (sum_xy, sum_xx, sum_yy) =
+                pairs
+                    .iter()
+                    .fold((zero, zero, zero), |(xy, xx, yy), &(x, y)| {
+                        let (dx, dy) = (x - mean_x, y - mean_y);
+                        (xy + dx * dy, xx + dx * dx, yy + dy * dy)
+                    });
+            let norm = (sum_xx * sum_yy).sqrt();
+            if norm == zero {
+                None
+            } else {
+                Some(sum_xy / norm)
+            }
+        })
+    }
+
+    fn spearman_correlation(&self) -> Result<Array2<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        let mut ranks = Array2::zeros(self.raw_dim());
+        for (mut ranks, row) in ranks.outer_iter_mut().zip(self.outer_iter()) {
+            ranks.assign(&average_ranks(&row));
+        }
+        ranks.pearson_correlation()
+    }
+
+    fn kendall_correlation(&self) -> Result<Array2<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        Ok(kendall_statistics(self)?.0)
+    }
+
+    fn pearson_correlation_test(
+        &self,
+        confidence_level: A,
+    ) -> Result<CorrelationTest<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        let coefficient = self.pearson_correlation()?;
+        let n = self.ncols() as f64;
+        let statistic = coefficient.mapv(|r| t_statistic(r, n));
+        Ok(correlation_test(
+            coefficient,
+            statistic,
+            |t| student_t_two_sided_p_value(t, n - 2.),
+            (1. / (n - 3.)).sqrt(),
+            n > 3.,
+            confidence_level,
+        ))
+    }
+
+    fn spearman_correlation_test(
+        &self,
+        confidence_level: A,
+    ) -> Result<CorrelationTest<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        let coefficient = self.spearman_correlation()?;
+        let n = self.ncols() as f64;
+        let statistic = coefficient.mapv(|r| t_statistic(r, n));
+        Ok(correlation_test(
+            coefficient,
+            statistic,
+            |t| student_t_two_sided_p_value(t, n - 2.),
+            (1.06 / (n - 3.)).sqrt(),
+            n > 3.,
+            confidence_level,
+        ))
+    }
+
+    fn kendall_correlation_test(
+        &self,
+        confidence_level: A,
+    ) -> Result<CorrelationTest<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive,
+    {
+        let (coefficient, statistic) = kendall_statistics(self)?;
+        let n = self.ncols() as f64;
+        Ok(correlation_test(
+            coefficient,
+            statistic,
+            normal_two_sided_p_value,
+            (0.437 / (n - 4.)).sqrt(),
+            n > 4.,
+            confidence_level,
+        ))
+    }
+
+    private_impl! {}
+}
+
+/// Returns the ranks (starting from 1) of the elements of `values`,
+/// assigning to tied elements the average of their ranks.
+///
+/// Ranks are undefined if any element is NaN: in that case, all the ranks are NaN.
+fn average_ranks<A, S>(values: &ArrayBase<S, Ix1>) -> Array1<A>
+where
+    A: Float + FromPrimitive,
+    S: Data<Elem = A>,
+{
+    if values.iter().any(|x| x.is_nan()) {
+        return Array1::from_elem(values.len(), A::nan());
+    }
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&i, &j| values[i].partial_cmp(&values[j]).unwrap());
+    let mut ranks = Array1::zeros(values.len());
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start;
+        while end + 1 < order.len() && values[order[end + 1]] == values[order[start]] {
+            end += 1;
+        }
+        // Average of the ranks start + 1, ..., end + 1
+        let rank = A::from_usize(start + end + 2).unwrap() / A::from_usize(2).unwrap();
+        for &i in &order[start..=end] {
+            ranks[i] = rank;
+        }
+        start = end + 1;
+    }
+    ranks
+}
+
+/// Sums over the groups of tied values in `values` (of sizes `t`) of
+/// `t(t - 1)`, `t(t - 1)(2t + 5)` and `t(t - 1)(t - 2)`.
+fn tie_sums<A, S>(values: &ArrayBase<S, Ix1>) -> (f64, f64, f64)
+where
+    A: Float,
+    S: Data<Elem = A>,
+{
+    let mut sorted: Vec<A> = values.iter().cloned().filter(|x| !x.is_nan()).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mut sums = (0., 0., 0.);
+    let mut start = 0;
+    while start < sorted.len() {
+        let end = start
+            + sorted[start..]
+                .iter()
+                .take_while(|&&x| x == sorted[start])
+                .count();
+        let t = (end - start) as f64;
+        sums.0 += t * (t - 1.);
+        sums.1 += t * (t - 1.) * (2. * t + 5.);
+        sums.2 += t * (t - 1.) * (t - 2.);
+        start = end;
+    }
+    sums
+}
+
+/// Returns the Kendall τ-b coefficients and the z-scores of `nc - nd`
+/// for every pair of rows of `m`.
+fn kendall_statistics<A, S>(m: &ArrayBase<S, Ix2>) -> Result<(Array2<A>, Array2<A>), EmptyInput>
+where
+    A: Float + FromPrimitive,
+    S: Data<Elem = A>,
+{
+    if m.is_empty() {
+        return Err(EmptyInput);
+    }
+    let (n_variables, n) = m.dim();
+    let ties: Vec<_> = m.outer_iter().map(|row| tie_sums(&row)).collect();
+    let n_f = n as f64;
+    let n0 = n_f * (n_f - 1.) / 2.;
+    let mut tau = Array2::zeros((n_variables, n_variables));
+    let mut z = Array2::zeros((n_variables, n_variables));
+    for i in 0..n_variables {
+        for j in i..n_variables {
+            let (x, y) = (m.row(i), m.row(j));
+            let mut score = 0.;
+            for k in 0..n {
+                for l in (k + 1)..n {
+                    let dx = x[l] - x[k];
+                    let dy = y[l] - y[k];
+                    let product = dx.signum() * dy.signum();
+                    if dx != A::zero() && dy != A::zero() {
+                        score += product.to_f64().unwrap();
+                    } else if dx.is_nan() || dy.is_nan() {
+                        score = f64::NAN;
+                    }
+                }
+            }
+            let (tx, ty) = (ties[i], ties[j]);
+            let tau_ij = score / ((n0 - tx.0 / 2.) * (n0 - ty.0 / 2.)).sqrt();
+            let mut variance = (n_f * (n_f - 1.) * (2. * n_f + 5.) - tx.1 - ty.1) / 18.
+                + tx.0 * ty.0 / (2. * n_f * (n_f - 1.));
+            if n > 2 {
+                variance += tx.2 * ty.2 / (9. * n_f * (n_f - 1.) * (n_f - 2.));
+            }
+            let z_ij = score / variance.sqrt();
+            tau[(i, j)] = A::from_f64(tau_ij).unwrap();
+            tau[(j, i)] = tau[(i, j)];
+            z[(i, j)] = A::from_f64(z_ij).unwrap();
+            z[(j, i)] = z[(i, j)];
+        }
+    }
+    Ok((tau, z))
+}
+
+/// The *t*-statistic of a correlation coefficient `r` computed from `n` observations.
+fn t_statistic<A>(r: A, n: f64) -> A
+where
+    A: Float + FromPrimitive,
+{
+    let r = r.max(-A::one()).min(A::one());
+    r * (A::from_f64(n - 2.).unwrap() / (A::one() - r * r)).sqrt()
+}
+
+/// Assembles a `CorrelationTest`, computing p-values from the statistics with `p_value`
+/// and confidence intervals with the Fisher transformation, using the given standard error
+/// (or NaN bounds if `valid_interval` is `false`).
+fn correlation_test<A, F>(
+    coefficient: Array2<A>,
+    statistic: Array2<A>,
+    p_value: F,
+    standard_error: f64,
+    valid_interval: bool,
+    confidence_level: A,
+) -> CorrelationTest<A>
+where
+    A: Float + FromPrimitive,
+    F: Fn(f64) -> f64,
+{
+    let confidence_level = confidence_level
+        .to_f64()
+        .expect("failed cast from type A to f64");
+    assert!(
+        confidence_level > 0. && confidence_level < 1.,
+        "`confidence_level` must be strictly between 0 and 1."
+    );
+    let half_width = if valid_interval {
+        normal_quantile((1. + confidence_level) / 2.) * standard_error
+    } else {
+        f64::NAN
+    };
+    let to_f64 = |x: A| x.to_f64().expect("failed cast from type A to f64");
+    let from_f64 = |x: f64| A::from_f64(x).unwrap();
+
+    let n_variables = coefficient.nrows();
+    let mut test = CorrelationTest {
+        p_value: statistic.mapv(|t| from_f64(p_value(to_f64(t)))),
+        lower_bound: coefficient
+            .mapv(|r| from_f64((to_f64(r).max(-1.).min(1.).atanh() - half_width).tanh())),
+        upper_bound: coefficient
+            .mapv(|r| from_f64((to_f64(r).max(-1.).min(1.).atanh() + half_width).tanh())),
+        coefficient,
+        statistic,
+    };
+    for i in 0..n_variables {
+        // A random variable is perfectly correlated with itself, unless its
+        // coefficient is undefined (e.g. because it is constant)
+        let (coefficient, statistic, p_value) = if test.coefficient[(i, i)].is_nan() {
+            (A::nan(), A::nan(), A::nan())
+        } else {
+            (A::one(), A::infinity(), A::zero())
+        };
+        test.coefficient[(i, i)] = coefficient;
+        test.statistic[(i, i)] = statistic;
+        test.p_value[(i, i)] = p_value;
+        test.lower_bound[(i, i)] = coefficient;
+        test.upper_bound[(i, i)] = coefficient;
+    }
+    test
+}
+
+/// Computes `statistic` for every pair of rows of `m`, passing it the observations
+/// where both rows are not NaN. `None` is mapped to NaN.
+fn pairwise_complete<A, S, F>(
+    m: &ArrayBase<S, Ix2>,
+    statistic: F,
+) -> Result<PairwiseComplete<A>, EmptyInput>
+where
+    A: MaybeNan,
+    A::NotNan: Float,
+    S: Data<Elem = A>,
+    F: Fn(&[(A::NotNan, A::NotNan)]) -> Option<A::NotNan>,
+{
+    if m.is_empty() {
+        return Err(EmptyInput);
+    }
+    let n_variables = m.nrows();
+    let mut values = Vec::with_capacity(n_variables * n_variables);
+    let mut counts = Array2::zeros((n_variables, n_variables));
+    let mut pairs = Vec::with_capacity(m.ncols());
+    for i in 0..n_variables {
+        for j in 0..n_variables {
+            pairs.clear();
+            pairs.extend(
+                m.row(i)
+                    .iter()
+                    .zip(m.row(j))
+                    .filter_map(|(x, y)| Some((*x.try_as_not_nan()?, *y.try_as_not_nan()?))),
+            );
+            counts[(i, j)] = pairs.len();
+            // The statistics are symmetric: reuse the upper triangle
+            let value = if j < i {
+                values[j * n_variables + i]
+            } else {
+                statistic(&pairs)
+            };
+            values.push(value);
+        }
+    }
+    let values = Array2::from_shape_vec(
+        (n_variables, n_variables),
+        values.into_iter().map(A::from_not_nan_opt).collect(),
+    )
+    .unwrap();
+    Ok(PairwiseComplete { values, counts })
+}
+
+fn pairwise_means<A>(pairs: &[(A, A)], n: A) -> (A, A)
+where
+    A: Float,
+{
+    let (sum_x, sum_y) = pairs
+        .iter()
+        .fold((A::zero(), A::zero()), |(sx, sy), &(x, y)| (sx + x, sy + y));
+    (sum_x / n, sum_y / n)
+}
+
+/// The range of lags evaluated by [`CrossCorrelationExt`] methods, following
+/// the conventions of [`numpy.correlate`].
+///
+/// Let `n` be the length of the first signal and `m` the length of the second one.
+///
+/// [`CrossCorrelationExt`]: trait.CrossCorrelationExt.html
+/// [`numpy.correlate`]: https://docs.scipy.org/doc/numpy/reference/generated/numpy.correlate.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CrossCorrelationMode {
+    /// Every lag at which the two signals overlap by at least one element,
+    /// i.e. from `-(m - 1)` to `n - 1`: `n + m - 1` lags in total.
+    Full,
+    /// `max(n, m)` lags, centered with respect to `Full`.
+    Same,
+    /// Only the lags at which the shorter signal completely overlaps the
+    /// longer one: `max(n, m) - min(n, m) + 1` lags in total.
+    Valid,
+}
+
+impl CrossCorrelationMode {
+    /// Returns the first and last lag (extremes included) for signals of
+    /// lengths `n` and `m`.
+    fn lag_range(self, n: usize, m: usize) -> (isize, isize) {
+        let (n, m) = (n as isize, m as isize);
+        match self {
+            CrossCorrelationMode::Full => (1 - m, n - 1),
+            CrossCorrelationMode::Same => {
+                if n >= m {
+                    (-(m / 2), n - 1 - m / 2)
+                } else {
+                    (n / 2 - (m - 1), n / 2)
+                }
+            }
+            CrossCorrelationMode::Valid => {
+                if n >= m {
+                    (0, n - m)
+                } else {
+                    (n - m, 0)
+                }
+            }
+        }
+    }
+}
+
+/// The cross-correlation of two signals, evaluated at a contiguous range of lags.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrossCorrelation<A> {
+    /// The lags at which the cross-correlation has been evaluated, in increasing order.
+    pub lags: Array1<isize>,
+    /// The cross-correlation at each lag.
+    pub values: Array1<A>,
+}
+
+/// Extension trait for one-dimensional `ArrayBase` providing functions
+/// to compute the lagged cross-correlation between two signals.
+pub trait CrossCorrelationExt<A, S>
+where
+    S: Data<Elem = A>,
+{
+    /// Returns the [cross-correlation] of `self` (*x*, of length *n*) and `other`
+    /// (*y*, of length *m*) for the lags selected by `mode`.
+    ///
+    /// The cross-correlation at lag *k* is defined as in [`numpy.correlate`]:
+    ///
+    /// ```text
+    /// c(k) = ∑ xₜ₊ₖ yₜ
+    ///        ₜ
+    /// ```
+    ///
+    /// where the sum runs over all `t` such that both `t + k` and `t` are valid
+    /// indices. A positive lag *k* means that the features of *y* appear in *x*
+    /// *k* positions later.
+    ///
+    /// The sums are computed directly when few lags are requested and using
+    /// a fast Fourier transform, in *O((n + m) log(n + m))* time, otherwise.
+    ///
+    /// If either `self` or `other` is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{CrossCorrelationExt, CrossCorrelationMode};
+    ///
+    /// let x = array![1., 2., 3.];
+    /// let y = array![0., 1., 0.5];
+    /// let cross_correlation = x.cross_correlation(&y, CrossCorrelationMode::Same).unwrap();
+    /// assert_eq!(cross_correlation.lags, array![-1, 0, 1]);
+    /// assert_eq!(cross_correlation.values, array![2., 3.5, 3.]);
+    /// ```
+    ///
+    /// [cross-correlation]: https://en.wikipedia.org/wiki/Cross-correlation
+    /// [`numpy.correlate`]: https://docs.scipy.org/doc/numpy/reference/generated/numpy.correlate.html
+    fn cross_correlation<S2>(
+        &self,
+        other: &ArrayBase<S2, Ix1>,
+        mode: CrossCorrelationMode,
+    ) -> Result<CrossCorrelation<A>, EmptyInput>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive;
+
+    /// Returns the cross-correlation of `self` (*x*) and `other` (*y*) for the
+    /// lags selected by `mode`, normalised to the scale of the
+    /// [Pearson correlation coefficient]:
+    ///
+    /// ```text
+    ///                 ∑ₜ (xₜ₊ₖ - x̅)(yₜ - y̅)
+    /// r(k) = ――――――――――――――――――――――――――――――
+    ///        √(∑ₜ (xₜ - x̅)²) √(∑ₜ (yₜ - y̅)²)
+    /// ```
+    ///
+    /// The values lie in `[-1, 1]`. When *x* and *y* have the same length, the
+    /// value at lag 0 is their Pearson correlation coefficient. If either signal
+    /// is constant, all the values are NaN.
+    ///
+    /// See [`cross_correlation`] for the definition of lags and modes.
+    ///
+    /// If either `self` or `other` is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the length of the signals.
+    ///
+    /// [Pearson correlation coefficient]: https://en.wikipedia.org/wiki/Pearson_correlation_coefficient
+    /// [`cross_correlation`]: #tymethod.cross_correlation
+    fn normalized_cross_correlation<S2>(
+        &self,
+        other: &ArrayBase<S2, Ix1>,
+        mode: CrossCorrelationMode,
+    ) -> Result<CrossCorrelation<A>, EmptyInput>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive;
+
+    /// Returns the lag, among those selected by `mode`, at which the normalised
+    /// cross-correlation of `self` and `other` (see [`normalized_cross_correlation`])
+    /// is maximum.
+    ///
+    /// This is the delay that best aligns the two signals: if `self` is a copy of
+    /// `other` delayed by *d* samples, the returned lag is *d*.
+    ///
+    /// If several lags attain the maximum, the smallest one is returned.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MinMaxError::EmptyInput` if either `self` or `other` is empty
+    /// * `MinMaxError::UndefinedOrder` if the ordering between two
+    ///   cross-correlation values is undefined (e.g. if either signal is constant,
+    ///   or contains NaN values)
+    ///
+    /// **Panics** if `A::from_usize()` fails to convert the length of the signals.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{CrossCorrelationExt, CrossCorrelationMode};
+    ///
+    /// let reference = array![0., 1., 3., 1., 0., 0., 0.];
+    /// let delayed = array![0., 0., 0., 1., 3., 1., 0.];
+    /// let lag = delayed
+    ///     .max_cross_correlation_lag(&reference, CrossCorrelationMode::Full)
+    ///     .unwrap();
+    /// assert_eq!(lag, 2);
+    /// ```
+    ///
+    /// [`normalized_cross_correlation`]: #tymethod.normalized_cross_correlation
+    fn max_cross_correlation_lag<S2>(
+        &self,
+        other: &ArrayBase<S2, Ix1>,
+        mode: CrossCorrelationMode,
+    ) -> Result<isize, MinMaxError>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive;
+
+    private_decl! {}
+}
+
+impl<A, S> CrossCorrelationExt<A, S> for ArrayBase<S, Ix1>
+where
+    S: Data<Elem = A>,
+{
+    fn cross_correlation<S2>(
+        &self,
+        other: &ArrayBase<S2, Ix1>,
+        mode: CrossCorrelationMode,
+    ) -> Result<CrossCorrelation<A>, EmptyInput>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive,
+    {
+        if self.is_empty() || other.is_empty() {
+            return Err(EmptyInput);
+        }
+        let x: Vec<A> = self.to_vec();
+        let y: Vec<A> = other.to_vec();
+        Ok(lagged_cross_correlation(&x, &y, mode))
+    }
+
+    fn normalized_cross_correlation<S2>(
+        &self,
+        other: &ArrayBase<S2, Ix1>,
+        mode: CrossCorrelationMode,
+    ) -> Result<CrossCorrelation<A>, EmptyInput>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive,
+    {
+        if self.is_empty() || other.is_empty() {
+            return Err(EmptyInput);
+        }
+        let center = |signal: Vec<A>| {
+            let mean = signal.iter().fold(A::zero(), |acc, &v| acc + v)
+                / A::from_usize(signal.len()).unwrap();
+            signal.into_iter().map(|v| v - mean).collect::<Vec<_>>()
+        };
+        let x = center(self.to_vec());
+        let y = center(other.to_vec());
+        let sum_sq = |signal: &[A]| signal.iter().fold(A::zero(), |acc, &v| acc + v * v);
+        let norm = (sum_sq(&x) * sum_sq(&y)).sqrt();
+        let mut cross_correlation = lagged_cross_correlation(&x, &y, mode);
+        cross_correlation.values.mapv_inplace(|v| v / norm);
+        Ok(cross_correlation)
+    }
+
+    fn max_cross_correlation_lag<S2>(
+        &self,
+        other: &ArrayBase<S2, Ix1>,
+        mode: CrossCorrelationMode,
+    ) -> Result<isize, MinMaxError>
+    where
+        S2: Data<Elem = A>,
+        A: Float + FromPrimitive,
+    {
+        let cross_correlation = self.normalized_cross_correlation(other, mode)?;
+        let mut best = 0;
+        for (i, value) in cross_correlation.values.iter().enumerate() {
+            match value.partial_cmp(&cross_correlation.values[best]) {
+                Some(std::cmp::Ordering::Greater) => best = i,
+                Some(_) => {}
+                None => return Err(MinMaxError::UndefinedOrder),
+            }
+        }
+        Ok(cross_correlation.lags[best])
+    }
+
     private_impl! {}
 }
 
+fn lagged_cross_correlation<A>(x: &[A], y: &[A], mode: CrossCorrelationMode) -> CrossCorrelation<A>
+where
+    A: Float + FromPrimitive,
+{
+    let (min_lag, max_lag) = mode.lag_range(x.len(), y.len());
+    CrossCorrelation {
+        lags: (min_lag..=max_lag).collect(),
+        values: Array1::from(lagged_products(x, y, min_lag, max_lag)),
+    }
+}
+
 #[cfg(test)]
 mod cov_tests {
     use super::*;
@@ -366,3 +1366,446 @@ mod pearson_correlation_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod partial_correlation_tests {
+    use super::*;
+    use ndarray::array;
+    use ndarray_rand::RandomExt;
+    use rand::distributions::Uniform;
+
+    #[test]
+    fn test_two_variables_match_pearson_correlation() {
+        let a = Array::random((2, 20), Uniform::new(-1., 1.));
+        assert_abs_diff_eq!(
+            a.partial_correlation().unwrap(),
+            a.pearson_correlation().unwrap(),
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_three_variables_match_recursive_formula() {
+        let a = Array::random((3, 50), Uniform::new(-1., 1.));
+        let r = a.pearson_correlation().unwrap();
+        let partial_correlation = a.partial_correlation().unwrap();
+        // ρ_01·2 = (ρ_01 - ρ_02 ρ_12) / √((1 - ρ_02²)(1 - ρ_12²))
+        let expected = (r[(0, 1)] - r[(0, 2)] * r[(1, 2)])
+            / ((1. - r[(0, 2)].powi(2)) * (1. - r[(1, 2)].powi(2))).sqrt();
+        assert_abs_diff_eq!(partial_correlation[(0, 1)], expected, epsilon = 1e-10);
+        assert_abs_diff_eq!(partial_correlation[(1, 0)], expected, epsilon = 1e-10);
+        assert_abs_diff_eq!(partial_correlation.diag(), Array::ones(3), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_controlling_for_common_cause() {
+        // x and y are both driven by z: they are strongly correlated,
+        // but independent once z is accounted for.
+        let z = array![1., 2., 3., 4., 5., 6., 7., 8.];
+        let x = &z * 2. + &array![0.1, -0.1, -0.1, 0.1, 0.1, -0.1, -0.1, 0.1];
+        let y = &z * -3. + &array![0.1, -0.1, 0.1, -0.1, -0.1, 0.1, -0.1, 0.1];
+        let mut a = Array2::zeros((3, 8));
+        a.row_mut(0).assign(&x);
+        a.row_mut(1).assign(&y);
+        a.row_mut(2).assign(&z);
+        assert!(a.pearson_correlation().unwrap()[(0, 1)] < -0.99);
+        assert_abs_diff_eq!(
+            a.partial_correlation().unwrap()[(0, 1)],
+            0.,
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_constant_variable_is_singular() {
+        let a = array![[1., 2., 3., 4.], [5., 5., 5., 5.]];
+        assert_eq!(
+            a.partial_correlation(),
+            Err(CovarianceError::NotPositiveDefinite)
+        );
+    }
+
+    #[test]
+    fn test_linearly_dependent_variables_are_singular() {
+        let a = array![[1., 2., 3., 5.], [2., 1., 0., 7.], [3., 3., 3., 12.]];
+        assert!(a
+            .partial_correlation()
+            .unwrap_err()
+            .is_not_positive_definite());
+    }
+
+    #[test]
+    fn test_fewer_observations_than_variables_is_singular() {
+        let a = Array::random((4, 3), Uniform::new(-1., 1.));
+        assert!(a
+            .partial_correlation()
+            .unwrap_err()
+            .is_not_positive_definite());
+        let b = array![[1.], [2.]];
+        assert!(b
+            .partial_correlation()
+            .unwrap_err()
+            .is_not_positive_definite());
+    }
+
+    #[test]
+    fn test_zero_variables_or_observations() {
+        let a = Array2::<f32>::zeros((0, 2));
+        assert_eq!(a.partial_correlation(), Err(CovarianceError::EmptyInput));
+        let b = Array2::<f32>::zeros((2, 0));
+        assert_eq!(b.partial_correlation(), Err(CovarianceError::EmptyInput));
+    }
+}
+
+#[cfg(test)]
+mod cross_correlation_tests {
+    use super::*;
+    use ndarray::array;
+    use ndarray_rand::RandomExt;
+    use rand::distributions::Uniform;
+
+    #[test]
+    fn test_modes_match_numpy() {
+        let x = array![1., 2., 3.];
+        let y = array![0., 1., 0.5];
+
+        let full = x.cross_correlation(&y, CrossCorrelationMode::Full).unwrap();
+        assert_eq!(full.lags, array![-2, -1, 0, 1, 2]);
+        assert_abs_diff_eq!(full.values, array![0.5, 2., 3.5, 3., 0.], epsilon = 1e-12);
+
+        let same = x.cross_correlation(&y, CrossCorrelationMode::Same).unwrap();
+        assert_eq!(same.lags, array![-1, 0, 1]);
+        assert_abs_diff_eq!(same.values, array![2., 3.5, 3.], epsilon = 1e-12);
+
+        let valid = x
+            .cross_correlation(&y, CrossCorrelationMode::Valid)
+            .unwrap();
+        assert_eq!(valid.lags, array![0]);
+        assert_abs_diff_eq!(valid.values, array![3.5], epsilon = 1e-12);
+
+        let reversed = y.cross_correlation(&x, CrossCorrelationMode::Full).unwrap();
+        assert_eq!(reversed.lags, array![-2, -1, 0, 1, 2]);
+        assert_abs_diff_eq!(
+            reversed.values,
+            array![0., 3., 3.5, 2., 0.5],
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_modes_with_different_lengths() {
+        let x = array![1., 2.];
+        let y = array![1., 1., 1., 1.];
+        // np.correlate(x, y, mode)
+        let full = x.cross_correlation(&y, CrossCorrelationMode::Full).unwrap();
+        assert_eq!(full.lags, array![-3, -2, -1, 0, 1]);
+        assert_abs_diff_eq!(full.values, array![1., 3., 3., 3., 2.], epsilon = 1e-12);
+        let same = x.cross_correlation(&y, CrossCorrelationMode::Same).unwrap();
+        assert_abs_diff_eq!(same.values, array![3., 3., 3., 2.], epsilon = 1e-12);
+        let valid = x
+            .cross_correlation(&y, CrossCorrelationMode::Valid)
+            .unwrap();
+        assert_eq!(valid.lags, array![-2, -1, 0]);
+        assert_abs_diff_eq!(valid.values, array![3., 3., 3.], epsilon = 1e-12);
+
+        let same = y.cross_correlation(&x, CrossCorrelationMode::Same).unwrap();
+        assert_eq!(same.lags, array![-1, 0, 1, 2]);
+        assert_abs_diff_eq!(same.values, array![2., 3., 3., 3.], epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_normalized_cross_correlation_at_lag_zero_is_pearson() {
+        let a = Array::random((2, 30), Uniform::new(-1., 1.));
+        let pearson = a.pearson_correlation().unwrap();
+        let cross_correlation = a
+            .row(0)
+            .normalized_cross_correlation(&a.row(1), CrossCorrelationMode::Full)
+            .unwrap();
+        assert_eq!(cross_correlation.lags[29], 0);
+        assert_abs_diff_eq!(
+            cross_correlation.values[29],
+            pearson[(0, 1)],
+            epsilon = 1e-12
+        );
+        assert!(cross_correlation
+            .values
+            .iter()
+            .all(|x| x.abs() <= 1. + 1e-12));
+    }
+
+    #[test]
+    fn test_max_cross_correlation_lag_recovers_delay() {
+        let signal = Array::random(500, Uniform::new(-1., 1.));
+        let delay = 37;
+        let delayed = Array::from_shape_fn(500, |t| if t < delay { 0. } else { signal[t - delay] });
+        let mode = CrossCorrelationMode::Full;
+        assert_eq!(delayed.max_cross_correlation_lag(&signal, mode), Ok(37));
+        assert_eq!(signal.max_cross_correlation_lag(&delayed, mode), Ok(-37));
+    }
+
+    #[test]
+    fn test_max_cross_correlation_lag_of_constant_signal() {
+        let x = array![1., 1., 1.];
+        let y = array![1., 2., 3.];
+        assert_eq!(
+            x.max_cross_correlation_lag(&y, CrossCorrelationMode::Full),
+            Err(MinMaxError::UndefinedOrder)
+        );
+    }
+
+    #[test]
+    fn test_empty_signals() {
+        let x = Array1::<f64>::zeros(0);
+        let y = array![1., 2.];
+        let mode = CrossCorrelationMode::Same;
+        assert_eq!(x.cross_correlation(&y, mode), Err(EmptyInput));
+        assert_eq!(y.normalized_cross_correlation(&x, mode), Err(EmptyInput));
+        assert_eq!(
+            x.max_cross_correlation_lag(&y, mode),
+            Err(MinMaxError::EmptyInput)
+        );
+    }
+}
+
+#[cfg(test)]
+mod skipnan_tests {
+    use super::*;
+    use ndarray::array;
+    use ndarray_rand::RandomExt;
+    use rand::distributions::Uniform;
+
+    #[test]
+    fn test_pairwise_deletion() {
+        let a = array![
+            [1., 2., f64::NAN, 4., 5.],
+            [2., f64::NAN, 3., 5., 4.],
+            [f64::NAN, 1., 1., 2., 3.]
+        ];
+        // Same semantics as pandas.DataFrame(a.T).cov() and .corr()
+        let expected_cov = array![
+            [3.3333333333333335, 2.666666666666667, 1.5],
+            [2.666666666666667, 1.6666666666666667, 0.5],
+            [1.5, 0.5, 0.9166666666666666]
+        ];
+        let expected_corr = array![
+            [1., 0.8386278693775346, 0.9819805060619659],
+            [0.8386278693775346, 1., 0.5],
+            [0.9819805060619659, 0.5, 1.]
+        ];
+        let expected_counts = array![[4, 3, 3], [3, 4, 3], [3, 3, 4]];
+
+        let cov = a.cov_skipnan(1.).unwrap();
+        assert_abs_diff_eq!(cov.values, expected_cov, epsilon = 1e-12);
+        assert_eq!(cov.counts, expected_counts);
+        let corr = a.pearson_correlation_skipnan().unwrap();
+        assert_abs_diff_eq!(corr.values, expected_corr, epsilon = 1e-12);
+        assert_eq!(corr.counts, expected_counts);
+    }
+
+    #[test]
+    fn test_without_nan_matches_cov_and_pearson_correlation() {
+        let a = Array::random((4, 10), Uniform::new(-1f32, 1.));
+        let cov = a.cov_skipnan(0.).unwrap();
+        assert_abs_diff_eq!(cov.values, a.cov(0.).unwrap(), epsilon = 1e-5);
+        assert!(cov.counts.iter().all(|&n| n == 10));
+        let corr = a.pearson_correlation_skipnan().unwrap();
+        assert_abs_diff_eq!(
+            corr.values,
+            a.pearson_correlation().unwrap(),
+            epsilon = 1e-5
+        );
+    }
+
+    #[test]
+    fn test_not_enough_observations_give_nan() {
+        let a = array![[1., f64::NAN, 3.], [f64::NAN, 2., 4.], [1., 1., 1.]];
+        let cov = a.cov_skipnan(1.).unwrap();
+        // Only one observation in common
+        assert_eq!(cov.counts[(0, 1)], 1);
+        assert!(cov.values[(0, 1)].is_nan());
+        assert_eq!(cov.values[(0, 0)], 2.);
+        let corr = a.pearson_correlation_skipnan().unwrap();
+        // Constant random variable
+        assert!(corr.values.row(2).iter().all(|x| x.is_nan()));
+        assert!(corr.values[(0, 1)].is_nan());
+    }
+
+    #[test]
+    fn test_all_nan_pair() {
+        let a = array![[f64::NAN, 1.], [2., f64::NAN]];
+        let corr = a.pearson_correlation_skipnan().unwrap();
+        assert_eq!(corr.counts, array![[1, 0], [0, 1]]);
+        assert!(corr.values.iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let a = Array2::<f64>::zeros((0, 3));
+        assert_eq!(a.cov_skipnan(1.), Err(EmptyInput));
+        let b = Array2::<f64>::zeros((3, 0));
+        assert_eq!(b.pearson_correlation_skipnan(), Err(EmptyInput));
+    }
+}
+
+#[cfg(test)]
+mod significance_tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn test_average_ranks() {
+        let a = array![3., 1., 2., 2., 5.];
+        assert_eq!(average_ranks(&a), array![4., 1., 2.5, 2.5, 5.]);
+    }
+
+    #[test]
+    fn test_pearson_correlation_test() {
+        let a = array![[1., 2., 3., 4.], [1., 3., 2., 4.]];
+        let test = a.pearson_correlation_test(0.95).unwrap();
+        assert_abs_diff_eq!(test.coefficient[(0, 1)], 0.8, epsilon = 1e-12);
+        assert_abs_diff_eq!(test.p_value[(0, 1)], 0.2, epsilon = 1e-10);
+        assert_abs_diff_eq!(test.p_value[(1, 0)], 0.2, epsilon = 1e-10);
+        assert_abs_diff_eq!(
+            test.lower_bound[(0, 1)],
+            -0.6969534452993225,
+            epsilon = 1e-8
+        );
+        assert_abs_diff_eq!(test.upper_bound[(0, 1)], 0.9956002504665856, epsilon = 1e-8);
+        assert_eq!(test.coefficient.diag(), array![1., 1.]);
+        assert_eq!(test.p_value.diag(), array![0., 0.]);
+        assert!(test.statistic[(0, 0)].is_infinite());
+    }
+
+    #[test]
+    fn test_spearman_correlation_test() {
+        let a = array![[1., 2., 3., 4.], [1., 3., 2., 4.]];
+        let test = a.spearman_correlation_test(0.95).unwrap();
+        assert_abs_diff_eq!(test.coefficient[(0, 1)], 0.8, epsilon = 1e-12);
+        assert_abs_diff_eq!(test.p_value[(0, 1)], 0.2, epsilon = 1e-10);
+        assert_abs_diff_eq!(
+            test.lower_bound[(0, 1)],
+            -0.7255633198751879,
+            epsilon = 1e-8
+        );
+        assert_abs_diff_eq!(test.upper_bound[(0, 1)], 0.996080737597307, epsilon = 1e-8);
+
+        let b = array![[1., 2., 2., 3., 4., 5.], [2., 1., 3., 3., 5., 4.]];
+        let test = b.spearman_correlation_test(0.95).unwrap();
+        assert_abs_diff_eq!(
+            test.coefficient[(0, 1)],
+            0.8088235294117647,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(test.statistic[(0, 1)], 2.7508597780419546, epsilon = 1e-10);
+        assert_abs_diff_eq!(test.p_value[(0, 1)], 0.05132906319967412, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_spearman_is_invariant_under_monotonic_transformations() {
+        let a = array![[0.5, -2., 3., 1.5, 7.], [1., 0., 2., 2., 9.]];
+        let b = a.mapv(|x: f64| x.powi(3) + x);
+        assert_abs_diff_eq!(
+            a.spearman_correlation().unwrap(),
+            b.spearman_correlation().unwrap(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            a.kendall_correlation().unwrap(),
+            b.kendall_correlation().unwrap(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_kendall_correlation_test() {
+        let a = array![[1., 2., 3., 4.], [1., 3., 2., 4.]];
+        let test = a.kendall_correlation_test(0.95).unwrap();
+        assert_abs_diff_eq!(test.coefficient[(0, 1)], 2. / 3., epsilon = 1e-12);
+        assert_abs_diff_eq!(test.statistic[(0, 1)], 1.3587324409735149, epsilon = 1e-10);
+        assert_abs_diff_eq!(test.p_value[(0, 1)], 0.17423138824802512, epsilon = 1e-10);
+        assert!(test.lower_bound[(0, 1)].is_nan());
+        assert!(test.upper_bound[(0, 1)].is_nan());
+    }
+
+    #[test]
+    fn test_kendall_correlation_test_with_ties() {
+        let a = array![[1., 2., 2., 3., 4., 5.], [2., 1., 3., 3., 5., 4.]];
+        let test = a.kendall_correlation_test(0.95).unwrap();
+        assert_abs_diff_eq!(
+            test.coefficient[(0, 1)],
+            0.6428571428571429,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(test.statistic[(0, 1)], 1.7516226243634268, epsilon = 1e-10);
+        assert_abs_diff_eq!(test.p_value[(0, 1)], 0.07983871964585258, epsilon = 1e-10);
+        assert_abs_diff_eq!(
+            test.lower_bound[(0, 1)],
+            -0.1519511435292958,
+            epsilon = 1e-8
+        );
+        assert_abs_diff_eq!(test.upper_bound[(0, 1)], 0.9327568020112925, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_undefined_coefficients_are_nan_on_the_diagonal() {
+        let a = array![[1., 2., 3., 4., 5.], [2., 2., 2., 2., 2.]];
+        for test in &[
+            a.pearson_correlation_test(0.95).unwrap(),
+            a.spearman_correlation_test(0.95).unwrap(),
+            a.kendall_correlation_test(0.95).unwrap(),
+        ] {
+            assert_eq!(test.coefficient[(0, 0)], 1.);
+            assert_eq!(test.p_value[(0, 0)], 0.);
+            for m in &[
+                &test.coefficient,
+                &test.statistic,
+                &test.p_value,
+                &test.lower_bound,
+                &test.upper_bound,
+            ] {
+                assert!(m[(1, 1)].is_nan());
+            }
+            assert!(test.coefficient[(0, 1)].is_nan());
+        }
+    }
+
+    #[test]
+    fn test_rank_correlations_with_nan_values() {
+        let a = array![[1., 2., 3., 4.], [1., f64::NAN, 2., 4.], [4., 3., 1., 2.]];
+        let spearman = a.spearman_correlation().unwrap();
+        let kendall = a.kendall_correlation().unwrap();
+        for corr in &[spearman, kendall] {
+            assert!(corr.row(1).iter().all(|x| x.is_nan()));
+            assert!(corr.column(1).iter().all(|x| x.is_nan()));
+            assert!(corr[(0, 2)] < 0.);
+        }
+        assert!(average_ranks(&array![1., f64::NAN])
+            .iter()
+            .all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn test_perfect_correlation() {
+        let a = array![[1., 2., 3., 4., 5.], [2., 4., 6., 8., 10.]];
+        let test = a.pearson_correlation_test(0.9).unwrap();
+        assert_abs_diff_eq!(test.p_value[(0, 1)], 0., epsilon = 1e-12);
+        assert_abs_diff_eq!(test.lower_bound[(0, 1)], 1., epsilon = 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_confidence_level_panics() {
+        let a = array![[1., 2., 3., 4., 5.], [2., 1., 3., 5., 4.]];
+        let _ = a.pearson_correlation_test(1.);
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let a = Array2::<f64>::zeros((2, 0));
+        assert_eq!(a.spearman_correlation(), Err(EmptyInput));
+        assert_eq!(a.kendall_correlation(), Err(EmptyInput));
+        assert_eq!(a.pearson_correlation_test(0.95), Err(EmptyInput));
+        assert_eq!(a.kendall_correlation_test(0.95), Err(EmptyInput));
+    }
+}
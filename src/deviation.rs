@@ -1,9 +1,147 @@
-use ndarray::{ArrayBase, Data, Dimension, Zip};
-use num_traits::{Signed, ToPrimitive};
+use ndarray::{Array, ArrayBase, Axis, Data, Dimension, RemoveAxis, Zip};
+use num_traits::{Float, Signed, ToPrimitive};
 use std::convert::Into;
 use std::ops::AddAssign;
 
 use crate::errors::MultiInputError;
+use crate::lanes::map_lanes;
+
+/// The [Bland–Altman] statistics of the agreement between two measurement methods.
+///
+/// [Bland–Altman]: https://en.wikipedia.org/wiki/Bland%E2%80%93Altman_plot
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlandAltman {
+    /// The mean of the differences between the two methods (the systematic bias).
+    pub bias: f64,
+    /// The sample standard deviation of the differences (with `ddof = 1`).
+    pub std_dev: f64,
+    /// The lower limit of agreement, `bias - 1.96 * std_dev`.
+    pub lower_limit: f64,
+    /// The upper limit of agreement, `bias + 1.96 * std_dev`.
+    pub upper_limit: f64,
+}
+
+/// The tolerance within which two floating-point numbers `a` and `b` are considered
+/// equal by [`count_close`] and related methods.
+///
+/// NaN is never close to anything, while infinities are only close to themselves.
+///
+/// [`count_close`]: trait.DeviationExt.html#tymethod.count_close
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tolerance<A> {
+    /// `|a - b| ≤ tol`
+    Absolute(A),
+    /// `|a - b| ≤ tol · max(|a|, |b|)`
+    Relative(A),
+    /// `|a - b| ≤ max(absolute, relative · max(|a|, |b|))`, i.e. `a` and `b` are close
+    /// within either tolerance.
+    AbsoluteOrRelative { absolute: A, relative: A },
+    /// `a` and `b` are at most the given number of [units in the last place] apart,
+    /// i.e. there are at most that many representable numbers between them
+    /// (excluding `a`, including `b`). Zero and negative zero are 0 ULPs apart.
+    ///
+    /// [units in the last place]: https://en.wikipedia.org/wiki/Unit_in_the_last_place
+    Ulps(u64),
+}
+
+impl<A> Tolerance<A>
+where
+    A: Float,
+{
+    /// Returns `true` if `a` and `b` are equal within the tolerance.
+    ///
+    /// **Panics** if the tolerance is negative or NaN.
+    pub fn is_close(&self, a: A, b: A) -> bool {
+        self.validate();
+        if a == b {
+            return true;
+        }
+        if !a.is_finite() || !b.is_finite() {
+            // NaN, or infinities with different values
+            return false;
+        }
+        let diff = (a - b).abs();
+        let scale = a.abs().max(b.abs());
+        match *self {
+            Tolerance::Absolute(tol) => diff <= tol,
+            Tolerance::Relative(tol) => diff <= tol * scale,
+            Tolerance::AbsoluteOrRelative { absolute, relative } => {
+                diff <= absolute || diff <= relative * scale
+            }
+            Tolerance::Ulps(max_ulps) => ulps_between(a, b) <= max_ulps,
+        }
+    }
+
+    /// **Panics** if any of the tolerances is negative or NaN.
+    fn validate(&self) {
+        let is_valid = |tol: A| tol >= A::zero();
+        let valid = match *self {
+            Tolerance::Absolute(tol) | Tolerance::Relative(tol) => is_valid(tol),
+            Tolerance::AbsoluteOrRelative { absolute, relative } => {
+                is_valid(absolute) && is_valid(relative)
+            }
+            Tolerance::Ulps(_) => true,
+        };
+        assert!(valid, "The tolerance must be non-negative.");
+    }
+}
+
+/// Returns the number of representable numbers of type `A` between `a` (excluded) and
+/// `b` (included), both finite or infinite.
+fn ulps_between<A: Float>(a: A, b: A) -> u64 {
+    let diff = i128::from(ordinal(a)) - i128::from(ordinal(b));
+    (if diff < 0 { -diff } else { diff }) as u64
+}
+
+/// Maps a non-NaN floating-point number to an integer, so that consecutive
+/// representable numbers have consecutive ordinals and zero has ordinal 0.
+fn ordinal<A: Float>(x: A) -> i64 {
+    // `integer_decode` writes x = sign · mantissa · 2^exponent: normal numbers have an
+    // implicit leading bit in the mantissa, while subnormal numbers may be returned
+    // with the exponent of the smallest normal number minus one.
+    let (implicit_bit, _, _) = A::one().integer_decode();
+    let (_, min_exponent, _) = A::min_positive_value().integer_decode();
+    let (mantissa, exponent, sign) = x.integer_decode();
+    let magnitude = if x.abs() >= A::min_positive_value() {
+        (i64::from(exponent) - i64::from(min_exponent) + 1) * implicit_bit as i64
+            + (mantissa - implicit_bit) as i64
+    } else {
+        (mantissa >> (min_exponent - exponent)) as i64
+    };
+    i64::from(sign) * magnitude
+}
+
+/// A pair of elements that are not equal within a [`Tolerance`].
+///
+/// [`Tolerance`]: enum.Tolerance.html
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mismatch<A, D: Dimension> {
+    /// The index of the elements.
+    pub index: D::Pattern,
+    /// The element of the first array.
+    pub value: A,
+    /// The element of the second array.
+    pub other: A,
+    /// The absolute difference `|a - b|`.
+    pub abs_diff: A,
+    /// The relative difference `|a - b| / max(|a|, |b|)`.
+    pub rel_diff: A,
+}
+
+/// The elements of two arrays that are not equal within a [`Tolerance`],
+/// returned by [`mismatch_report`].
+///
+/// [`Tolerance`]: enum.Tolerance.html
+/// [`mismatch_report`]: trait.DeviationExt.html#tymethod.mismatch_report
+#[derive(Clone, Debug, PartialEq)]
+pub struct MismatchReport<A, D: Dimension> {
+    /// The number of mismatching elements.
+    pub n_mismatches: usize,
+    /// The number of compared elements.
+    pub n_elements: usize,
+    /// The largest mismatches, by decreasing absolute difference (NaN first).
+    pub largest: Vec<Mismatch<A, D>>,
+}
 
 /// An extension trait for `ArrayBase` providing functions
 /// to compute different deviation measures.
@@ -36,6 +174,98 @@ where
         A: PartialEq,
         T: Data<Elem = A>;
 
+    /// Counts the number of indices at which the elements of the arrays `self`
+    /// and `other` are equal within `tolerance` (see [`Tolerance`]).
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if the tolerance is negative or NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{DeviationExt, Tolerance};
+    ///
+    /// let a = array![1., 0.1 + 0.2, 1e10, 0.];
+    /// let b = array![1., 0.3, 1e10 + 1., 1e-12];
+    /// assert_eq!(a.count_eq(&b).unwrap(), 1);
+    /// assert_eq!(a.count_close(&b, Tolerance::Ulps(4)).unwrap(), 2);
+    /// assert_eq!(a.count_close(&b, Tolerance::Relative(1e-9)).unwrap(), 3);
+    /// assert_eq!(a.count_close(&b, Tolerance::Absolute(1e-9)).unwrap(), 3);
+    /// ```
+    ///
+    /// [`Tolerance`]: enum.Tolerance.html
+    fn count_close<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        tolerance: Tolerance<A>,
+    ) -> Result<usize, MultiInputError>
+    where
+        A: Float,
+        T: Data<Elem = A>;
+
+    /// Counts the number of indices at which the elements of the arrays `self`
+    /// and `other` are not equal within `tolerance` (see [`Tolerance`]).
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if the tolerance is negative or NaN.
+    ///
+    /// [`Tolerance`]: enum.Tolerance.html
+    fn count_not_close<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        tolerance: Tolerance<A>,
+    ) -> Result<usize, MultiInputError>
+    where
+        A: Float,
+        T: Data<Elem = A>;
+
+    /// Compares `self` and `other` within `tolerance` (see [`Tolerance`]), and returns
+    /// the number of mismatching elements together with the `n_largest` mismatches
+    /// with the largest absolute difference (or all of them, if there are fewer).
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if the tolerance is negative or NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{DeviationExt, Tolerance};
+    ///
+    /// let expected = array![[1., 2.], [3., 4.]];
+    /// let computed = array![[1., 2.5], [3.0000001, 5.]];
+    /// let report = computed
+    ///     .mismatch_report(&expected, Tolerance::Relative(1e-6), 1)
+    ///     .unwrap();
+    /// assert_eq!(report.n_mismatches, 2);
+    /// assert_eq!(report.largest[0].index, (1, 1));
+    /// assert_eq!(report.largest[0].abs_diff, 1.);
+    /// ```
+    ///
+    /// [`Tolerance`]: enum.Tolerance.html
+    fn mismatch_report<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        tolerance: Tolerance<A>,
+        n_largest: usize,
+    ) -> Result<MismatchReport<A, D>, MultiInputError>
+    where
+        A: Float,
+        T: Data<Elem = A>;
+
     /// Computes the [squared L2 distance] between `self` and `other`.
     ///
     /// ```text
@@ -214,6 +444,349 @@ where
         A: AddAssign + Clone + Signed + ToPrimitive,
         T: Data<Elem = A>;
 
+    /// Computes [Lin's concordance correlation coefficient] between `self` and `other`.
+    ///
+    /// ```text
+    ///               2 sₐᵦ
+    /// ρ꜀ = ――――――――――――――――――――――
+    ///      sₐ² + sᵦ² + (ā - b̄)²
+    /// ```
+    ///
+    /// where `self` is `a`, `other` is `b`, `ā` and `b̄` are their means, `sₐ²` and `sᵦ²`
+    /// their (biased, i.e. normalised by `n`) variances and `sₐᵦ` their covariance.
+    /// It measures how far the pairs `(aᵢ, bᵢ)` deviate from the line `a = b`: it is 1
+    /// for perfect agreement, while a perfect linear relationship with a different slope
+    /// or an offset gives a value smaller than 1.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if the type cast from `A` to `f64` fails.
+    ///
+    /// [Lin's concordance correlation coefficient]: https://en.wikipedia.org/wiki/Concordance_correlation_coefficient
+    fn concordance_correlation<T>(&self, other: &ArrayBase<T, D>) -> Result<f64, MultiInputError>
+    where
+        A: Clone + ToPrimitive,
+        T: Data<Elem = A>;
+
+    /// Computes the [Bland–Altman] bias and 95% limits of agreement between
+    /// `self` and `other`.
+    ///
+    /// The differences `dᵢ = aᵢ - bᵢ` are computed, where `self` is `a` and `other` is `b`;
+    /// the bias is their mean `d̄` and the limits of agreement are
+    ///
+    /// ```text
+    /// d̄ ± 1.96 s
+    /// ```
+    ///
+    /// where `s` is the sample standard deviation of the differences. If `self` holds
+    /// a single element, the standard deviation and the limits of agreement are NaN.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if the type cast from `A` to `f64` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::DeviationExt;
+    ///
+    /// let new_method = array![10.2, 11.8, 9.9, 12.5];
+    /// let reference = array![10., 12., 10., 12.];
+    /// let agreement = new_method.bland_altman(&reference).unwrap();
+    /// assert!((agreement.bias - 0.1).abs() < 1e-12);
+    /// assert!(agreement.lower_limit < agreement.bias);
+    /// assert!(agreement.bias < agreement.upper_limit);
+    /// ```
+    ///
+    /// [Bland–Altman]: https://en.wikipedia.org/wiki/Bland%E2%80%93Altman_plot
+    fn bland_altman<T>(&self, other: &ArrayBase<T, D>) -> Result<BlandAltman, MultiInputError>
+    where
+        A: Clone + ToPrimitive,
+        T: Data<Elem = A>;
+
+    /// Counts the number of indices at which the elements of `self` and `other`
+    /// are equal (see [`count_eq`]) for each lane along `axis`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`count_eq`]: #tymethod.count_eq
+    fn count_eq_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<usize, D::Smaller>, MultiInputError>
+    where
+        A: PartialEq,
+        T: Data<Elem = A>,
+        D: RemoveAxis;
+
+    /// Counts the number of indices at which the elements of `self` and `other`
+    /// are not equal (see [`count_neq`]) for each lane along `axis`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`count_neq`]: #tymethod.count_neq
+    fn count_neq_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<usize, D::Smaller>, MultiInputError>
+    where
+        A: PartialEq,
+        T: Data<Elem = A>,
+        D: RemoveAxis;
+
+    /// Computes the squared L2 distance between `self` and `other` along `axis`
+    /// (see [`sq_l2_dist`]), i.e. for each lane of `self` and the corresponding lane
+    /// of `other`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`sq_l2_dist`]: #tymethod.sq_l2_dist
+    fn sq_l2_dist_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed,
+        T: Data<Elem = A>,
+        D: RemoveAxis;
+
+    /// Computes the L2 distance between `self` and `other` along `axis`
+    /// (see [`l2_dist`]), i.e. for each lane of `self` and the corresponding lane
+    /// of `other`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds or if the type cast from `A` to `f64` fails.
+    ///
+    /// [`l2_dist`]: #tymethod.l2_dist
+    fn l2_dist_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<f64, D::Smaller>, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive,
+        T: Data<Elem = A>,
+        D: RemoveAxis;
+
+    /// Computes the L1 distance between `self` and `other` along `axis`
+    /// (see [`l1_dist`]), i.e. for each lane of `self` and the corresponding lane
+    /// of `other`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`l1_dist`]: #tymethod.l1_dist
+    fn l1_dist_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed,
+        T: Data<Elem = A>,
+        D: RemoveAxis;
+
+    /// Computes the L∞ distance between `self` and `other` along `axis`
+    /// (see [`linf_dist`]), i.e. for each lane of `self` and the corresponding lane
+    /// of `other`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// [`linf_dist`]: #tymethod.linf_dist
+    fn linf_dist_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: Clone + PartialOrd + Signed,
+        T: Data<Elem = A>,
+        D: RemoveAxis;
+
+    /// Computes the mean absolute error between `self` and `other` along `axis`
+    /// (see [`mean_abs_err`]), i.e. for each lane of `self` and the corresponding lane
+    /// of `other`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds or if the type cast from `A` to `f64` fails.
+    ///
+    /// [`mean_abs_err`]: #tymethod.mean_abs_err
+    fn mean_abs_err_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<f64, D::Smaller>, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive,
+        T: Data<Elem = A>,
+        D: RemoveAxis;
+
+    /// Computes the mean squared error between `self` and `other` along `axis`
+    /// (see [`mean_sq_err`]), i.e. for each lane of `self` and the corresponding lane
+    /// of `other`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds or if the type cast from `A` to `f64` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::DeviationExt;
+    ///
+    /// // Predictions and targets of a model with two outputs (one per column)
+    /// let predictions = array![[1., 10.], [2., 20.], [3., 30.]];
+    /// let targets = array![[1., 12.], [2., 18.], [4., 30.]];
+    /// let mse = predictions.mean_sq_err_axis(&targets, Axis(0)).unwrap();
+    /// assert_eq!(mse, array![1. / 3., 8. / 3.]);
+    /// ```
+    ///
+    /// [`mean_sq_err`]: #tymethod.mean_sq_err
+    fn mean_sq_err_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<f64, D::Smaller>, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive,
+        T: Data<Elem = A>,
+        D: RemoveAxis;
+
+    /// Computes the root-mean-square error between `self` and `other` along `axis`
+    /// (see [`root_mean_sq_err`]), i.e. for each lane of `self` and the corresponding lane
+    /// of `other`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds or if the type cast from `A` to `f64` fails.
+    ///
+    /// [`root_mean_sq_err`]: #tymethod.root_mean_sq_err
+    fn root_mean_sq_err_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<f64, D::Smaller>, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive,
+        T: Data<Elem = A>,
+        D: RemoveAxis;
+
+    /// Computes the peak signal-to-noise ratio between `self` and `other` along `axis`
+    /// (see [`peak_signal_to_noise_ratio`]), i.e. for each lane of `self` and the corresponding lane
+    /// of `other`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds or if the type cast from `A` to `f64` fails.
+    ///
+    /// [`peak_signal_to_noise_ratio`]: #tymethod.peak_signal_to_noise_ratio
+    fn peak_signal_to_noise_ratio_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        maxv: A,
+        axis: Axis,
+    ) -> Result<Array<f64, D::Smaller>, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive,
+        T: Data<Elem = A>,
+        D: RemoveAxis;
+
+    /// Computes Lin's concordance correlation coefficient between `self` and `other` along `axis`
+    /// (see [`concordance_correlation`]), i.e. for each lane of `self` and the corresponding lane
+    /// of `other`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds or if the type cast from `A` to `f64` fails.
+    ///
+    /// [`concordance_correlation`]: #tymethod.concordance_correlation
+    fn concordance_correlation_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<f64, D::Smaller>, MultiInputError>
+    where
+        A: Clone + ToPrimitive,
+        T: Data<Elem = A>,
+        D: RemoveAxis;
+
+    /// Computes the Bland–Altman bias and limits of agreement between `self` and `other` along `axis`
+    /// (see [`bland_altman`]), i.e. for each lane of `self` and the corresponding lane
+    /// of `other`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds or if the type cast from `A` to `f64` fails.
+    ///
+    /// [`bland_altman`]: #tymethod.bland_altman
+    fn bland_altman_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<BlandAltman, D::Smaller>, MultiInputError>
+    where
+        A: Clone + ToPrimitive,
+        T: Data<Elem = A>,
+        D: RemoveAxis;
+
     private_decl! {}
 }
 
@@ -249,6 +822,83 @@ where
         self.count_eq(other).map(|n_eq| self.len() - n_eq)
     }
 
+    fn count_close<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        tolerance: Tolerance<A>,
+    ) -> Result<usize, MultiInputError>
+    where
+        A: Float,
+        T: Data<Elem = A>,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, other);
+
+        let mut count = 0;
+
+        Zip::from(self).and(other).apply(|&a, &b| {
+            if tolerance.is_close(a, b) {
+                count += 1;
+            }
+        });
+
+        Ok(count)
+    }
+
+    fn count_not_close<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        tolerance: Tolerance<A>,
+    ) -> Result<usize, MultiInputError>
+    where
+        A: Float,
+        T: Data<Elem = A>,
+    {
+        self.count_close(other, tolerance)
+            .map(|n_close| self.len() - n_close)
+    }
+
+    fn mismatch_report<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        tolerance: Tolerance<A>,
+        n_largest: usize,
+    ) -> Result<MismatchReport<A, D>, MultiInputError>
+    where
+        A: Float,
+        T: Data<Elem = A>,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, other);
+
+        let mut mismatches = Vec::new();
+        for ((index, &a), &b) in self.indexed_iter().zip(other) {
+            if !tolerance.is_close(a, b) {
+                let abs_diff = (a - b).abs();
+                mismatches.push(Mismatch {
+                    index,
+                    value: a,
+                    other: b,
+                    abs_diff,
+                    rel_diff: abs_diff / a.abs().max(b.abs()),
+                });
+            }
+        }
+        let n_mismatches = mismatches.len();
+        // NaN differences are the largest ones
+        mismatches.sort_by(|x, y| match (x.abs_diff.is_nan(), y.abs_diff.is_nan()) {
+            (false, false) => y.abs_diff.partial_cmp(&x.abs_diff).unwrap(),
+            (x_nan, y_nan) => y_nan.cmp(&x_nan),
+        });
+        mismatches.truncate(n_largest);
+
+        Ok(MismatchReport {
+            n_mismatches,
+            n_elements: self.len(),
+            largest: mismatches,
+        })
+    }
+
     fn sq_l2_dist<T>(&self, other: &ArrayBase<T, D>) -> Result<A, MultiInputError>
     where
         A: AddAssign + Clone + Signed,
@@ -373,5 +1023,218 @@ where
         Ok(psnr)
     }
 
+    fn concordance_correlation<T>(&self, other: &ArrayBase<T, D>) -> Result<f64, MultiInputError>
+    where
+        A: Clone + ToPrimitive,
+        T: Data<Elem = A>,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, other);
+
+        let to_f64 = |x: &A| x.to_f64().expect("failed cast from type A to f64");
+        let n = self.len() as f64;
+        let mean_a = self.iter().map(to_f64).sum::<f64>() / n;
+        let mean_b = other.iter().map(to_f64).sum::<f64>() / n;
+
+        let (mut var_a, mut var_b, mut cov) = (0., 0., 0.);
+        Zip::from(self).and(other).apply(|a, b| {
+            let (da, db) = (to_f64(a) - mean_a, to_f64(b) - mean_b);
+            var_a += da * da;
+            var_b += db * db;
+            cov += da * db;
+        });
+        let mean_diff = mean_a - mean_b;
+
+        Ok(2. * cov / n / ((var_a + var_b) / n + mean_diff * mean_diff))
+    }
+
+    fn bland_altman<T>(&self, other: &ArrayBase<T, D>) -> Result<BlandAltman, MultiInputError>
+    where
+        A: Clone + ToPrimitive,
+        T: Data<Elem = A>,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, other);
+
+        let to_f64 = |x: &A| x.to_f64().expect("failed cast from type A to f64");
+        let mut differences = Vec::with_capacity(self.len());
+        Zip::from(self).and(other).apply(|a, b| {
+            differences.push(to_f64(a) - to_f64(b));
+        });
+        let n = differences.len() as f64;
+        let bias = differences.iter().sum::<f64>() / n;
+        let sum_sq = differences
+            .iter()
+            .map(|d| (d - bias) * (d - bias))
+            .sum::<f64>();
+        let std_dev = (sum_sq / (n - 1.)).sqrt();
+
+        Ok(BlandAltman {
+            bias,
+            std_dev,
+            lower_limit: bias - 1.96 * std_dev,
+            upper_limit: bias + 1.96 * std_dev,
+        })
+    }
+
+    fn count_eq_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<usize, D::Smaller>, MultiInputError>
+    where
+        A: PartialEq,
+        T: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        map_lanes(self, other, axis, |a, b| a.count_eq(&b))
+    }
+
+    fn count_neq_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<usize, D::Smaller>, MultiInputError>
+    where
+        A: PartialEq,
+        T: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        map_lanes(self, other, axis, |a, b| a.count_neq(&b))
+    }
+
+    fn sq_l2_dist_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed,
+        T: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        map_lanes(self, other, axis, |a, b| a.sq_l2_dist(&b))
+    }
+
+    fn l2_dist_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<f64, D::Smaller>, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive,
+        T: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        map_lanes(self, other, axis, |a, b| a.l2_dist(&b))
+    }
+
+    fn l1_dist_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed,
+        T: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        map_lanes(self, other, axis, |a, b| a.l1_dist(&b))
+    }
+
+    fn linf_dist_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        A: Clone + PartialOrd + Signed,
+        T: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        map_lanes(self, other, axis, |a, b| a.linf_dist(&b))
+    }
+
+    fn mean_abs_err_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<f64, D::Smaller>, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive,
+        T: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        map_lanes(self, other, axis, |a, b| a.mean_abs_err(&b))
+    }
+
+    fn mean_sq_err_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<f64, D::Smaller>, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive,
+        T: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        map_lanes(self, other, axis, |a, b| a.mean_sq_err(&b))
+    }
+
+    fn root_mean_sq_err_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<f64, D::Smaller>, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive,
+        T: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        map_lanes(self, other, axis, |a, b| a.root_mean_sq_err(&b))
+    }
+
+    fn peak_signal_to_noise_ratio_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        maxv: A,
+        axis: Axis,
+    ) -> Result<Array<f64, D::Smaller>, MultiInputError>
+    where
+        A: AddAssign + Clone + Signed + ToPrimitive,
+        T: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        map_lanes(self, other, axis, |a, b| {
+            a.peak_signal_to_noise_ratio(&b, maxv.clone())
+        })
+    }
+
+    fn concordance_correlation_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<f64, D::Smaller>, MultiInputError>
+    where
+        A: Clone + ToPrimitive,
+        T: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        map_lanes(self, other, axis, |a, b| a.concordance_correlation(&b))
+    }
+
+    fn bland_altman_axis<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        axis: Axis,
+    ) -> Result<Array<BlandAltman, D::Smaller>, MultiInputError>
+    where
+        A: Clone + ToPrimitive,
+        T: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        map_lanes(self, other, axis, |a, b| a.bland_altman(&b))
+    }
+
     private_impl! {}
 }
@@ -0,0 +1,656 @@
+use crate::errors::{MultiInputError, ShapeMismatch};
+use ndarray::prelude::*;
+use ndarray::{Data, RemoveAxis};
+use num_traits::{Float, FromPrimitive};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// The outcome of a permutation test of independence based on the distance correlation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistanceCorrelationTest<A> {
+    /// The distance correlation of the two samples.
+    pub distance_correlation: A,
+    /// The p-value of the permutation test, i.e. the fraction of permutations
+    /// (including the identity) whose distance covariance is at least as large as
+    /// the observed one.
+    pub p_value: A,
+}
+
+/// Extension trait for `ArrayBase` providing functions to compute
+/// [distance covariance and distance correlation], measures of dependence
+/// between two random vectors that, unlike Pearson correlation, are zero
+/// if and only if the random vectors are independent.
+///
+/// The first axis of each array indexes the observations: a 1-dimensional array
+/// of length `n` holds `n` observations of a scalar random variable, while a
+/// 2-dimensional array of shape `(n, p)` holds `n` observations of a `p`-dimensional
+/// random vector (one per row). Higher-dimensional observations are flattened.
+/// The two arrays may have different dimensionalities, e.g. the observations of a
+/// scalar random variable may be paired with those of a random vector.
+///
+/// [distance covariance and distance correlation]: https://en.wikipedia.org/wiki/Distance_correlation
+pub trait DistanceCorrelationExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Computes the sample [distance covariance] of `self` and `other`.
+    ///
+    /// Let `aⱼₖ = ‖xⱼ - xₖ‖` and `bⱼₖ = ‖yⱼ - yₖ‖` be the Euclidean distances between
+    /// the observations of `self` (`x`) and `other` (`y`), and let `Aⱼₖ` and `Bⱼₖ` be the
+    /// corresponding doubly centered distances (the row and column means are subtracted
+    /// and the grand mean is added back). The distance covariance is
+    ///
+    /// ```text
+    ///        ⎛ 1   n   n          ⎞ ½
+    /// dCov = ⎜ ―   ∑   ∑  Aⱼₖ Bⱼₖ ⎟
+    ///        ⎝ n² j=1 k=1         ⎠
+    /// ```
+    ///
+    /// If both `self` and `other` hold scalar observations, the computation takes
+    /// *O(n log n)* time ([Chaudhuri and Hu, 2019]); otherwise it takes *O(n²)* time.
+    /// The distance covariance is NaN if `self` or `other` contains a NaN value.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` or `other` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same
+    ///   number of observations (the observations themselves may have different dimensions)
+    ///
+    /// **Panics** if the type cast from `usize` to `A` fails.
+    ///
+    /// [distance covariance]: https://en.wikipedia.org/wiki/Distance_correlation#Distance_covariance
+    /// [Chaudhuri and Hu, 2019]: https://doi.org/10.1016/j.csda.2019.01.016
+    fn distance_covariance<T, E>(&self, other: &ArrayBase<T, E>) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+        E: RemoveAxis;
+
+    /// Computes the sample [distance correlation] of `self` and `other`:
+    ///
+    /// ```text
+    ///                 dCov(x, y)
+    /// dCor = ―――――――――――――――――――――――――
+    ///        √(dCov(x, x) dCov(y, y))
+    /// ```
+    ///
+    /// (see [`distance_covariance`]). It lies between 0 and 1; it is 0 if either
+    /// sample is constant and NaN if `self` or `other` contains a NaN value.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` or `other` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same
+    ///   number of observations (the observations themselves may have different dimensions)
+    ///
+    /// **Panics** if the type cast from `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::DistanceCorrelationExt;
+    ///
+    /// // A dependence that Pearson correlation can't detect
+    /// let x = array![-2., -1., 0., 1., 2.];
+    /// let y = x.mapv(|x| x * x);
+    /// let dcor = x.distance_correlation(&y).unwrap();
+    /// assert!((dcor - 0.5159234568589328_f64).abs() < 1e-12);
+    /// ```
+    ///
+    /// [distance correlation]: https://en.wikipedia.org/wiki/Distance_correlation#Distance_correlation
+    /// [`distance_covariance`]: #tymethod.distance_covariance
+    fn distance_correlation<T, E>(&self, other: &ArrayBase<T, E>) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+        E: RemoveAxis;
+
+    /// Computes the distance correlation of `self` and `other`
+    /// (see [`distance_correlation`]) together with the p-value of a permutation
+    /// test of independence.
+    ///
+    /// The observations of `other` are shuffled `n_permutations` times using `rng`, and
+    /// the p-value is
+    ///
+    /// ```text
+    ///     1 + #{permutations with dCov ≥ observed dCov}
+    /// p = ――――――――――――――――――――――――――――――――――――――――――――――
+    ///                 1 + n_permutations
+    /// ```
+    ///
+    /// If `self` or `other` contains a NaN value, the distance correlation and the
+    /// p-value are NaN.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` or `other` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same
+    ///   number of observations (the observations themselves may have different dimensions)
+    ///
+    /// **Panics** if the type cast from `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::DistanceCorrelationExt;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let x = array![1., 2., 3., 4., 5., 6., 7., 8., 9., 10.];
+    /// let y = x.mapv(|x: f64| (x - 5.5).abs());
+    /// let mut rng = StdRng::seed_from_u64(42);
+    /// let test = x.distance_correlation_test(&y, 999, &mut rng).unwrap();
+    /// assert!(test.p_value > 0.);
+    /// assert!(test.p_value <= 1.);
+    /// ```
+    ///
+    /// [`distance_correlation`]: #tymethod.distance_correlation
+    fn distance_correlation_test<T, E, R>(
+        &self,
+        other: &ArrayBase<T, E>,
+        n_permutations: usize,
+        rng: &mut R,
+    ) -> Result<DistanceCorrelationTest<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+        E: RemoveAxis,
+        R: Rng + ?Sized;
+
+    private_decl! {}
+}
+
+impl<A, S, D> DistanceCorrelationExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: RemoveAxis,
+{
+    fn distance_covariance<T, E>(&self, other: &ArrayBase<T, E>) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+        E: RemoveAxis,
+    {
+        let (x, y) = observations(self, other)?;
+        Ok(non_negative(distance_covariance_sq(&x, &y)).sqrt())
+    }
+
+    fn distance_correlation<T, E>(&self, other: &ArrayBase<T, E>) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+        E: RemoveAxis,
+    {
+        let (x, y) = observations(self, other)?;
+        Ok(distance_correlation(&x, &y))
+    }
+
+    fn distance_correlation_test<T, E, R>(
+        &self,
+        other: &ArrayBase<T, E>,
+        n_permutations: usize,
+        rng: &mut R,
+    ) -> Result<DistanceCorrelationTest<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+        E: RemoveAxis,
+        R: Rng + ?Sized,
+    {
+        let (x, y) = observations(self, other)?;
+        let observed = distance_covariance_sq(&x, &y);
+        if observed.is_nan() {
+            return Ok(DistanceCorrelationTest {
+                distance_correlation: A::nan(),
+                p_value: A::nan(),
+            });
+        }
+        let mut order: Vec<usize> = (0..y.nrows()).collect();
+        let mut n_extreme = 1;
+        for _ in 0..n_permutations {
+            order.shuffle(rng);
+            let permuted = y.select(Axis(0), &order);
+            // Guard against rounding making an identical statistic look smaller
+            let tolerance = A::epsilon() * A::from_usize(x.nrows()).unwrap() * observed.abs();
+            if distance_covariance_sq(&x, &permuted) >= observed - tolerance {
+                n_extreme += 1;
+            }
+        }
+        Ok(DistanceCorrelationTest {
+            distance_correlation: distance_correlation(&x, &y),
+            p_value: A::from_usize(n_extreme).unwrap() / A::from_usize(n_permutations + 1).unwrap(),
+        })
+    }
+
+    private_impl! {}
+}
+
+/// Validates the inputs and reshapes them as `(n_observations, dimension)` matrices.
+fn observations<A, S, T, D, E>(
+    x: &ArrayBase<S, D>,
+    y: &ArrayBase<T, E>,
+) -> Result<(Array2<A>, Array2<A>), MultiInputError>
+where
+    A: Clone,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+    D: Dimension,
+    E: Dimension,
+{
+    return_err_if_empty!(x);
+    return_err_if_empty!(y);
+    let n = x.len_of(Axis(0));
+    if n != y.len_of(Axis(0)) {
+        return Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+            first_shape: x.shape().to_vec(),
+            second_shape: y.shape().to_vec(),
+        }));
+    }
+    let as_matrix = |shape: (usize, usize), values: Vec<A>| {
+        Array2::from_shape_vec(shape, values).expect("the shape matches the number of elements")
+    };
+    Ok((
+        as_matrix((n, x.len() / n), x.iter().cloned().collect()),
+        as_matrix((n, y.len() / n), y.iter().cloned().collect()),
+    ))
+}
+
+fn distance_correlation<A>(x: &Array2<A>, y: &Array2<A>) -> A
+where
+    A: Float + FromPrimitive,
+{
+    let denominator = (distance_covariance_sq(x, x) * distance_covariance_sq(y, y)).sqrt();
+    if denominator.is_nan() {
+        A::nan()
+    } else if denominator > A::zero() {
+        (non_negative(distance_covariance_sq(x, y)) / denominator)
+            .sqrt()
+            .min(A::one())
+    } else {
+        A::zero()
+    }
+}
+
+/// Clamps the negative values caused by rounding errors to zero, keeping NaN.
+fn non_negative<A: Float>(value: A) -> A {
+    if value < A::zero() {
+        A::zero()
+    } else {
+        value
+    }
+}
+
+/// Computes the squared sample distance covariance (a V-statistic), choosing
+/// the *O(n log n)* algorithm if both samples are univariate.
+///
+/// It is NaN if either sample contains a NaN value.
+fn distance_covariance_sq<A>(x: &Array2<A>, y: &Array2<A>) -> A
+where
+    A: Float + FromPrimitive,
+{
+    if x.iter().chain(y).any(|v| v.is_nan()) {
+        A::nan()
+    } else if x.ncols() == 1 && y.ncols() == 1 {
+        univariate_distance_covariance_sq(x.column(0), y.column(0))
+    } else {
+        let a = double_centered_distances(x);
+        let b = double_centered_distances(y);
+        let n = A::from_usize(x.nrows()).unwrap();
+        (a * b).sum() / (n * n)
+    }
+}
+
+fn double_centered_distances<A>(x: &Array2<A>) -> Array2<A>
+where
+    A: Float + FromPrimitive,
+{
+    let n = x.nrows();
+    let mut distances = Array2::zeros((n, n));
+    for j in 0..n {
+        for k in (j + 1)..n {
+            let d = x
+                .row(j)
+                .iter()
+                .zip(x.row(k))
+                .fold(A::zero(), |acc, (&a, &b)| acc + (a - b) * (a - b))
+                .sqrt();
+            distances[(j, k)] = d;
+            distances[(k, j)] = d;
+        }
+    }
+    let row_means = distances.mean_axis(Axis(1)).unwrap();
+    let grand_mean = row_means.mean().unwrap();
+    for ((j, k), d) in distances.indexed_iter_mut() {
+        *d = *d - row_means[j] - row_means[k] + grand_mean;
+    }
+    distances
+}
+
+/// Computes the squared distance covariance of two scalar samples as
+///
+/// ```text
+/// S₁/n² + S₂ - 2 S₃
+/// ```
+///
+/// where `S₁ = ∑ⱼₖ aⱼₖ bⱼₖ`, `S₂ = a‥ b‥ / n⁴` and `S₃ = ∑ⱼ aⱼ. bⱼ. / n³`.
+/// The row sums `aⱼ.` and `bⱼ.` are computed from the sorted samples, `S₁` with
+/// a Fenwick tree indexed by the ranks of `y`.
+fn univariate_distance_covariance_sq<A>(x: ArrayView1<A>, y: ArrayView1<A>) -> A
+where
+    A: Float + FromPrimitive,
+{
+    let n = x.len();
+    let n_a = A::from_usize(n).unwrap();
+    let a_rows = distance_row_sums(&x);
+    let b_rows = distance_row_sums(&y);
+    let a_total = a_rows.iter().fold(A::zero(), |acc, &v| acc + v);
+    let b_total = b_rows.iter().fold(A::zero(), |acc, &v| acc + v);
+    let s3 = a_rows
+        .iter()
+        .zip(&b_rows)
+        .fold(A::zero(), |acc, (&a, &b)| acc + a * b);
+    let s1 = sum_of_distance_products(&x, &y);
+    s1 / (n_a * n_a) + a_total * b_total / (n_a * n_a * n_a * n_a) - (s3 + s3) / (n_a * n_a * n_a)
+}
+
+/// Returns `∑ₖ |xⱼ - xₖ|` for every `j`.
+fn distance_row_sums<A>(x: &ArrayView1<A>) -> Vec<A>
+where
+    A: Float + FromPrimitive,
+{
+    let n = x.len();
+    let order = argsort(x);
+    let total = x.iter().fold(A::zero(), |acc, &v| acc + v);
+    let mut sums = vec![A::zero(); n];
+    let mut prefix = A::zero();
+    for (rank, &j) in order.iter().enumerate() {
+        let value = x[j];
+        let below = A::from_usize(rank).unwrap();
+        let above = A::from_usize(n - 1 - rank).unwrap();
+        let suffix = total - prefix - value;
+        sums[j] = value * below - prefix + suffix - value * above;
+        prefix = prefix + value;
+    }
+    sums
+}
+
+/// Returns `∑ⱼₖ |xⱼ - xₖ| |yⱼ - yₖ|`.
+///
+/// Visiting the observations in increasing order of `x`, the contribution of each pair
+/// is `±(xₖ - xⱼ)(yₖ - yⱼ)`, the sign depending on whether `yⱼ` is below or above `yₖ`:
+/// it can be expanded into sums of `1`, `xⱼ`, `yⱼ` and `xⱼyⱼ` over the previous
+/// observations with smaller (respectively larger) `y`, which are prefix sums over the
+/// ranks of `y`.
+fn sum_of_distance_products<A>(x: &ArrayView1<A>, y: &ArrayView1<A>) -> A
+where
+    A: Float + FromPrimitive,
+{
+    let n = x.len();
+    // Dense ranks of `y`, so that tied values share the same slot
+    let y_order = argsort(y);
+    let mut y_ranks = vec![0; n];
+    let mut rank = 0;
+    for (i, &j) in y_order.iter().enumerate() {
+        if i > 0 && y[j] != y[y_order[i - 1]] {
+            rank += 1;
+        }
+        y_ranks[j] = rank;
+    }
+
+    let mut tree = FenwickTree::new(rank + 1);
+    let mut totals = [A::zero(); 4];
+    let mut sum = A::zero();
+    for k in argsort(x) {
+        let (xk, yk) = (x[k], y[k]);
+        let below = tree.prefix_sum(y_ranks[k]);
+        let up_to = tree.prefix_sum(y_ranks[k] + 1);
+        let mut above = totals;
+        for (a, &u) in above.iter_mut().zip(&up_to) {
+            *a = *a - u;
+        }
+        // ∑ (xₖ - xⱼ)(yₖ - yⱼ) = count·xₖyₖ - xₖ∑yⱼ - yₖ∑xⱼ + ∑xⱼyⱼ
+        let expand = |s: &[A]| s[0] * xk * yk - xk * s[2] - yk * s[1] + s[3];
+        sum = sum + expand(&below) - expand(&above);
+        let entry = [A::one(), xk, yk, xk * yk];
+        tree.add(y_ranks[k], entry);
+        for i in 0..4 {
+            totals[i] = totals[i] + entry[i];
+        }
+    }
+    sum + sum
+}
+
+/// Returns the indices that sort `x` in increasing order.
+///
+/// **Panics** if `x` contains NaN values.
+fn argsort<A>(x: &ArrayView1<A>) -> Vec<usize>
+where
+    A: Float,
+{
+    let mut order: Vec<usize> = (0..x.len()).collect();
+    order.sort_by(|&i, &j| {
+        x[i].partial_cmp(&x[j])
+            .expect("Distance covariance is undefined for NaN values.")
+    });
+    order
+}
+
+/// A [Fenwick tree] of 4-component sums.
+///
+/// [Fenwick tree]: https://en.wikipedia.org/wiki/Fenwick_tree
+struct FenwickTree<A> {
+    nodes: Vec<[A; 4]>,
+}
+
+impl<A> FenwickTree<A>
+where
+    A: Float,
+{
+    fn new(len: usize) -> Self {
+        FenwickTree {
+            nodes: vec![[A::zero(); 4]; len + 1],
+        }
+    }
+
+    fn add(&mut self, index: usize, value: [A; 4]) {
+        let mut i = index + 1;
+        while i < self.nodes.len() {
+            for (node, &v) in self.nodes[i].iter_mut().zip(&value) {
+                *node = *node + v;
+            }
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Returns the sum of the values at the indices smaller than `end`.
+    fn prefix_sum(&self, end: usize) -> [A; 4] {
+        let mut sum = [A::zero(); 4];
+        let mut i = end;
+        while i > 0 {
+            for (s, &v) in sum.iter_mut().zip(&self.nodes[i]) {
+                *s = *s + v;
+            }
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+    use quickcheck_macros::quickcheck;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::f64;
+
+    #[test]
+    fn test_univariate_distance_correlation() {
+        let x = array![1., 2., 3., 4., 5.];
+        let y = x.mapv(|x| x * x);
+        assert_abs_diff_eq!(
+            x.distance_covariance(&y).unwrap(),
+            2.701110882581461,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            x.distance_correlation(&y).unwrap(),
+            0.9869160440537484,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_detects_nonlinear_dependence() {
+        let x = array![-2., -1., 0., 1., 2.];
+        let y = array![4., 1., 0., 1., 4.];
+        assert_abs_diff_eq!(
+            x.distance_covariance(&y).unwrap(),
+            0.6693280212272605,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            x.distance_correlation(&y).unwrap(),
+            0.5159234568589328,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_multivariate_distance_correlation() {
+        let x = array![[0., 1.], [1., 3.], [2., 2.], [4., 0.], [3., 5.]];
+        let y = array![[1.], [2.], [2.], [0.], [7.]];
+        assert_abs_diff_eq!(
+            x.distance_covariance(&y).unwrap(),
+            1.4919802773193416,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            x.distance_correlation(&y).unwrap(),
+            0.8981084696325675,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_samples_of_different_dimensionalities() {
+        let x = array![[0., 1.], [1., 3.], [2., 2.], [4., 0.], [3., 5.]];
+        let y = array![1., 2., 2., 0., 7.];
+        assert_eq!(
+            x.distance_correlation(&y).unwrap(),
+            x.distance_correlation(&y.clone().insert_axis(Axis(1)))
+                .unwrap()
+        );
+        assert_eq!(
+            y.distance_covariance(&x).unwrap(),
+            x.distance_covariance(&y).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_linear_relationship() {
+        let x = array![3., -1., 4., 1., 5., 9., 2., 6.];
+        let y = x.mapv(|x| 2. - 3. * x);
+        assert_abs_diff_eq!(x.distance_correlation(&y).unwrap(), 1., epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_constant_sample() {
+        let x = array![1., 2., 3.];
+        let y = array![5., 5., 5.];
+        assert_eq!(x.distance_correlation(&y).unwrap(), 0.);
+    }
+
+    #[quickcheck]
+    fn fast_algorithm_matches_quadratic_algorithm(pairs: Vec<(i8, i8)>) -> bool {
+        if pairs.is_empty() {
+            return true;
+        }
+        let n = pairs.len();
+        let x = Array2::from_shape_vec((n, 1), pairs.iter().map(|&(a, _)| f64::from(a)).collect())
+            .unwrap();
+        let y = Array2::from_shape_vec(
+            (n, 1),
+            pairs.iter().map(|&(_, b)| f64::from(b) / 4.).collect(),
+        )
+        .unwrap();
+        let n = x.nrows() as f64;
+        let fast = univariate_distance_covariance_sq(x.column(0), y.column(0));
+        let quadratic =
+            (double_centered_distances(&x) * double_centered_distances(&y)).sum() / (n * n);
+        (fast - quadratic).abs() < 1e-9 * (1. + quadratic.abs())
+    }
+
+    #[test]
+    fn test_permutation_test() {
+        let x = Array::linspace(0., 1., 30);
+        let y = x.mapv(|x| (x - 0.5) * (x - 0.5));
+        let mut rng = StdRng::seed_from_u64(0);
+        let test = x.distance_correlation_test(&y, 199, &mut rng).unwrap();
+        assert_eq!(
+            test.distance_correlation,
+            x.distance_correlation(&y).unwrap()
+        );
+        assert!(test.p_value < 0.05);
+
+        let test = x.distance_correlation_test(&y, 0, &mut rng).unwrap();
+        assert_eq!(test.p_value, 1.);
+    }
+
+    #[test]
+    fn test_permutation_test_under_independence() {
+        let x = array![1., 2., 3., 4., 5., 6.];
+        let y = array![3., 1., 3., 1., 3., 1.];
+        let mut rng = StdRng::seed_from_u64(0);
+        let test = x.distance_correlation_test(&y, 99, &mut rng).unwrap();
+        assert!(test.p_value > 0.1);
+    }
+
+    #[test]
+    fn test_nan_values() {
+        let x = array![1., 2., f64::NAN, 4.];
+        let y = array![3., 1., 4., 1.];
+        assert!(x.distance_covariance(&y).unwrap().is_nan());
+        assert!(y.distance_correlation(&x).unwrap().is_nan());
+        let mut rng = StdRng::seed_from_u64(0);
+        let test = x.distance_correlation_test(&y, 9, &mut rng).unwrap();
+        assert!(test.distance_correlation.is_nan());
+        assert!(test.p_value.is_nan());
+
+        let x = array![[1., 0.], [2., f64::NAN], [0., 3.]];
+        let y = array![[1.], [2.], [2.]];
+        assert!(x.distance_covariance(&y).unwrap().is_nan());
+        assert!(x.distance_correlation(&y).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_errors() {
+        let a = array![1., 2., 3.];
+        let b = array![1., 2.];
+        assert_eq!(
+            a.distance_correlation(&b),
+            Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+                first_shape: vec![3],
+                second_shape: vec![2],
+            }))
+        );
+        let empty = Array1::<f64>::zeros(0);
+        assert_eq!(
+            empty.distance_covariance(&a),
+            Err(MultiInputError::EmptyInput)
+        );
+        assert_eq!(
+            empty.distance_correlation(&empty),
+            Err(MultiInputError::EmptyInput)
+        );
+        let c = Array2::<f64>::zeros((3, 0));
+        assert_eq!(
+            array![[1.], [2.], [3.]].distance_correlation(&c),
+            Err(MultiInputError::EmptyInput)
+        );
+    }
+}
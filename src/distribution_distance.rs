@@ -0,0 +1,437 @@
+use crate::errors::{EmptyInput, MultiInputError, ShapeMismatch};
+use ndarray::prelude::*;
+use ndarray::Data;
+use num_traits::{Float, FromPrimitive};
+
+/// Extension trait for 1-dimensional arrays of observations providing functions to
+/// compute distances between the empirical distributions of two samples.
+///
+/// The samples may have different sizes, and each observation may be given a
+/// non-negative weight: the empirical distribution then puts on each observation a
+/// probability proportional to its weight (equal probabilities if no weights are given).
+pub trait DistributionDistanceExt<A, S>
+where
+    S: Data<Elem = A>,
+{
+    /// Computes the [Wasserstein-p distance] (also known as earth mover's distance
+    /// for `p = 1`) between the empirical distributions of `self` and `other`,
+    /// with optional weights `self_weights` and `other_weights`.
+    ///
+    /// In one dimension, it is the `Lᵖ` distance between the quantile functions
+    /// `F⁻¹` and `G⁻¹` of the two distributions:
+    ///
+    /// ```text
+    ///       ⎛ 1                     ⎞ 1/p
+    /// Wₚ =  ⎜ ∫ |F⁻¹(t) - G⁻¹(t)|ᵖ dt⎟
+    ///       ⎝ 0                     ⎠
+    /// ```
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` or `other` is empty
+    /// * `MultiInputError::ShapeMismatch` if the weights don't have the same length
+    ///   as the corresponding observations
+    ///
+    /// **Panics** if `p` is less than 1 or not finite, if any observation is NaN, or if
+    /// any weight is negative or the weights of a sample sum to zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::DistributionDistanceExt;
+    ///
+    /// let a = array![0., 1., 3.];
+    /// let b = array![5., 6., 8.];
+    /// assert_abs_diff_eq!(a.wasserstein_distance(&b, 1., None, None).unwrap(), 5.);
+    ///
+    /// let a = array![0., 4.];
+    /// let b = array![1.];
+    /// assert_abs_diff_eq!(a.wasserstein_distance(&b, 1., None, None).unwrap(), 2.);
+    /// assert_abs_diff_eq!(
+    ///     a.wasserstein_distance(&b, 2., None, None).unwrap(),
+    ///     5f64.sqrt(),
+    ///     epsilon = 1e-12
+    /// );
+    /// ```
+    ///
+    /// [Wasserstein-p distance]: https://en.wikipedia.org/wiki/Wasserstein_metric
+    fn wasserstein_distance<T>(
+        &self,
+        other: &ArrayBase<T, Ix1>,
+        p: A,
+        self_weights: Option<ArrayView1<A>>,
+        other_weights: Option<ArrayView1<A>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>;
+
+    /// Computes the [energy distance] between the empirical distributions of `self`
+    /// and `other`, with optional weights `self_weights` and `other_weights`.
+    ///
+    /// In one dimension, it is proportional to the `L²` distance between the cumulative
+    /// distribution functions `F` and `G` of the two distributions:
+    ///
+    /// ```text
+    ///       ⎛   +∞               ⎞ 1/2
+    /// D =   ⎜ 2 ∫ (F(x) - G(x))² dx⎟
+    ///       ⎝   -∞               ⎠
+    /// ```
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` or `other` is empty
+    /// * `MultiInputError::ShapeMismatch` if the weights don't have the same length
+    ///   as the corresponding observations
+    ///
+    /// **Panics** if any observation is NaN, or if any weight is negative or the weights
+    /// of a sample sum to zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::DistributionDistanceExt;
+    ///
+    /// let a = array![0., 8.];
+    /// let weights = array![3., 1.];
+    /// assert_abs_diff_eq!(
+    ///     a.energy_distance(&a, Some(weights.view()), None).unwrap(),
+    ///     1.,
+    ///     epsilon = 1e-12
+    /// );
+    /// ```
+    ///
+    /// [energy distance]: https://en.wikipedia.org/wiki/Energy_distance
+    fn energy_distance<T>(
+        &self,
+        other: &ArrayBase<T, Ix1>,
+        self_weights: Option<ArrayView1<A>>,
+        other_weights: Option<ArrayView1<A>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>;
+
+    private_decl! {}
+}
+
+impl<A, S> DistributionDistanceExt<A, S> for ArrayBase<S, Ix1>
+where
+    S: Data<Elem = A>,
+{
+    fn wasserstein_distance<T>(
+        &self,
+        other: &ArrayBase<T, Ix1>,
+        p: A,
+        self_weights: Option<ArrayView1<A>>,
+        other_weights: Option<ArrayView1<A>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+    {
+        let u = empirical_cdf(self, self_weights)?;
+        let v = empirical_cdf(other, other_weights)?;
+        Ok(wasserstein(&u, &v, p))
+    }
+
+    fn energy_distance<T>(
+        &self,
+        other: &ArrayBase<T, Ix1>,
+        self_weights: Option<ArrayView1<A>>,
+        other_weights: Option<ArrayView1<A>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+    {
+        let u = empirical_cdf(self, self_weights)?;
+        let v = empirical_cdf(other, other_weights)?;
+
+        let mut values: Vec<A> = u.iter().chain(&v).map(|&(x, _)| x).collect();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let (mut i, mut j) = (0, 0);
+        let (mut cdf_u, mut cdf_v) = (A::zero(), A::zero());
+        let mut integral = A::zero();
+        for window in values.windows(2) {
+            while i < u.len() && u[i].0 <= window[0] {
+                cdf_u = u[i].1;
+                i += 1;
+            }
+            while j < v.len() && v[j].0 <= window[0] {
+                cdf_v = v[j].1;
+                j += 1;
+            }
+            integral = integral + (cdf_u - cdf_v).powi(2) * (window[1] - window[0]);
+        }
+        Ok((integral + integral).sqrt())
+    }
+
+    private_impl! {}
+}
+
+/// Returns the observations sorted in increasing order, each paired with the value of
+/// the cumulative distribution function at that observation.
+fn empirical_cdf<A, S>(
+    observations: &ArrayBase<S, Ix1>,
+    weights: Option<ArrayView1<A>>,
+) -> Result<Vec<(A, A)>, MultiInputError>
+where
+    A: Float,
+    S: Data<Elem = A>,
+{
+    if observations.is_empty() {
+        return Err(EmptyInput.into());
+    }
+    let mut points: Vec<(A, A)> = match weights {
+        Some(weights) => {
+            if weights.len() != observations.len() {
+                return Err(ShapeMismatch {
+                    first_shape: observations.shape().to_vec(),
+                    second_shape: weights.shape().to_vec(),
+                }
+                .into());
+            }
+            observations
+                .iter()
+                .zip(&weights)
+                .map(|(&x, &w)| {
+                    assert!(w >= A::zero(), "Weights must be non-negative.");
+                    (x, w)
+                })
+                .collect()
+        }
+        None => observations.iter().map(|&x| (x, A::one())).collect(),
+    };
+    points.sort_by(|a, b| {
+        a.0.partial_cmp(&b.0)
+            .expect("Observations must not be NaN.")
+    });
+    Ok(weighted_cdf(points))
+}
+
+/// Replaces the weights of the sorted `points` by their normalised cumulative sums.
+fn weighted_cdf<A: Float>(mut points: Vec<(A, A)>) -> Vec<(A, A)> {
+    let total = points.iter().fold(A::zero(), |acc, &(_, w)| acc + w);
+    assert!(total > A::zero(), "Weights must not sum to zero.");
+    let mut cumulative = A::zero();
+    for point in &mut points {
+        cumulative = cumulative + point.1;
+        point.1 = cumulative / total;
+    }
+    // Guard against rounding errors in the last cumulative sum
+    points.last_mut().unwrap().1 = A::one();
+    points
+}
+
+/// Computes the Wasserstein-p distance between two distributions given by their sorted
+/// support points and the values of their cumulative distribution functions.
+fn wasserstein<A: Float>(u: &[(A, A)], v: &[(A, A)], p: A) -> A {
+    assert!(
+        p >= A::one() && p.is_finite(),
+        "`p` must be finite and at least 1."
+    );
+    // Walk along the quantile functions, which are constant between consecutive
+    // values of the cumulative distribution functions
+    let (mut i, mut j) = (0, 0);
+    let mut level = A::zero();
+    let mut integral = A::zero();
+    while i < u.len() && j < v.len() {
+        let next = u[i].1.min(v[j].1);
+        integral = integral + (next - level) * (u[i].0 - v[j].0).abs().powf(p);
+        level = next;
+        if u[i].1 <= next {
+            i += 1;
+        }
+        if v[j].1 <= next {
+            j += 1;
+        }
+    }
+    integral.powf(p.recip())
+}
+
+/// Computes the Wasserstein-p distance between two distributions over the same points,
+/// given by the (unnormalised) weights `u` and `v` of the sorted points.
+pub(crate) fn wasserstein_on_points(points: &[f64], u: &[f64], v: &[f64], p: f64) -> f64 {
+    let cdf = |weights: &[f64]| {
+        weighted_cdf(
+            points
+                .iter()
+                .cloned()
+                .zip(weights.iter().cloned())
+                .collect(),
+        )
+    };
+    wasserstein(&cdf(u), &cdf(v), p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::histogram::{Bins, Edges, Grid, Histogram};
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn test_wasserstein_distance() {
+        let a = array![0., 1., 3.];
+        let b = array![5., 6., 8.];
+        assert_abs_diff_eq!(a.wasserstein_distance(&b, 1., None, None).unwrap(), 5.);
+        // A shift gives the same distance for every `p`
+        assert_abs_diff_eq!(
+            a.wasserstein_distance(&b, 3., None, None).unwrap(),
+            5.,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            b.wasserstein_distance(&a, 1., None, None).unwrap(),
+            5.,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(a.wasserstein_distance(&a, 2., None, None).unwrap(), 0.);
+
+        let a = array![0., 2.];
+        let b = array![1.];
+        assert_abs_diff_eq!(
+            a.wasserstein_distance(&b, 2., None, None).unwrap(),
+            1.,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_weighted_wasserstein_distance() {
+        let a = array![0., 1.];
+        let wa = array![3., 1.];
+        let wb = array![2., 2.];
+        assert_abs_diff_eq!(
+            a.wasserstein_distance(&a, 1., Some(wa.view()), Some(wb.view()))
+                .unwrap(),
+            0.25,
+            epsilon = 1e-12
+        );
+
+        let a = array![3.4, 3.9, 7.5, 7.8];
+        let b = array![4.5, 1.4];
+        let wa = array![1.4, 0.9, 3.1, 7.2];
+        let wb = array![3.2, 3.5];
+        assert_abs_diff_eq!(
+            a.wasserstein_distance(&b, 1., Some(wa.view()), Some(wb.view()))
+                .unwrap(),
+            4.078133143804786,
+            epsilon = 1e-12
+        );
+
+        // Integer weights are equivalent to repeating the observations
+        let a = array![2., 0., 5.];
+        let wa = array![2., 1., 0.];
+        let repeated = array![0., 2., 2.];
+        let b = array![1., 4., 3., 3.5];
+        assert_abs_diff_eq!(
+            a.wasserstein_distance(&b, 2., Some(wa.view()), None)
+                .unwrap(),
+            repeated.wasserstein_distance(&b, 2., None, None).unwrap(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_energy_distance() {
+        let a = array![0.];
+        let b = array![2.];
+        assert_abs_diff_eq!(
+            a.energy_distance(&b, None, None).unwrap(),
+            2.,
+            epsilon = 1e-12
+        );
+
+        let a = array![0.7, 7.4, 2.4, 6.8];
+        let b = array![1.4, 8.];
+        let wa = array![2.1, 4.2, 7.4, 8.];
+        let wb = array![7.6, 8.8];
+        assert_abs_diff_eq!(
+            a.energy_distance(&b, Some(wa.view()), Some(wb.view()))
+                .unwrap(),
+            0.8800334097615822,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(a.energy_distance(&a, None, None).unwrap(), 0.);
+    }
+
+    #[test]
+    fn test_histogram_wasserstein_distance() {
+        let bins = Bins::new(Edges::from(vec![0, 1, 2, 3, 5]));
+        let grid = Grid::from(vec![bins]);
+        let mut a = Histogram::new(grid.clone());
+        let mut b = Histogram::new(grid);
+        for &x in &[0, 1, 1] {
+            a.add_observation(&array![x]).unwrap();
+        }
+        for &x in &[1, 2, 2] {
+            b.add_observation(&array![x]).unwrap();
+        }
+        // Each observation moves to the center of the next bin
+        assert_abs_diff_eq!(a.wasserstein_distance(&b, 1.).unwrap(), 1., epsilon = 1e-12);
+        assert_abs_diff_eq!(b.wasserstein_distance(&a, 2.).unwrap(), 1., epsilon = 1e-12);
+
+        b.add_observation(&array![4]).unwrap();
+        // The quantile functions differ by 1, 2, 1 and 2.5 on [0, 1/4), [1/4, 1/3),
+        // [1/3, 3/4) and [3/4, 1) respectively
+        assert_abs_diff_eq!(
+            a.wasserstein_distance(&b, 1.).unwrap(),
+            35. / 24.,
+            epsilon = 1e-12
+        );
+
+        let empty = Histogram::new(a.grid().clone());
+        assert_eq!(a.wasserstein_distance(&empty, 1.), Err(EmptyInput));
+        assert_eq!(empty.wasserstein_distance(&a, 1.), Err(EmptyInput));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_histogram_wasserstein_distance_with_different_grids_panics() {
+        let a = Histogram::new(Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 2]))]));
+        let b = Histogram::new(Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 3]))]));
+        let _ = a.wasserstein_distance(&b, 1.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_p_less_than_one_panics() {
+        let a = array![0., 1.];
+        let _ = a.wasserstein_distance(&a, 0.5, None, None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_negative_weight_panics() {
+        let a = array![0., 1.];
+        let _ = a.energy_distance(&a, Some(array![1., -1.].view()), None);
+    }
+
+    #[test]
+    fn test_errors() {
+        let a = array![0., 1.];
+        let empty = Array1::<f64>::zeros(0);
+        assert_eq!(
+            a.wasserstein_distance(&empty, 1., None, None),
+            Err(MultiInputError::EmptyInput)
+        );
+        assert_eq!(
+            empty.energy_distance(&a, None, None),
+            Err(MultiInputError::EmptyInput)
+        );
+        assert_eq!(
+            a.energy_distance(&a, None, Some(array![1.].view())),
+            Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+                first_shape: vec![2],
+                second_shape: vec![1],
+            }))
+        );
+    }
+}
@@ -0,0 +1,431 @@
+use crate::errors::{MultiInputError, ShapeMismatch};
+use crate::pairwise::{DistanceMetric, PairwiseDistanceExt};
+use ndarray::prelude::*;
+use ndarray::{Data, LinalgScalar};
+use num_traits::{Float, FromPrimitive};
+
+/// The result of [`dtw`](trait.DynamicTimeWarpingExt.html#tymethod.dtw).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dtw<A> {
+    /// The dynamic time warping distance.
+    pub distance: A,
+    /// The optimal warping path: the pairs `(i, j)` of matched time steps of the first
+    /// and second sequences, from `(0, 0)` to the last time steps of both sequences.
+    /// It is empty if the distance is NaN.
+    pub path: Vec<(usize, usize)>,
+}
+
+/// Extension trait for `ArrayBase` providing functions to compute the
+/// [dynamic time warping] (DTW) distance between two sequences of possibly different
+/// lengths.
+///
+/// A 1-dimensional array is a univariate sequence, while the rows of a 2-dimensional
+/// array are the time steps of a multivariate sequence.
+///
+/// [dynamic time warping]: https://en.wikipedia.org/wiki/Dynamic_time_warping
+pub trait DynamicTimeWarpingExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Returns the dynamic time warping distance between `self` and `other`.
+    ///
+    /// A warping path matches each time step of `self` with one or more time steps of
+    /// `other` and vice versa, from the first to the last ones and without crossing. The
+    /// distance is the minimum over all warping paths of the sum of the distances `metric`
+    /// (see [`DistanceMetric`]) between matched time steps.
+    ///
+    /// If `window` is `Some(r)`, the paths are restricted to the Sakoe–Chiba band of
+    /// radius `r`, i.e. time step `i` of `self` may only be matched with time steps `j` of
+    /// `other` such that `|i - j| ≤ r`. The radius is widened to the difference of the
+    /// lengths of the sequences if it is smaller, so that a warping path always exists.
+    ///
+    /// The distance is NaN if the distance between any two time steps that may be
+    /// matched is NaN (e.g. if a time step contains a NaN value).
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` or `other` is empty
+    /// * `MultiInputError::ShapeMismatch` if the time steps of `self` and `other` don't
+    ///   have the same number of variables
+    ///
+    /// **Panics** if the order `p` of a Minkowski distance is NaN or not positive,
+    /// or if the type cast of the number of variables from `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{DistanceMetric, DynamicTimeWarpingExt};
+    ///
+    /// let a = array![0., 1., 2., 1.];
+    /// let b = array![0., 0., 1., 2., 2., 1.];
+    /// assert_eq!(a.dtw_distance(&b, DistanceMetric::L1, None).unwrap(), 0.);
+    /// ```
+    ///
+    /// [`DistanceMetric`]: enum.DistanceMetric.html
+    fn dtw_distance<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        metric: DistanceMetric<A>,
+        window: Option<usize>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive + LinalgScalar,
+        T: Data<Elem = A>;
+
+    /// Returns the dynamic time warping distance between `self` and `other` together
+    /// with the optimal warping path (see [`dtw_distance`]).
+    ///
+    /// If there are several optimal paths, matching the next time steps of both
+    /// sequences is preferred over advancing only in `self`, which is preferred over
+    /// advancing only in `other`. If the distance is NaN, the returned path is empty.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` or `other` is empty
+    /// * `MultiInputError::ShapeMismatch` if the time steps of `self` and `other` don't
+    ///   have the same number of variables
+    ///
+    /// **Panics** if the order `p` of a Minkowski distance is NaN or not positive,
+    /// or if the type cast of the number of variables from `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{DistanceMetric, DynamicTimeWarpingExt};
+    ///
+    /// let a = array![1., 2., 3.];
+    /// let b = array![2., 2., 2., 3., 4.];
+    /// let dtw = a.dtw(&b, DistanceMetric::L1, None).unwrap();
+    /// assert_eq!(dtw.distance, 2.);
+    /// assert_eq!(dtw.path, vec![(0, 0), (1, 1), (1, 2), (2, 3), (2, 4)]);
+    /// ```
+    ///
+    /// [`dtw_distance`]: #tymethod.dtw_distance
+    fn dtw<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        metric: DistanceMetric<A>,
+        window: Option<usize>,
+    ) -> Result<Dtw<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive + LinalgScalar,
+        T: Data<Elem = A>;
+
+    private_decl! {}
+}
+
+impl<A, S> DynamicTimeWarpingExt<A, S, Ix1> for ArrayBase<S, Ix1>
+where
+    S: Data<Elem = A>,
+{
+    fn dtw_distance<T>(
+        &self,
+        other: &ArrayBase<T, Ix1>,
+        metric: DistanceMetric<A>,
+        window: Option<usize>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive + LinalgScalar,
+        T: Data<Elem = A>,
+    {
+        let cost = accumulated_cost(
+            &self.view().insert_axis(Axis(1)),
+            &other.view().insert_axis(Axis(1)),
+            metric,
+            window,
+        )?;
+        Ok(cost[(self.len(), other.len())])
+    }
+
+    fn dtw<T>(
+        &self,
+        other: &ArrayBase<T, Ix1>,
+        metric: DistanceMetric<A>,
+        window: Option<usize>,
+    ) -> Result<Dtw<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive + LinalgScalar,
+        T: Data<Elem = A>,
+    {
+        let cost = accumulated_cost(
+            &self.view().insert_axis(Axis(1)),
+            &other.view().insert_axis(Axis(1)),
+            metric,
+            window,
+        )?;
+        Ok(optimal_path(&cost))
+    }
+
+    private_impl! {}
+}
+
+impl<A, S> DynamicTimeWarpingExt<A, S, Ix2> for ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    fn dtw_distance<T>(
+        &self,
+        other: &ArrayBase<T, Ix2>,
+        metric: DistanceMetric<A>,
+        window: Option<usize>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive + LinalgScalar,
+        T: Data<Elem = A>,
+    {
+        let cost = accumulated_cost(self, other, metric, window)?;
+        Ok(cost[(self.nrows(), other.nrows())])
+    }
+
+    fn dtw<T>(
+        &self,
+        other: &ArrayBase<T, Ix2>,
+        metric: DistanceMetric<A>,
+        window: Option<usize>,
+    ) -> Result<Dtw<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive + LinalgScalar,
+        T: Data<Elem = A>,
+    {
+        let cost = accumulated_cost(self, other, metric, window)?;
+        Ok(optimal_path(&cost))
+    }
+
+    private_impl! {}
+}
+
+/// Returns the matrix of accumulated costs `C`, where `C[(i, j)]` is the DTW distance
+/// between the first `i` time steps of `x` and the first `j` time steps of `y`
+/// (infinite outside of the band, NaN if any of the distances it depends on is NaN).
+fn accumulated_cost<A, S, T>(
+    x: &ArrayBase<S, Ix2>,
+    y: &ArrayBase<T, Ix2>,
+    metric: DistanceMetric<A>,
+    window: Option<usize>,
+) -> Result<Array2<A>, MultiInputError>
+where
+    A: Float + FromPrimitive + LinalgScalar,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    if x.ncols() != y.ncols() {
+        return Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+            first_shape: x.shape().to_vec(),
+            second_shape: y.shape().to_vec(),
+        }));
+    }
+    // The local costs are computed from the differences of the observations
+    // (see `cdist`), so that they are accurate for series with a large offset
+    let distances = x.cdist(y, metric)?;
+    let (n, m) = distances.dim();
+    let length_difference = if n > m { n - m } else { m - n };
+    let radius = window.map(|r| r.max(length_difference));
+
+    let mut cost = Array2::from_elem((n + 1, m + 1), A::infinity());
+    cost[(0, 0)] = A::zero();
+    for i in 1..=n {
+        let (start, end) = match radius {
+            Some(r) => (i.saturating_sub(r).max(1), (i + r).min(m)),
+            None => (1, m),
+        };
+        for j in start..=end {
+            let previous = nan_propagating_min(
+                nan_propagating_min(cost[(i - 1, j - 1)], cost[(i - 1, j)]),
+                cost[(i, j - 1)],
+            );
+            cost[(i, j)] = distances[(i - 1, j - 1)] + previous;
+        }
+    }
+    Ok(cost)
+}
+
+/// Returns the minimum of `a` and `b`, or NaN if either of them is NaN.
+fn nan_propagating_min<A: Float>(a: A, b: A) -> A {
+    if a.is_nan() || b.is_nan() {
+        A::nan()
+    } else {
+        a.min(b)
+    }
+}
+
+/// Backtracks the optimal warping path through the matrix of accumulated costs.
+///
+/// The path is empty if the distance is NaN; otherwise none of the costs is NaN.
+fn optimal_path<A>(cost: &Array2<A>) -> Dtw<A>
+where
+    A: Float,
+{
+    let (mut i, mut j) = (cost.nrows() - 1, cost.ncols() - 1);
+    if cost[(i, j)].is_nan() {
+        return Dtw {
+            distance: cost[(i, j)],
+            path: Vec::new(),
+        };
+    }
+    let mut path = vec![(i - 1, j - 1)];
+    while (i, j) != (1, 1) {
+        let diagonal = cost[(i - 1, j - 1)];
+        let up = cost[(i - 1, j)];
+        let left = cost[(i, j - 1)];
+        if diagonal <= up && diagonal <= left {
+            i -= 1;
+            j -= 1;
+        } else if up <= left {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+        path.push((i - 1, j - 1));
+    }
+    path.reverse();
+    Dtw {
+        distance: cost[(cost.nrows() - 1, cost.ncols() - 1)],
+        path,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+    use std::f64;
+
+    #[test]
+    fn test_dtw() {
+        let a = array![0., 2., 0., 1., 0., 0.];
+        let b = array![0., 0., 0.5, 2., 0., 1., 0.];
+        let dtw = a.dtw(&b, DistanceMetric::L1, None).unwrap();
+        assert_abs_diff_eq!(dtw.distance, 0.5);
+        assert_eq!(
+            dtw.path,
+            vec![
+                (0, 0),
+                (0, 1),
+                (0, 2),
+                (1, 3),
+                (2, 4),
+                (3, 5),
+                (4, 6),
+                (5, 6)
+            ]
+        );
+        assert_eq!(
+            b.dtw_distance(&a, DistanceMetric::L1, None).unwrap(),
+            dtw.distance
+        );
+        assert_eq!(a.dtw_distance(&a, DistanceMetric::L2, None).unwrap(), 0.);
+    }
+
+    #[test]
+    fn test_dtw_with_window() {
+        let a = array![0., 2., 0., 1., 0., 0.];
+        let b = array![0., 0., 0.5, 2., 0., 1., 0.];
+        let dtw = a.dtw(&b, DistanceMetric::L1, Some(1)).unwrap();
+        assert_abs_diff_eq!(dtw.distance, 4.);
+        assert_eq!(
+            dtw.path,
+            vec![
+                (0, 0),
+                (0, 1),
+                (1, 2),
+                (2, 2),
+                (3, 3),
+                (4, 4),
+                (4, 5),
+                (5, 6)
+            ]
+        );
+        assert!(dtw
+            .path
+            .iter()
+            .all(|&(i, j)| if i > j { i - j <= 1 } else { j - i <= 1 }));
+        // The band is widened to the difference of the lengths
+        assert_eq!(a.dtw(&b, DistanceMetric::L1, Some(0)).unwrap(), dtw);
+        // A wide enough band gives the unconstrained distance
+        assert_eq!(
+            a.dtw_distance(&b, DistanceMetric::L1, Some(6)).unwrap(),
+            a.dtw_distance(&b, DistanceMetric::L1, None).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_dtw_with_large_offset() {
+        let a = array![1e8, 1e8 + 1., 1e8 + 2.];
+        let b = array![1e8 + 3., 1e8 + 4.];
+        let l1 = a.dtw_distance(&b, DistanceMetric::L1, None).unwrap();
+        assert_abs_diff_eq!(l1, 7.);
+        assert_abs_diff_eq!(a.dtw_distance(&b, DistanceMetric::L2, None).unwrap(), l1);
+        assert_abs_diff_eq!(
+            a.dtw_distance(&b, DistanceMetric::SqL2, None).unwrap(),
+            9. + 4. + 4.
+        );
+    }
+
+    #[test]
+    fn test_multivariate_dtw() {
+        let a = array![[0., 0.], [1., 1.], [2., 2.]];
+        let b = array![[0., 0.], [0., 0.], [1., 1.], [3., 2.]];
+        let dtw = a.dtw(&b, DistanceMetric::L2, None).unwrap();
+        assert_abs_diff_eq!(dtw.distance, 1., epsilon = 1e-12);
+        assert_eq!(dtw.path, vec![(0, 0), (0, 1), (1, 2), (2, 3)]);
+        assert_abs_diff_eq!(
+            a.dtw_distance(&b, DistanceMetric::L1, None).unwrap(),
+            1.,
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_single_time_step() {
+        let a = array![1.];
+        let b = array![1., 2., 4.];
+        let dtw = a.dtw(&b, DistanceMetric::L1, Some(0)).unwrap();
+        assert_eq!(dtw.distance, 4.);
+        assert_eq!(dtw.path, vec![(0, 0), (0, 1), (0, 2)]);
+    }
+
+    #[test]
+    fn test_nan_values() {
+        let a = array![f64::NAN, 1., 2.];
+        let b = array![0., 1., 1., 2.];
+        assert!(a
+            .dtw_distance(&b, DistanceMetric::L1, None)
+            .unwrap()
+            .is_nan());
+        let dtw = b.dtw(&a, DistanceMetric::L2, Some(1)).unwrap();
+        assert!(dtw.distance.is_nan());
+        assert!(dtw.path.is_empty());
+        let c = array![[0., 1.], [2., f64::NAN]];
+        assert!(c
+            .dtw_distance(&c, DistanceMetric::SqL2, None)
+            .unwrap()
+            .is_nan());
+    }
+
+    #[test]
+    fn test_errors() {
+        let a = array![1., 2.];
+        let empty = Array1::<f64>::zeros(0);
+        assert_eq!(
+            a.dtw_distance(&empty, DistanceMetric::L1, None),
+            Err(MultiInputError::EmptyInput)
+        );
+        assert_eq!(
+            empty.dtw(&a, DistanceMetric::L1, None),
+            Err(MultiInputError::EmptyInput)
+        );
+        let b = array![[1., 2.], [3., 4.]];
+        let c = array![[1., 2., 3.]];
+        assert_eq!(
+            b.dtw_distance(&c, DistanceMetric::L1, None),
+            Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+                first_shape: vec![2, 2],
+                second_shape: vec![1, 3],
+            }))
+        );
+    }
+}
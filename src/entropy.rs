@@ -1,8 +1,62 @@
 //! Information theory (e.g. entropy, KL divergence, etc.).
 use crate::errors::{EmptyInput, MultiInputError, ShapeMismatch};
-use ndarray::{Array, ArrayBase, Data, Dimension, Zip};
+use crate::lanes::map_lanes;
+use ndarray::{Array, Array1, ArrayBase, ArrayView1, Axis, Data, Dimension, RemoveAxis, Zip};
 use num_traits::Float;
 
+/// The base of the logarithm used to compute information theory quantities,
+/// i.e. their unit of measure.
+///
+/// All the methods of [`EntropyExt`] without a `_with_base` suffix use the natural
+/// logarithm, i.e. they return values in nats. The Tsallis entropy does not involve
+/// a logarithm, so it has no `_with_base` variant.
+///
+/// [`EntropyExt`]: trait.EntropyExt.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LogBase<A> {
+    /// Base 2: values are measured in bits (or shannons).
+    Two,
+    /// Base *e*: values are measured in nats.
+    E,
+    /// Base 10: values are measured in bans (or hartleys).
+    Ten,
+    /// An arbitrary base, which must be positive and different from 1.
+    Custom(A),
+}
+
+impl<A> LogBase<A>
+where
+    A: Float,
+{
+    /// Returns the natural logarithm of the base.
+    ///
+    /// **Panics** if the base is `Custom` and it is not positive, it is equal to 1 or it is NaN.
+    pub fn ln(self) -> A {
+        match self {
+            LogBase::Two => A::from(2.).unwrap().ln(),
+            LogBase::E => A::one(),
+            LogBase::Ten => A::from(10.).unwrap().ln(),
+            LogBase::Custom(base) => {
+                assert!(
+                    base > A::zero() && base != A::one(),
+                    "The logarithm base must be positive and different from 1."
+                );
+                base.ln()
+            }
+        }
+    }
+
+    /// Converts `value`, measured in nats, to the unit of measure of the base.
+    ///
+    /// **Panics** if the base is `Custom` and it is not positive, it is equal to 1 or it is NaN.
+    pub fn from_nats(self, value: A) -> A {
+        match self {
+            LogBase::E => value,
+            base => value / base.ln(),
+        }
+    }
+}
+
 /// Extension trait for `ArrayBase` providing methods
 /// to compute information theory quantities
 /// (e.g. entropy, Kullback–Leibler divergence, etc.).
@@ -77,153 +131,1285 @@ where
     fn kl_divergence<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
     where
         S2: Data<Elem = A>,
-        A: Float;
+        A: Float;
+
+    /// Computes the [cross entropy] *H(p,q)* between two arrays,
+    /// where `self`=*p*.
+    ///
+    /// The cross entropy is defined as:
+    ///
+    /// ```text
+    ///            n
+    /// H(p,q) = - ∑ pᵢ ln(qᵢ)
+    ///           i=1
+    /// ```
+    ///
+    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the array shapes are not identical,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// **Panics** if any element in *q* is negative and taking the logarithm of a negative number
+    /// is a panic cause for `A`.
+    ///
+    /// ## Remarks
+    ///
+    /// The cross entropy is a measure used in [Information Theory]
+    /// to describe the relationship between two probability distributions: it only makes sense
+    /// when each array sums to 1 with entries between 0 and 1 (extremes included).
+    ///
+    /// The array values are **not** normalised by this function before
+    /// computing the entropy to avoid introducing potentially
+    /// unnecessary numerical errors (e.g. if the array were to be already normalised).
+    ///
+    /// The cross entropy is often used as an objective/loss function in
+    /// [optimization problems], including [machine learning].
+    ///
+    /// By definition, *pᵢ ln(qᵢ)* is set to 0 if *pᵢ* is 0.
+    ///
+    /// [cross entropy]: https://en.wikipedia.org/wiki/Cross-entropy
+    /// [Information Theory]: https://en.wikipedia.org/wiki/Information_theory
+    /// [optimization problems]: https://en.wikipedia.org/wiki/Cross-entropy_method
+    /// [machine learning]: https://en.wikipedia.org/wiki/Cross_entropy#Cross-entropy_error_function_and_logistic_regression
+    fn cross_entropy<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [Jensen-Shannon divergence] *JSD(p,q)* between two arrays,
+    /// where `self`=*p*.
+    ///
+    /// The Jensen-Shannon divergence is a symmetrised and bounded version of the
+    /// Kullback-Leibler divergence, defined as:
+    ///
+    /// ```text
+    ///            1              1
+    /// JSD(p,q) = ― Dₖₗ(p,m) + ― Dₖₗ(q,m)
+    ///            2              2
+    /// ```
+    ///
+    /// where *m = (p + q) / 2*. For probability distributions, it lies between 0 and *ln(2)*.
+    ///
+    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the array shapes are not identical,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// **Panics** if, for a pair of elements *(pᵢ, qᵢ)* from *p* and *q*, computing
+    /// *ln(mᵢ/pᵢ)* or *ln(mᵢ/qᵢ)* is a panic cause for `A`.
+    ///
+    /// ## Remarks
+    ///
+    /// As for [`kl_divergence`], the array values are **not** normalised by this function,
+    /// and NaN values propagate to the result.
+    ///
+    /// [Jensen-Shannon divergence]: https://en.wikipedia.org/wiki/Jensen%E2%80%93Shannon_divergence
+    /// [`kl_divergence`]: #tymethod.kl_divergence
+    fn js_divergence<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the Jensen-Shannon distance between two arrays, where `self`=*p*:
+    /// the square root of the [Jensen-Shannon divergence], which is a metric.
+    ///
+    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the array shapes are not identical,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// **Panics** if, for a pair of elements *(pᵢ, qᵢ)* from *p* and *q*, computing
+    /// *ln(mᵢ/pᵢ)* or *ln(mᵢ/qᵢ)* is a panic cause for `A`.
+    ///
+    /// [Jensen-Shannon divergence]: #tymethod.js_divergence
+    fn js_distance<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the generalized [Jensen-Shannon divergence] of the distributions
+    /// *p⁽¹⁾, …, p⁽ᵏ⁾* given by the subviews of `self` along `axis`, with weights *π*:
+    ///
+    /// ```text
+    ///                           k
+    /// JSDπ(p⁽¹⁾, …, p⁽ᵏ⁾) =     ∑ πⱼ Dₖₗ(p⁽ʲ⁾,m)
+    ///                          j=1
+    /// ```
+    ///
+    /// where *m = ∑ πⱼ p⁽ʲ⁾* is the mixture distribution. If `weights` is `None`, the
+    /// distributions are equally weighted (*πⱼ = 1/k*); otherwise the weights are
+    /// **not** normalised, and should sum to 1. Distributions with a zero weight
+    /// are ignored.
+    ///
+    /// With two equally weighted distributions, it is the [`js_divergence`].
+    ///
+    /// If the array is empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the length of `weights` is not the length of `axis`,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds, if the type cast of the number of
+    /// distributions from `usize` to `A` fails, or if computing *ln(mᵢ/p⁽ʲ⁾ᵢ)*
+    /// is a panic cause for `A`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::EntropyExt;
+    ///
+    /// // Three distributions over two outcomes, one per row
+    /// let distributions = array![[1., 0.], [0., 1.], [0.5, 0.5]];
+    /// let weights = array![0.25, 0.25, 0.5];
+    /// assert_abs_diff_eq!(
+    ///     distributions
+    ///         .generalized_js_divergence(Axis(0), Some(weights.view()))
+    ///         .unwrap(),
+    ///     0.5 * 2f64.ln(),
+    ///     epsilon = 1e-12
+    /// );
+    /// ```
+    ///
+    /// [Jensen-Shannon divergence]: https://en.wikipedia.org/wiki/Jensen%E2%80%93Shannon_divergence
+    /// [`js_divergence`]: #tymethod.js_divergence
+    fn generalized_js_divergence(
+        &self,
+        axis: Axis,
+        weights: Option<ArrayView1<A>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float,
+        D: RemoveAxis;
+
+    /// Computes the [Rényi entropy] *Hα* of order `alpha` of the array values, defined as
+    ///
+    /// ```text
+    ///        1       n
+    /// Hα = ――――― ln( ∑ xᵢ^α )
+    ///      1 - α    i=1
+    /// ```
+    ///
+    /// where the sum runs over the non-zero elements. The limit cases are:
+    ///
+    /// - *α = 0*: the Hartley entropy, *ln* of the number of non-zero elements;
+    /// - *α = 1*: the Shannon [`entropy`];
+    /// - *α = 2*: the collision entropy, *-ln(∑ xᵢ²)*;
+    /// - *α = ∞*: the min-entropy, *-ln(max xᵢ)*.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `alpha` is negative or NaN, or if `ln` of any element in the array
+    /// panics (which can occur for negative values for some `A`).
+    ///
+    /// ## Remarks
+    ///
+    /// As for [`entropy`], the array values are **not** normalised by this function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::EntropyExt;
+    /// use std::f64;
+    ///
+    /// let p = array![0.5, 0.25, 0.25];
+    /// assert_abs_diff_eq!(p.renyi_entropy(0.).unwrap(), 3f64.ln(), epsilon = 1e-12);
+    /// assert_abs_diff_eq!(p.renyi_entropy(1.).unwrap(), p.entropy().unwrap());
+    /// assert_abs_diff_eq!(p.renyi_entropy(2.).unwrap(), -0.375f64.ln(), epsilon = 1e-12);
+    /// assert_abs_diff_eq!(
+    ///     p.renyi_entropy(f64::INFINITY).unwrap(),
+    ///     2f64.ln(),
+    ///     epsilon = 1e-12
+    /// );
+    /// ```
+    ///
+    /// [Rényi entropy]: https://en.wikipedia.org/wiki/R%C3%A9nyi_entropy
+    /// [`entropy`]: #tymethod.entropy
+    fn renyi_entropy(&self, alpha: A) -> Result<A, EmptyInput>
+    where
+        A: Float;
+
+    /// Computes the [Tsallis entropy] *Sq* of order `q` of the array values, defined as
+    ///
+    /// ```text
+    ///        1          n
+    /// Sq = ――――― ( 1 -  ∑ xᵢ^q )
+    ///      q - 1       i=1
+    /// ```
+    ///
+    /// where the sum runs over the non-zero elements. For *q = 1*, it is the
+    /// Shannon [`entropy`].
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `q` is NaN, or if `ln` of any element in the array panics
+    /// (which can occur for negative values for some `A`).
+    ///
+    /// ## Remarks
+    ///
+    /// As for [`entropy`], the array values are **not** normalised by this function.
+    ///
+    /// [Tsallis entropy]: https://en.wikipedia.org/wiki/Tsallis_entropy
+    /// [`entropy`]: #tymethod.entropy
+    fn tsallis_entropy(&self, q: A) -> Result<A, EmptyInput>
+    where
+        A: Float;
+
+    /// Computes the [Rényi divergence] *Dα(p,q)* of order `alpha` between two arrays,
+    /// where `self`=*p*.
+    ///
+    /// The Rényi divergence is defined as:
+    ///
+    /// ```text
+    ///             1        n
+    /// Dα(p,q) = ――――― ln(  ∑ pᵢ^α qᵢ^(1-α) )
+    ///           α - 1     i=1
+    /// ```
+    ///
+    /// where the sum runs over the non-zero elements of *p*. The limit cases are:
+    ///
+    /// - *α = 0*: *-ln(∑ qᵢ)*, the sum running over the non-zero elements of *p*;
+    /// - *α = 1*: the Kullback-Leibler divergence (see [`kl_divergence`]);
+    /// - *α = ∞*: *ln(max pᵢ/qᵢ)*.
+    ///
+    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the array shapes are not identical,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// **Panics** if `alpha` is negative or NaN, or if, for a pair of elements *(pᵢ, qᵢ)*
+    /// from *p* and *q*, computing *ln(qᵢ/pᵢ)* is a panic cause for `A`.
+    ///
+    /// ## Remarks
+    ///
+    /// As for [`kl_divergence`], the array values are **not** normalised by this function,
+    /// and NaN values propagate to the result.
+    ///
+    /// [Rényi divergence]: https://en.wikipedia.org/wiki/R%C3%A9nyi_entropy#R%C3%A9nyi_divergence
+    /// [`kl_divergence`]: #tymethod.kl_divergence
+    fn renyi_divergence<S2>(&self, q: &ArrayBase<S2, D>, alpha: A) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [`entropy`] of the array values using a logarithm of the given `base`,
+    /// e.g. in bits with `LogBase::Two`.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `base` is not a valid logarithm base (see [`LogBase`]) or
+    /// if `ln` of any element in the array panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::{EntropyExt, LogBase};
+    ///
+    /// let p = array![0.5, 0.25, 0.25];
+    /// assert_abs_diff_eq!(p.entropy_with_base(LogBase::Two).unwrap(), 1.5, epsilon = 1e-12);
+    /// ```
+    ///
+    /// [`entropy`]: #tymethod.entropy
+    /// [`LogBase`]: enum.LogBase.html
+    fn entropy_with_base(&self, base: LogBase<A>) -> Result<A, EmptyInput>
+    where
+        A: Float;
+
+    /// Computes the [`kl_divergence`] between two arrays using a logarithm of the given `base`.
+    ///
+    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the array shapes are not identical,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// **Panics** if `base` is not a valid logarithm base (see [`LogBase`]) or
+    /// under the same conditions as [`kl_divergence`].
+    ///
+    /// [`kl_divergence`]: #tymethod.kl_divergence
+    /// [`LogBase`]: enum.LogBase.html
+    fn kl_divergence_with_base<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        base: LogBase<A>,
+    ) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [`cross_entropy`] between two arrays using a logarithm of the given `base`.
+    ///
+    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the array shapes are not identical,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// **Panics** if `base` is not a valid logarithm base (see [`LogBase`]) or
+    /// under the same conditions as [`cross_entropy`].
+    ///
+    /// [`cross_entropy`]: #tymethod.cross_entropy
+    /// [`LogBase`]: enum.LogBase.html
+    fn cross_entropy_with_base<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        base: LogBase<A>,
+    ) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [`js_divergence`] between two arrays using a logarithm of the given `base`.
+    ///
+    /// With `LogBase::Two`, the Jensen-Shannon divergence of two probability distributions
+    /// lies between 0 and 1.
+    ///
+    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the array shapes are not identical,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// **Panics** if `base` is not a valid logarithm base (see [`LogBase`]) or
+    /// under the same conditions as [`js_divergence`].
+    ///
+    /// [`js_divergence`]: #tymethod.js_divergence
+    /// [`LogBase`]: enum.LogBase.html
+    fn js_divergence_with_base<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        base: LogBase<A>,
+    ) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [`js_distance`] between two arrays using a logarithm of the given `base`,
+    /// i.e. the square root of [`js_divergence_with_base`].
+    ///
+    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the array shapes are not identical,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// **Panics** if `base` is not a valid logarithm base (see [`LogBase`]) or
+    /// under the same conditions as [`js_distance`].
+    ///
+    /// [`js_distance`]: #tymethod.js_distance
+    /// [`js_divergence_with_base`]: #tymethod.js_divergence_with_base
+    /// [`LogBase`]: enum.LogBase.html
+    fn js_distance_with_base<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        base: LogBase<A>,
+    ) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [`generalized_js_divergence`] of the subviews of `self` along `axis`
+    /// using a logarithm of the given `base`.
+    ///
+    /// If the array is empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the length of `weights` is not the length of `axis`,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// **Panics** if `base` is not a valid logarithm base (see [`LogBase`]) or
+    /// under the same conditions as [`generalized_js_divergence`].
+    ///
+    /// [`generalized_js_divergence`]: #tymethod.generalized_js_divergence
+    /// [`LogBase`]: enum.LogBase.html
+    fn generalized_js_divergence_with_base(
+        &self,
+        axis: Axis,
+        weights: Option<ArrayView1<A>>,
+        base: LogBase<A>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float,
+        D: RemoveAxis;
+
+    /// Computes the [`renyi_entropy`] of order `alpha` of the array values
+    /// using a logarithm of the given `base`.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `base` is not a valid logarithm base (see [`LogBase`]) or
+    /// under the same conditions as [`renyi_entropy`].
+    ///
+    /// [`renyi_entropy`]: #tymethod.renyi_entropy
+    /// [`LogBase`]: enum.LogBase.html
+    fn renyi_entropy_with_base(&self, alpha: A, base: LogBase<A>) -> Result<A, EmptyInput>
+    where
+        A: Float;
+
+    /// Computes the [`renyi_divergence`] of order `alpha` between two arrays
+    /// using a logarithm of the given `base`.
+    ///
+    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the array shapes are not identical,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// **Panics** if `base` is not a valid logarithm base (see [`LogBase`]) or
+    /// under the same conditions as [`renyi_divergence`].
+    ///
+    /// [`renyi_divergence`]: #tymethod.renyi_divergence
+    /// [`LogBase`]: enum.LogBase.html
+    fn renyi_divergence_with_base<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        alpha: A,
+        base: LogBase<A>,
+    ) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [`entropy`] of each lane of the array along `axis`.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `ln` of any element in the array panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::EntropyExt;
+    ///
+    /// // One distribution per row
+    /// let p = array![[0.5, 0.5], [1., 0.]];
+    /// let entropies = p.entropy_axis(Axis(1)).unwrap();
+    /// assert_abs_diff_eq!(entropies, array![2f64.ln(), 0.], epsilon = 1e-12);
+    /// ```
+    ///
+    /// [`entropy`]: #tymethod.entropy
+    fn entropy_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float,
+        D: RemoveAxis;
+
+    /// Computes the [`kl_divergence`] between each lane of `self` along `axis`
+    /// and the corresponding lane of `q`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `q` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds or under the same conditions as [`kl_divergence`].
+    ///
+    /// [`kl_divergence`]: #tymethod.kl_divergence
+    fn kl_divergence_axis<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        axis: Axis,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+        D: RemoveAxis;
+
+    /// Computes the [`cross_entropy`] between each lane of `self` along `axis`
+    /// and the corresponding lane of `q`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `q` don't have the same shape
+    ///
+    /// **Panics** if `axis` is out of bounds or under the same conditions as [`cross_entropy`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::EntropyExt;
+    ///
+    /// // Targets and predicted probabilities of a classifier, one sample per row
+    /// let targets = array![[1., 0., 0.], [0., 1., 0.]];
+    /// let predictions = array![[0.8, 0.1, 0.1], [0.25, 0.5, 0.25]];
+    /// let losses = targets.cross_entropy_axis(&predictions, Axis(1)).unwrap();
+    /// assert_abs_diff_eq!(losses, array![-0.8f64.ln(), 2f64.ln()], epsilon = 1e-12);
+    /// ```
+    ///
+    /// [`cross_entropy`]: #tymethod.cross_entropy
+    fn cross_entropy_axis<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        axis: Axis,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+        D: RemoveAxis;
+
+    /// Computes the [`entropy_axis`] using a logarithm of the given `base`.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `base` is not a valid logarithm base (see [`LogBase`]) or
+    /// under the same conditions as [`entropy_axis`].
+    ///
+    /// [`entropy_axis`]: #tymethod.entropy_axis
+    /// [`LogBase`]: enum.LogBase.html
+    fn entropy_axis_with_base(
+        &self,
+        axis: Axis,
+        base: LogBase<A>,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float,
+        D: RemoveAxis;
+
+    /// Computes the [`kl_divergence_axis`] using a logarithm of the given `base`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `q` don't have the same shape
+    ///
+    /// **Panics** if `base` is not a valid logarithm base (see [`LogBase`]) or
+    /// under the same conditions as [`kl_divergence_axis`].
+    ///
+    /// [`kl_divergence_axis`]: #tymethod.kl_divergence_axis
+    /// [`LogBase`]: enum.LogBase.html
+    fn kl_divergence_axis_with_base<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        axis: Axis,
+        base: LogBase<A>,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+        D: RemoveAxis;
+
+    /// Computes the [`cross_entropy_axis`] using a logarithm of the given `base`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `q` don't have the same shape
+    ///
+    /// **Panics** if `base` is not a valid logarithm base (see [`LogBase`]) or
+    /// under the same conditions as [`cross_entropy_axis`].
+    ///
+    /// [`cross_entropy_axis`]: #tymethod.cross_entropy_axis
+    /// [`LogBase`]: enum.LogBase.html
+    fn cross_entropy_axis_with_base<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        axis: Axis,
+        base: LogBase<A>,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+        D: RemoveAxis;
+
+    /// Computes the [Hellinger distance] *H(p,q)* between two arrays, where `self`=*p*.
+    ///
+    /// The Hellinger distance is defined as:
+    ///
+    /// ```text
+    ///              ⎛ 1   n              ⎞ ½
+    /// H(p,q) =     ⎜ ―   ∑ (√pᵢ - √qᵢ)² ⎟
+    ///              ⎝ 2  i=1             ⎠
+    /// ```
+    ///
+    /// For probability distributions, it lies between 0 and 1, and it is equal to
+    /// *√(1 - BC(p,q))*, where *BC* is the [`bhattacharyya_coefficient`].
+    ///
+    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the array shapes are not identical,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// **Panics** if, for a pair of elements *(pᵢ, qᵢ)* from *p* and *q*, computing
+    /// *√pᵢ* or *√qᵢ* is a panic cause for `A`.
+    ///
+    /// ## Remarks
+    ///
+    /// As for [`kl_divergence`], the array values are **not** normalised by this function,
+    /// and NaN values propagate to the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::EntropyExt;
+    ///
+    /// let p = array![0.5, 0.5, 0.];
+    /// let q = array![0., 0.5, 0.5];
+    /// assert_abs_diff_eq!(p.hellinger_distance(&q).unwrap(), 0.5f64.sqrt(), epsilon = 1e-12);
+    /// assert_abs_diff_eq!(p.bhattacharyya_coefficient(&q).unwrap(), 0.5, epsilon = 1e-12);
+    /// assert_abs_diff_eq!(p.total_variation_distance(&q).unwrap(), 0.5);
+    /// ```
+    ///
+    /// [Hellinger distance]: https://en.wikipedia.org/wiki/Hellinger_distance
+    /// [`bhattacharyya_coefficient`]: #tymethod.bhattacharyya_coefficient
+    /// [`kl_divergence`]: #tymethod.kl_divergence
+    fn hellinger_distance<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [Bhattacharyya coefficient] *BC(p,q)* between two arrays,
+    /// where `self`=*p*.
+    ///
+    /// The Bhattacharyya coefficient is defined as:
+    ///
+    /// ```text
+    ///             n
+    /// BC(p,q) =   ∑ √(pᵢ qᵢ)
+    ///            i=1
+    /// ```
+    ///
+    /// For probability distributions, it lies between 0 (disjoint supports) and 1
+    /// (identical distributions).
+    ///
+    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the array shapes are not identical,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// **Panics** if, for a pair of elements *(pᵢ, qᵢ)* from *p* and *q*, computing
+    /// *√(pᵢ qᵢ)* is a panic cause for `A`.
+    ///
+    /// ## Remarks
+    ///
+    /// As for [`kl_divergence`], the array values are **not** normalised by this function,
+    /// and NaN values propagate to the result.
+    ///
+    /// [Bhattacharyya coefficient]: https://en.wikipedia.org/wiki/Bhattacharyya_distance
+    /// [`kl_divergence`]: #tymethod.kl_divergence
+    fn bhattacharyya_coefficient<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [Bhattacharyya distance] *D_B(p,q)* between two arrays,
+    /// where `self`=*p*, defined as
+    ///
+    /// ```text
+    /// D_B(p,q) = - ln(BC(p,q))
+    /// ```
+    ///
+    /// where *BC* is the [`bhattacharyya_coefficient`]. It is infinite if the
+    /// distributions have disjoint supports.
+    ///
+    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the array shapes are not identical,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// **Panics** if, for a pair of elements *(pᵢ, qᵢ)* from *p* and *q*, computing
+    /// *√(pᵢ qᵢ)* is a panic cause for `A`.
+    ///
+    /// [Bhattacharyya distance]: https://en.wikipedia.org/wiki/Bhattacharyya_distance
+    /// [`bhattacharyya_coefficient`]: #tymethod.bhattacharyya_coefficient
+    fn bhattacharyya_distance<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [`bhattacharyya_distance`] between two arrays using a logarithm
+    /// of the given `base`.
+    ///
+    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the array shapes are not identical,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// **Panics** if `base` is not a valid logarithm base (see [`LogBase`]) or
+    /// under the same conditions as [`bhattacharyya_distance`].
+    ///
+    /// [`bhattacharyya_distance`]: #tymethod.bhattacharyya_distance
+    /// [`LogBase`]: enum.LogBase.html
+    fn bhattacharyya_distance_with_base<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        base: LogBase<A>,
+    ) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the [total variation distance] *δ(p,q)* between two arrays,
+    /// where `self`=*p*.
+    ///
+    /// The total variation distance is defined as:
+    ///
+    /// ```text
+    ///           1   n
+    /// δ(p,q) =  ―   ∑ |pᵢ - qᵢ|
+    ///           2  i=1
+    /// ```
+    ///
+    /// For probability distributions, it lies between 0 and 1, and it is the largest
+    /// difference between the probabilities that *p* and *q* assign to the same event.
+    ///
+    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the array shapes are not identical,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// ## Remarks
+    ///
+    /// As for [`kl_divergence`], the array values are **not** normalised by this function,
+    /// and NaN values propagate to the result.
+    ///
+    /// [total variation distance]: https://en.wikipedia.org/wiki/Total_variation_distance_of_probability_measures
+    /// [`kl_divergence`]: #tymethod.kl_divergence
+    fn total_variation_distance<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    /// Computes the (Pearson) [chi-square divergence] *χ²(p,q)* between two arrays,
+    /// where `self`=*p*.
+    ///
+    /// The chi-square divergence is defined as:
+    ///
+    /// ```text
+    ///             n  (pᵢ - qᵢ)²
+    /// χ²(p,q) =   ∑  ――――――――――
+    ///            i=1     qᵢ
+    /// ```
+    ///
+    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
+    /// If the array shapes are not identical,
+    /// `Err(MultiInputError::ShapeMismatch)` is returned.
+    ///
+    /// ## Remarks
+    ///
+    /// As for [`kl_divergence`], the array values are **not** normalised by this function,
+    /// and NaN values propagate to the result.
+    ///
+    /// By definition, *(pᵢ - qᵢ)²/qᵢ* is set to 0 if both *pᵢ* and *qᵢ* are 0; it is
+    /// infinite if only *qᵢ* is 0.
+    ///
+    /// [chi-square divergence]: https://en.wikipedia.org/wiki/F-divergence#Instances_of_f-divergences
+    /// [`kl_divergence`]: #tymethod.kl_divergence
+    fn chi_square_divergence<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float;
+
+    private_decl! {}
+}
+
+impl<A, S, D> EntropyExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn entropy(&self) -> Result<A, EmptyInput>
+    where
+        A: Float,
+    {
+        if self.len() == 0 {
+            Err(EmptyInput)
+        } else {
+            let entropy = -self
+                .mapv(|x| {
+                    if x == A::zero() {
+                        A::zero()
+                    } else {
+                        x * x.ln()
+                    }
+                })
+                .sum();
+            Ok(entropy)
+        }
+    }
+
+    fn kl_divergence<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        A: Float,
+        S2: Data<Elem = A>,
+    {
+        if self.len() == 0 {
+            return Err(MultiInputError::EmptyInput);
+        }
+        if self.shape() != q.shape() {
+            return Err(ShapeMismatch {
+                first_shape: self.shape().to_vec(),
+                second_shape: q.shape().to_vec(),
+            }
+            .into());
+        }
+
+        let mut temp = Array::zeros(self.raw_dim());
+        Zip::from(&mut temp)
+            .and(self)
+            .and(q)
+            .apply(|result, &p, &q| {
+                *result = {
+                    if p == A::zero() {
+                        A::zero()
+                    } else {
+                        p * (q / p).ln()
+                    }
+                }
+            });
+        let kl_divergence = -temp.sum();
+        Ok(kl_divergence)
+    }
+
+    fn cross_entropy<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        if self.len() == 0 {
+            return Err(MultiInputError::EmptyInput);
+        }
+        if self.shape() != q.shape() {
+            return Err(ShapeMismatch {
+                first_shape: self.shape().to_vec(),
+                second_shape: q.shape().to_vec(),
+            }
+            .into());
+        }
+
+        let mut temp = Array::zeros(self.raw_dim());
+        Zip::from(&mut temp)
+            .and(self)
+            .and(q)
+            .apply(|result, &p, &q| {
+                *result = {
+                    if p == A::zero() {
+                        A::zero()
+                    } else {
+                        p * q.ln()
+                    }
+                }
+            });
+        let cross_entropy = -temp.sum();
+        Ok(cross_entropy)
+    }
+
+    fn js_divergence<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        if self.is_empty() {
+            return Err(MultiInputError::EmptyInput);
+        }
+        if self.shape() != q.shape() {
+            return Err(ShapeMismatch {
+                first_shape: self.shape().to_vec(),
+                second_shape: q.shape().to_vec(),
+            }
+            .into());
+        }
+
+        let half = A::from(0.5).unwrap();
+        let mut mixture = Array::zeros(self.raw_dim());
+        Zip::from(&mut mixture)
+            .and(self)
+            .and(q)
+            .apply(|m, &p, &q| *m = half * (p + q));
+        let js_divergence = half * (self.kl_divergence(&mixture)? + q.kl_divergence(&mixture)?);
+        Ok(js_divergence)
+    }
+
+    fn js_distance<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        Ok(self.js_divergence(q)?.sqrt())
+    }
+
+    fn generalized_js_divergence(
+        &self,
+        axis: Axis,
+        weights: Option<ArrayView1<A>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        if self.is_empty() {
+            return Err(MultiInputError::EmptyInput);
+        }
+        let n = self.len_of(axis);
+        let weights = match weights {
+            Some(weights) => {
+                if weights.len() != n {
+                    return Err(ShapeMismatch {
+                        first_shape: self.shape().to_vec(),
+                        second_shape: weights.shape().to_vec(),
+                    }
+                    .into());
+                }
+                weights.to_owned()
+            }
+            None => Array1::from_elem(n, A::one() / A::from(n).unwrap()),
+        };
+
+        let mut mixture = Array::zeros(self.raw_dim().remove_axis(axis));
+        for (p, &w) in self.axis_iter(axis).zip(&weights) {
+            Zip::from(&mut mixture)
+                .and(&p)
+                .apply(|m, &p| *m = *m + w * p);
+        }
+        let mut js_divergence = A::zero();
+        for (p, &w) in self.axis_iter(axis).zip(&weights) {
+            if w != A::zero() {
+                js_divergence = js_divergence + w * p.kl_divergence(&mixture)?;
+            }
+        }
+        Ok(js_divergence)
+    }
+
+    fn renyi_entropy(&self, alpha: A) -> Result<A, EmptyInput>
+    where
+        A: Float,
+    {
+        assert!(alpha >= A::zero(), "`alpha` must be non-negative.");
+        if alpha == A::one() {
+            return self.entropy();
+        }
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        let non_zero = self.iter().filter(|&&x| x != A::zero());
+        let renyi_entropy = if alpha.is_infinite() {
+            -non_zero
+                .fold(A::zero(), |max, &x| nan_propagating_max(max, x))
+                .ln()
+        } else {
+            let sum = non_zero.fold(A::zero(), |acc, &x| acc + x.powf(alpha));
+            sum.ln() / (A::one() - alpha)
+        };
+        Ok(renyi_entropy)
+    }
+
+    fn tsallis_entropy(&self, q: A) -> Result<A, EmptyInput>
+    where
+        A: Float,
+    {
+        assert!(!q.is_nan(), "`q` must not be NaN.");
+        if q == A::one() {
+            return self.entropy();
+        }
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        let sum = self
+            .iter()
+            .filter(|&&x| x != A::zero())
+            .fold(A::zero(), |acc, &x| acc + x.powf(q));
+        Ok((A::one() - sum) / (q - A::one()))
+    }
+
+    fn renyi_divergence<S2>(&self, q: &ArrayBase<S2, D>, alpha: A) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        assert!(alpha >= A::zero(), "`alpha` must be non-negative.");
+        if alpha == A::one() {
+            return self.kl_divergence(q);
+        }
+        if self.is_empty() {
+            return Err(MultiInputError::EmptyInput);
+        }
+        if self.shape() != q.shape() {
+            return Err(ShapeMismatch {
+                first_shape: self.shape().to_vec(),
+                second_shape: q.shape().to_vec(),
+            }
+            .into());
+        }
+
+        let mut acc = A::zero();
+        Zip::from(self).and(q).apply(|&p, &q| {
+            if p != A::zero() {
+                acc = if alpha.is_infinite() {
+                    nan_propagating_max(acc, p / q)
+                } else {
+                    acc + p.powf(alpha) * q.powf(A::one() - alpha)
+                };
+            }
+        });
+        let renyi_divergence = if alpha.is_infinite() {
+            acc.ln()
+        } else {
+            acc.ln() / (alpha - A::one())
+        };
+        Ok(renyi_divergence)
+    }
+
+    fn entropy_with_base(&self, base: LogBase<A>) -> Result<A, EmptyInput>
+    where
+        A: Float,
+    {
+        Ok(base.from_nats(self.entropy()?))
+    }
+
+    fn kl_divergence_with_base<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        base: LogBase<A>,
+    ) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        Ok(base.from_nats(self.kl_divergence(q)?))
+    }
+
+    fn cross_entropy_with_base<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        base: LogBase<A>,
+    ) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        Ok(base.from_nats(self.cross_entropy(q)?))
+    }
+
+    fn js_divergence_with_base<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        base: LogBase<A>,
+    ) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        Ok(base.from_nats(self.js_divergence(q)?))
+    }
+
+    fn js_distance_with_base<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        base: LogBase<A>,
+    ) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        Ok(self.js_divergence_with_base(q, base)?.sqrt())
+    }
+
+    fn generalized_js_divergence_with_base(
+        &self,
+        axis: Axis,
+        weights: Option<ArrayView1<A>>,
+        base: LogBase<A>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        Ok(base.from_nats(self.generalized_js_divergence(axis, weights)?))
+    }
+
+    fn renyi_entropy_with_base(&self, alpha: A, base: LogBase<A>) -> Result<A, EmptyInput>
+    where
+        A: Float,
+    {
+        Ok(base.from_nats(self.renyi_entropy(alpha)?))
+    }
+
+    fn renyi_divergence_with_base<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        alpha: A,
+        base: LogBase<A>,
+    ) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        Ok(base.from_nats(self.renyi_divergence(q, alpha)?))
+    }
+
+    fn entropy_axis(&self, axis: Axis) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        Ok(self.map_axis(axis, |lane| {
+            lane.entropy()
+                .expect("lanes of a non-empty array are not empty")
+        }))
+    }
+
+    fn kl_divergence_axis<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        axis: Axis,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+        D: RemoveAxis,
+    {
+        map_lanes(self, q, axis, |p, q| p.kl_divergence(&q))
+    }
+
+    fn cross_entropy_axis<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        axis: Axis,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+        D: RemoveAxis,
+    {
+        map_lanes(self, q, axis, |p, q| p.cross_entropy(&q))
+    }
 
-    /// Computes the [cross entropy] *H(p,q)* between two arrays,
-    /// where `self`=*p*.
-    ///
-    /// The cross entropy is defined as:
-    ///
-    /// ```text
-    ///            n
-    /// H(p,q) = - ∑ pᵢ ln(qᵢ)
-    ///           i=1
-    /// ```
-    ///
-    /// If the arrays are empty, `Err(MultiInputError::EmptyInput)` is returned.
-    /// If the array shapes are not identical,
-    /// `Err(MultiInputError::ShapeMismatch)` is returned.
-    ///
-    /// **Panics** if any element in *q* is negative and taking the logarithm of a negative number
-    /// is a panic cause for `A`.
-    ///
-    /// ## Remarks
-    ///
-    /// The cross entropy is a measure used in [Information Theory]
-    /// to describe the relationship between two probability distributions: it only makes sense
-    /// when each array sums to 1 with entries between 0 and 1 (extremes included).
-    ///
-    /// The array values are **not** normalised by this function before
-    /// computing the entropy to avoid introducing potentially
-    /// unnecessary numerical errors (e.g. if the array were to be already normalised).
-    ///
-    /// The cross entropy is often used as an objective/loss function in
-    /// [optimization problems], including [machine learning].
-    ///
-    /// By definition, *pᵢ ln(qᵢ)* is set to 0 if *pᵢ* is 0.
-    ///
-    /// [cross entropy]: https://en.wikipedia.org/wiki/Cross-entropy
-    /// [Information Theory]: https://en.wikipedia.org/wiki/Information_theory
-    /// [optimization problems]: https://en.wikipedia.org/wiki/Cross-entropy_method
-    /// [machine learning]: https://en.wikipedia.org/wiki/Cross_entropy#Cross-entropy_error_function_and_logistic_regression
-    fn cross_entropy<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    fn entropy_axis_with_base(
+        &self,
+        axis: Axis,
+        base: LogBase<A>,
+    ) -> Result<Array<A, D::Smaller>, EmptyInput>
+    where
+        A: Float,
+        D: RemoveAxis,
+    {
+        Ok(self.entropy_axis(axis)?.mapv(|x| base.from_nats(x)))
+    }
+
+    fn kl_divergence_axis_with_base<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        axis: Axis,
+        base: LogBase<A>,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
     where
         S2: Data<Elem = A>,
-        A: Float;
+        A: Float,
+        D: RemoveAxis,
+    {
+        Ok(self
+            .kl_divergence_axis(q, axis)?
+            .mapv(|x| base.from_nats(x)))
+    }
 
-    private_decl! {}
-}
+    fn cross_entropy_axis_with_base<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        axis: Axis,
+        base: LogBase<A>,
+    ) -> Result<Array<A, D::Smaller>, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+        D: RemoveAxis,
+    {
+        Ok(self
+            .cross_entropy_axis(q, axis)?
+            .mapv(|x| base.from_nats(x)))
+    }
 
-impl<A, S, D> EntropyExt<A, S, D> for ArrayBase<S, D>
-where
-    S: Data<Elem = A>,
-    D: Dimension,
-{
-    fn entropy(&self) -> Result<A, EmptyInput>
+    fn hellinger_distance<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
     where
+        S2: Data<Elem = A>,
         A: Float,
     {
-        if self.len() == 0 {
-            Err(EmptyInput)
-        } else {
-            let entropy = -self
-                .mapv(|x| {
-                    if x == A::zero() {
-                        A::zero()
-                    } else {
-                        x * x.ln()
-                    }
-                })
-                .sum();
-            Ok(entropy)
-        }
+        let sum = sum_over_pairs(self, q, |p, q| {
+            let difference = p.sqrt() - q.sqrt();
+            difference * difference
+        })?;
+        Ok((sum / A::from(2.).unwrap()).sqrt())
     }
 
-    fn kl_divergence<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    fn bhattacharyya_coefficient<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
     where
+        S2: Data<Elem = A>,
         A: Float,
+    {
+        sum_over_pairs(self, q, |p, q| (p * q).sqrt())
+    }
+
+    fn bhattacharyya_distance<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
         S2: Data<Elem = A>,
+        A: Float,
     {
-        if self.len() == 0 {
-            return Err(MultiInputError::EmptyInput);
-        }
-        if self.shape() != q.shape() {
-            return Err(ShapeMismatch {
-                first_shape: self.shape().to_vec(),
-                second_shape: q.shape().to_vec(),
-            }
-            .into());
-        }
+        Ok(-self.bhattacharyya_coefficient(q)?.ln())
+    }
 
-        let mut temp = Array::zeros(self.raw_dim());
-        Zip::from(&mut temp)
-            .and(self)
-            .and(q)
-            .apply(|result, &p, &q| {
-                *result = {
-                    if p == A::zero() {
-                        A::zero()
-                    } else {
-                        p * (q / p).ln()
-                    }
-                }
-            });
-        let kl_divergence = -temp.sum();
-        Ok(kl_divergence)
+    fn bhattacharyya_distance_with_base<S2>(
+        &self,
+        q: &ArrayBase<S2, D>,
+        base: LogBase<A>,
+    ) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        Ok(base.from_nats(self.bhattacharyya_distance(q)?))
     }
 
-    fn cross_entropy<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    fn total_variation_distance<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
     where
         S2: Data<Elem = A>,
         A: Float,
     {
-        if self.len() == 0 {
-            return Err(MultiInputError::EmptyInput);
-        }
-        if self.shape() != q.shape() {
-            return Err(ShapeMismatch {
-                first_shape: self.shape().to_vec(),
-                second_shape: q.shape().to_vec(),
-            }
-            .into());
-        }
+        let sum = sum_over_pairs(self, q, |p, q| (p - q).abs())?;
+        Ok(sum / A::from(2.).unwrap())
+    }
 
-        let mut temp = Array::zeros(self.raw_dim());
-        Zip::from(&mut temp)
-            .and(self)
-            .and(q)
-            .apply(|result, &p, &q| {
-                *result = {
-                    if p == A::zero() {
-                        A::zero()
-                    } else {
-                        p * q.ln()
-                    }
-                }
-            });
-        let cross_entropy = -temp.sum();
-        Ok(cross_entropy)
+    fn chi_square_divergence<S2>(&self, q: &ArrayBase<S2, D>) -> Result<A, MultiInputError>
+    where
+        S2: Data<Elem = A>,
+        A: Float,
+    {
+        sum_over_pairs(self, q, |p, q| {
+            if p == A::zero() && q == A::zero() {
+                A::zero()
+            } else {
+                (p - q) * (p - q) / q
+            }
+        })
     }
 
     private_impl! {}
 }
 
+/// Returns the sum of `f` over the pairs of corresponding elements of `p` and `q`.
+fn sum_over_pairs<A, S, S2, D, F>(
+    p: &ArrayBase<S, D>,
+    q: &ArrayBase<S2, D>,
+    f: F,
+) -> Result<A, MultiInputError>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D: Dimension,
+    F: Fn(A, A) -> A,
+{
+    return_err_if_empty!(p);
+    return_err_unless_same_shape!(p, q);
+
+    let mut sum = A::zero();
+    Zip::from(p).and(q).apply(|&p, &q| sum = sum + f(p, q));
+    Ok(sum)
+}
+
+/// Returns the maximum of `a` and `b`, or NaN if either of them is NaN.
+fn nan_propagating_max<A: Float>(a: A, b: A) -> A {
+    if a.is_nan() || b.is_nan() {
+        A::nan()
+    } else {
+        a.max(b)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::EntropyExt;
+    use super::{EntropyExt, LogBase};
     use crate::errors::{EmptyInput, MultiInputError};
     use approx::assert_abs_diff_eq;
-    use ndarray::{array, Array1};
+    use ndarray::{array, s, Array, Array1, Array2, Axis};
     use noisy_float::types::n64;
     use std::f64;
 
@@ -396,4 +1582,434 @@ mod tests {
         assert_abs_diff_eq!(p.kl_divergence(&q)?, expected_kl, epsilon = 1e-6);
         Ok(())
     }
+    #[test]
+    fn test_js_divergence_and_distance() -> Result<(), MultiInputError> {
+        let p = array![0.5, 0.5];
+        let q = array![1., 0.];
+        // Computed using scipy.spatial.distance.jensenshannon(p, q)
+        let expected_js_distance: f64 = 0.46450140402245893;
+        assert_abs_diff_eq!(p.js_distance(&q)?, expected_js_distance, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            q.js_divergence(&p)?,
+            expected_js_distance.powi(2),
+            epsilon = 1e-12
+        );
+        assert_eq!(p.js_divergence(&p)?, 0.);
+        // Distributions with disjoint supports
+        let r = array![0., 1.];
+        assert_abs_diff_eq!(q.js_divergence(&r)?, 2f64.ln(), epsilon = 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn test_js_divergence_errors_and_nan_values() -> Result<(), MultiInputError> {
+        let p = array![f64::NAN, 1.];
+        let q = array![0.5, 0.5];
+        assert!(p.js_divergence(&q)?.is_nan());
+        assert!(q.js_distance(&p)?.is_nan());
+        let s = array![0.2, 0.3, 0.5];
+        assert!(q.js_divergence(&s).unwrap_err().is_shape_mismatch());
+        let empty: Array1<f64> = array![];
+        assert!(empty.js_distance(&empty).unwrap_err().is_empty_input());
+        Ok(())
+    }
+
+    #[test]
+    fn test_generalized_js_divergence() -> Result<(), MultiInputError> {
+        let distributions = array![[0.1, 0.2, 0.7], [0.3, 0.3, 0.4], [0.6, 0.2, 0.2]];
+        assert_abs_diff_eq!(
+            distributions.generalized_js_divergence(Axis(0), None)?,
+            0.12114890527730267,
+            epsilon = 1e-12
+        );
+        let weights = array![0.5, 0.25, 0.25];
+        assert_abs_diff_eq!(
+            distributions.generalized_js_divergence(Axis(0), Some(weights.view()))?,
+            0.1265146825004564,
+            epsilon = 1e-12
+        );
+        // Distributions along columns, and zero weights
+        let weights = array![0.5, 0.5, 0.];
+        assert_abs_diff_eq!(
+            distributions
+                .t()
+                .generalized_js_divergence(Axis(1), Some(weights.view()))?,
+            distributions.row(0).js_divergence(&distributions.row(1))?,
+            epsilon = 1e-12
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_generalized_js_divergence_errors() {
+        let distributions = array![[0.5, 0.5], [1., 0.]];
+        let weights = array![0.2, 0.3, 0.5];
+        assert!(distributions
+            .generalized_js_divergence(Axis(0), Some(weights.view()))
+            .unwrap_err()
+            .is_shape_mismatch());
+        let empty = Array2::<f64>::zeros((0, 2));
+        assert!(empty
+            .generalized_js_divergence(Axis(0), None)
+            .unwrap_err()
+            .is_empty_input());
+    }
+    #[test]
+    fn test_renyi_entropy() -> Result<(), EmptyInput> {
+        let p = array![0.5, 0.25, 0.25, 0.];
+        assert_abs_diff_eq!(p.renyi_entropy(0.)?, 3f64.ln(), epsilon = 1e-12);
+        assert_eq!(p.renyi_entropy(1.)?, p.entropy()?);
+        assert_abs_diff_eq!(p.renyi_entropy(2.)?, 0.9808292530117262, epsilon = 1e-12);
+        assert_abs_diff_eq!(p.renyi_entropy(3.)?, 0.9281489951828131, epsilon = 1e-12);
+        assert_abs_diff_eq!(p.renyi_entropy(f64::INFINITY)?, 2f64.ln(), epsilon = 1e-12);
+        // Continuity around the Shannon entropy
+        assert_abs_diff_eq!(p.renyi_entropy(1. + 1e-9)?, p.entropy()?, epsilon = 1e-6);
+        // Rényi entropies decrease with the order
+        assert!(p.renyi_entropy(0.5)? > p.entropy()?);
+        assert!(p.renyi_entropy(10.)? > p.renyi_entropy(f64::INFINITY)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_tsallis_entropy() -> Result<(), EmptyInput> {
+        let p = array![0.5, 0.25, 0.25, 0.];
+        assert_eq!(p.tsallis_entropy(1.)?, p.entropy()?);
+        assert_abs_diff_eq!(p.tsallis_entropy(2.)?, 0.625, epsilon = 1e-12);
+        assert_abs_diff_eq!(p.tsallis_entropy(3.)?, 0.421875, epsilon = 1e-12);
+        assert_abs_diff_eq!(p.tsallis_entropy(0.)?, 2., epsilon = 1e-12);
+        assert_abs_diff_eq!(p.tsallis_entropy(1. - 1e-9)?, p.entropy()?, epsilon = 1e-6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_renyi_divergence() -> Result<(), MultiInputError> {
+        let p = array![0.5, 0.25, 0.25];
+        let q = array![1. / 3., 1. / 3., 1. / 3.];
+        assert_eq!(p.renyi_divergence(&q, 1.)?, p.kl_divergence(&q)?);
+        assert_abs_diff_eq!(
+            p.renyi_divergence(&q, 0.5)?,
+            0.029012295188969018,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(p.renyi_divergence(&q, 2.)?, 1.125f64.ln(), epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            p.renyi_divergence(&q, f64::INFINITY)?,
+            1.5f64.ln(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(p.renyi_divergence(&q, 0.)?, 0., epsilon = 1e-12);
+        let r = array![0., 0.5, 0.5];
+        assert_abs_diff_eq!(
+            r.renyi_divergence(&q, 0.)?,
+            -(2f64 / 3.).ln(),
+            epsilon = 1e-12
+        );
+        assert_eq!(p.renyi_divergence(&r, 2.)?, f64::INFINITY);
+        assert_abs_diff_eq!(p.renyi_divergence(&p, 3.)?, 0., epsilon = 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn test_renyi_with_nan_values() -> Result<(), MultiInputError> {
+        let p = array![f64::NAN, 1.];
+        let q = array![0.5, 0.5];
+        assert!(p.renyi_entropy(2.)?.is_nan());
+        assert!(p.renyi_entropy(f64::INFINITY)?.is_nan());
+        assert!(p.tsallis_entropy(2.)?.is_nan());
+        assert!(p.renyi_divergence(&q, 2.)?.is_nan());
+        assert!(q.renyi_divergence(&p, f64::INFINITY)?.is_nan());
+        Ok(())
+    }
+
+    #[test]
+    fn test_renyi_errors() {
+        let empty: Array1<f64> = array![];
+        assert_eq!(empty.renyi_entropy(2.), Err(EmptyInput));
+        assert_eq!(empty.tsallis_entropy(2.), Err(EmptyInput));
+        assert!(empty
+            .renyi_divergence(&empty, 2.)
+            .unwrap_err()
+            .is_empty_input());
+        let p = array![0.5, 0.5];
+        let q = array![0.2, 0.3, 0.5];
+        assert!(p.renyi_divergence(&q, 0.5).unwrap_err().is_shape_mismatch());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_renyi_entropy_with_negative_order_panics() {
+        let _ = array![0.5, 0.5].renyi_entropy(-1.);
+    }
+
+    #[test]
+    fn test_log_base() -> Result<(), MultiInputError> {
+        let p = array![0.5, 0.25, 0.25];
+        let q = array![0.25, 0.25, 0.5];
+        assert_eq!(LogBase::E.from_nats(1.5f64), 1.5);
+        assert_abs_diff_eq!(LogBase::Custom(2f64).ln(), LogBase::Two.ln());
+        assert_abs_diff_eq!(LogBase::<f64>::Ten.ln(), 10f64.ln());
+
+        assert_abs_diff_eq!(p.entropy_with_base(LogBase::Two)?, 1.5, epsilon = 1e-12);
+        assert_abs_diff_eq!(p.entropy_with_base(LogBase::E)?, p.entropy()?);
+        assert_abs_diff_eq!(
+            p.entropy_with_base(LogBase::Ten)?,
+            p.entropy()? / 10f64.ln(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            p.entropy_with_base(LogBase::Custom(4.))?,
+            0.75,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            p.cross_entropy_with_base(&q, LogBase::Two)?,
+            1.75,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            p.kl_divergence_with_base(&q, LogBase::Two)?,
+            0.25,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            array![1., 0.].js_divergence_with_base(&array![0., 1.], LogBase::Two)?,
+            1.,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            p.js_distance_with_base(&q, LogBase::Two)?,
+            p.js_divergence_with_base(&q, LogBase::Two)?.sqrt()
+        );
+        let distributions = array![[1., 0.], [0., 1.]];
+        assert_abs_diff_eq!(
+            distributions.generalized_js_divergence_with_base(Axis(0), None, LogBase::Two)?,
+            1.,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(p.renyi_entropy_with_base(0., LogBase::Two)?, 3f64.log2());
+        assert_abs_diff_eq!(
+            p.renyi_entropy_with_base(f64::INFINITY, LogBase::Two)?,
+            1.,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            p.renyi_divergence_with_base(&q, f64::INFINITY, LogBase::Two)?,
+            1.,
+            epsilon = 1e-12
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_log_base_errors() {
+        let empty: Array1<f64> = array![];
+        assert_eq!(empty.entropy_with_base(LogBase::Two), Err(EmptyInput));
+        assert!(empty
+            .kl_divergence_with_base(&empty, LogBase::Two)
+            .unwrap_err()
+            .is_empty_input());
+        assert!(array![0.5, 0.5]
+            .cross_entropy_with_base(&array![1.], LogBase::Ten)
+            .unwrap_err()
+            .is_shape_mismatch());
+        assert!(array![f64::NAN, 1.]
+            .entropy_with_base(LogBase::Two)
+            .unwrap()
+            .is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_log_base_one_panics() {
+        let _ = array![0.5, 0.5].entropy_with_base(LogBase::Custom(1.));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_log_base_negative_panics() {
+        let _ = array![0.5, 0.5].entropy_with_base(LogBase::Custom(-2.));
+    }
+
+    #[test]
+    fn test_entropy_axis() -> Result<(), MultiInputError> {
+        let p = array![[0.5, 0.25, 0.25], [1., 0., 0.]];
+        let q = array![[0.25, 0.25, 0.5], [0.5, 0.25, 0.25]];
+        for axis in 0..2 {
+            let axis = Axis(axis);
+            let entropies = p.entropy_axis(axis)?;
+            let kl_divergences = p.kl_divergence_axis(&q, axis)?;
+            let cross_entropies = p.cross_entropy_axis(&q, axis)?;
+            assert_eq!(entropies.len(), p.len_of(Axis(1 - axis.index())));
+            for (i, (lane_p, lane_q)) in p.lanes(axis).into_iter().zip(q.lanes(axis)).enumerate() {
+                assert_abs_diff_eq!(entropies[i], lane_p.entropy()?);
+                assert_abs_diff_eq!(kl_divergences[i], lane_p.kl_divergence(&lane_q)?);
+                assert_abs_diff_eq!(cross_entropies[i], lane_p.cross_entropy(&lane_q)?);
+            }
+        }
+        assert_abs_diff_eq!(
+            p.entropy_axis_with_base(Axis(1), LogBase::Two)?,
+            array![1.5, 0.],
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            p.kl_divergence_axis_with_base(&q, Axis(1), LogBase::Two)?,
+            array![0.25, 1.],
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            p.cross_entropy_axis_with_base(&q, Axis(1), LogBase::Two)?,
+            array![1.75, 1.],
+            epsilon = 1e-12
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_entropy_axis_with_3d_array() -> Result<(), MultiInputError> {
+        let p = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (1 + i + j * k) as f64 / 30.);
+        let q = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (2 + i * j + k) as f64 / 40.);
+        let kl_divergences = p.kl_divergence_axis(&q, Axis(1))?;
+        assert_eq!(kl_divergences.shape(), &[2, 4]);
+        for i in 0..2 {
+            for k in 0..4 {
+                let lane_p = p.slice(s![i, .., k]);
+                let lane_q = q.slice(s![i, .., k]);
+                assert_abs_diff_eq!(kl_divergences[[i, k]], lane_p.kl_divergence(&lane_q)?);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_entropy_axis_errors() {
+        let empty: Array2<f64> = Array2::zeros((0, 3));
+        assert_eq!(empty.entropy_axis(Axis(1)), Err(EmptyInput));
+        assert!(empty
+            .cross_entropy_axis(&empty, Axis(1))
+            .unwrap_err()
+            .is_empty_input());
+        let p = array![[0.5, 0.5], [1., 0.]];
+        let q = array![[0.5, 0.5, 0.], [1., 0., 0.]];
+        assert!(p
+            .kl_divergence_axis(&q, Axis(1))
+            .unwrap_err()
+            .is_shape_mismatch());
+        assert!(p
+            .cross_entropy_axis(&q.t(), Axis(0))
+            .unwrap_err()
+            .is_shape_mismatch());
+        let with_nan = array![[f64::NAN, 1.], [0.5, 0.5]];
+        let entropies = with_nan.entropy_axis(Axis(1)).unwrap();
+        assert!(entropies[0].is_nan());
+        assert_abs_diff_eq!(entropies[1], 2f64.ln());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_entropy_axis_out_of_bounds_panics() {
+        let _ = array![[0.5, 0.5]].entropy_axis(Axis(2));
+    }
+
+    #[test]
+    fn test_distribution_distances() -> Result<(), MultiInputError> {
+        let p = array![0.1, 0.2, 0.3, 0.4];
+        let q = array![0.25, 0.25, 0.25, 0.25];
+        let bc: f64 = p
+            .iter()
+            .zip(&q)
+            .map(|(&p, &q): (&f64, &f64)| (p * q).sqrt())
+            .sum();
+        assert_abs_diff_eq!(p.bhattacharyya_coefficient(&q)?, bc, epsilon = 1e-12);
+        assert_abs_diff_eq!(p.bhattacharyya_distance(&q)?, -bc.ln(), epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            p.bhattacharyya_distance_with_base(&q, LogBase::Two)?,
+            -bc.log2(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(p.hellinger_distance(&q)?, (1. - bc).sqrt(), epsilon = 1e-12);
+        assert_abs_diff_eq!(p.total_variation_distance(&q)?, 0.2, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            p.chi_square_divergence(&q)?,
+            (0.0225 + 0.0025 + 0.0025 + 0.0225) / 0.25,
+            epsilon = 1e-12
+        );
+
+        // Symmetry of the distances
+        assert_abs_diff_eq!(p.hellinger_distance(&q)?, q.hellinger_distance(&p)?);
+        assert_abs_diff_eq!(
+            p.total_variation_distance(&q)?,
+            q.total_variation_distance(&p)?
+        );
+
+        // Identical distributions
+        assert_abs_diff_eq!(p.hellinger_distance(&p)?, 0.);
+        assert_abs_diff_eq!(p.bhattacharyya_coefficient(&p)?, 1., epsilon = 1e-12);
+        assert_eq!(p.total_variation_distance(&p)?, 0.);
+        assert_eq!(p.chi_square_divergence(&p)?, 0.);
+        Ok(())
+    }
+
+    #[test]
+    fn test_distribution_distances_with_zeroes() -> Result<(), MultiInputError> {
+        let p = array![0.5, 0.5, 0., 0.];
+        let q = array![0., 0., 0.5, 0.5];
+        assert_abs_diff_eq!(p.hellinger_distance(&q)?, 1.);
+        assert_eq!(p.bhattacharyya_coefficient(&q)?, 0.);
+        assert_eq!(p.bhattacharyya_distance(&q)?, f64::INFINITY);
+        assert_eq!(p.total_variation_distance(&q)?, 1.);
+        assert_eq!(p.chi_square_divergence(&q)?, f64::INFINITY);
+
+        let r = array![0.5, 0.25, 0.25, 0.];
+        assert_abs_diff_eq!(p.chi_square_divergence(&r)?, 0.5, epsilon = 1e-12);
+        assert_eq!(r.chi_square_divergence(&p)?, f64::INFINITY);
+        Ok(())
+    }
+
+    #[test]
+    fn test_distribution_distances_with_nan_values() -> Result<(), MultiInputError> {
+        let p = array![f64::NAN, 1.];
+        let q = array![0.5, 0.5];
+        assert!(p.hellinger_distance(&q)?.is_nan());
+        assert!(q.bhattacharyya_coefficient(&p)?.is_nan());
+        assert!(p.bhattacharyya_distance(&q)?.is_nan());
+        assert!(q.total_variation_distance(&p)?.is_nan());
+        assert!(p.chi_square_divergence(&q)?.is_nan());
+        assert!(q.chi_square_divergence(&p)?.is_nan());
+        Ok(())
+    }
+
+    #[test]
+    fn test_distribution_distances_errors() {
+        let empty: Array1<f64> = array![];
+        assert!(empty
+            .hellinger_distance(&empty)
+            .unwrap_err()
+            .is_empty_input());
+        assert!(empty
+            .chi_square_divergence(&empty)
+            .unwrap_err()
+            .is_empty_input());
+        let p = array![0.5, 0.5];
+        let q = array![0.25, 0.25, 0.5];
+        assert!(p
+            .bhattacharyya_coefficient(&q)
+            .unwrap_err()
+            .is_shape_mismatch());
+        assert!(p
+            .bhattacharyya_distance(&q)
+            .unwrap_err()
+            .is_shape_mismatch());
+        assert!(p
+            .bhattacharyya_distance_with_base(&q, LogBase::Two)
+            .unwrap_err()
+            .is_shape_mismatch());
+        assert!(p
+            .total_variation_distance(&q)
+            .unwrap_err()
+            .is_shape_mismatch());
+        let p = array![[0.5, 0.5]];
+        let q = array![[0.5], [0.5]];
+        assert!(p.hellinger_distance(&q).unwrap_err().is_shape_mismatch());
+        assert!(p.chi_square_divergence(&q).unwrap_err().is_shape_mismatch());
+    }
 }
@@ -0,0 +1,459 @@
+use crate::entropy::LogBase;
+use crate::errors::EmptyInput;
+use crate::histogram::{Grid, HistogramExt};
+use crate::special::{digamma, ln_gamma};
+use ndarray::prelude::*;
+use ndarray::Data;
+use num_traits::{Float, ToPrimitive};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The estimator of the entropy of a discrete distribution used by
+/// [`discrete_entropy`], given the number of occurrences of each observed value
+/// in a sample.
+///
+/// [`discrete_entropy`]: trait.EntropyEstimationExt.html#tymethod.discrete_entropy
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiscreteEntropyEstimator {
+    /// The plug-in (or maximum likelihood) estimator, i.e. the entropy of the
+    /// empirical distribution. It is biased downwards, especially when many values
+    /// are rarely (or never) observed.
+    PlugIn,
+    /// The plug-in estimator with the [Miller–Madow] bias correction *(K - 1) / 2N*,
+    /// where *K* is the number of distinct observed values and *N* is the sample size.
+    ///
+    /// [Miller–Madow]: https://doi.org/10.1162/089976603321780272
+    MillerMadow,
+    /// The [Chao–Shen] estimator, which corrects the empirical probabilities for the
+    /// unobserved values (using the Good–Turing estimate of the sample coverage) and
+    /// weights each term with the inverse of its inclusion probability
+    /// (Horvitz–Thompson estimator).
+    ///
+    /// [Chao–Shen]: https://doi.org/10.1023/A:1026096204727
+    ChaoShen,
+}
+
+/// Extension trait for two-dimensional `ArrayBase` providing methods to estimate
+/// the entropy of a distribution from a sample of observations drawn from it.
+///
+/// Each row of the array is an observation: for a shape `(n, d)`, the sample is made
+/// of `n` observations of a `d`-dimensional random variable. A one-dimensional sample
+/// can be turned into a column with `.insert_axis(Axis(1))`.
+///
+/// Contrary to [`EntropyExt`], the array values are not probabilities: they are the
+/// observed values of the random variable.
+///
+/// [`EntropyExt`]: trait.EntropyExt.html
+pub trait EntropyEstimationExt<A, S>
+where
+    S: Data<Elem = A>,
+{
+    /// Estimates the entropy of a discrete distribution from the observations (rows)
+    /// of `self`, using the given `estimator` and a logarithm of the given `base`.
+    ///
+    /// Two observations are the same value of the random variable if the rows are equal.
+    ///
+    /// If the array has no rows, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `base` is not a valid logarithm base (see [`LogBase`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::{DiscreteEntropyEstimator, EntropyEstimationExt, LogBase};
+    ///
+    /// let rolls = array![1, 4, 2, 6, 6, 3, 1, 5, 2, 4, 6, 1].insert_axis(Axis(1));
+    /// let plug_in = rolls
+    ///     .discrete_entropy(DiscreteEntropyEstimator::PlugIn, LogBase::Two)
+    ///     .unwrap();
+    /// let miller_madow = rolls
+    ///     .discrete_entropy(DiscreteEntropyEstimator::MillerMadow, LogBase::Two)
+    ///     .unwrap();
+    /// // 6 distinct values in 12 observations
+    /// assert_abs_diff_eq!(miller_madow - plug_in, 5. / 24. / 2f64.ln(), epsilon = 1e-12);
+    /// ```
+    ///
+    /// [`LogBase`]: enum.LogBase.html
+    fn discrete_entropy(
+        &self,
+        estimator: DiscreteEntropyEstimator,
+        base: LogBase<f64>,
+    ) -> Result<f64, EmptyInput>
+    where
+        A: Eq + Hash;
+
+    /// Estimates the [differential entropy] of a continuous distribution from the
+    /// observations (rows) of `self`, using the [Kozachenko–Leonenko] *k*-nearest
+    /// neighbour estimator and a logarithm of the given `base`:
+    ///
+    /// ```text
+    ///                                   d   n
+    /// H = ψ(n) - ψ(k) + log(V_d) +     ―   ∑ log(εᵢ)
+    ///                                   n  i=1
+    /// ```
+    ///
+    /// where *ψ* is the digamma function, *V_d* is the volume of the unit ball in *d*
+    /// dimensions and *εᵢ* is the Euclidean distance between the *i*-th observation
+    /// and its *k*-th nearest neighbour.
+    ///
+    /// The distances between all pairs of observations are computed and those from
+    /// each observation are sorted, hence the complexity is *O(n² (d + log n))*.
+    /// If an observation has less than *k* distinct neighbours (i.e. there are repeated
+    /// observations), *εᵢ* is zero and the estimate is *-∞*.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `k` is zero or if it is not less than the number of observations,
+    /// if `base` is not a valid logarithm base (see [`LogBase`]), or if a type cast
+    /// from `f64` to `A` fails.
+    ///
+    /// [differential entropy]: https://en.wikipedia.org/wiki/Differential_entropy
+    /// [Kozachenko–Leonenko]: https://doi.org/10.1103/PhysRevE.69.066138
+    /// [`LogBase`]: enum.LogBase.html
+    fn kozachenko_leonenko_entropy(&self, k: usize, base: LogBase<A>) -> Result<A, EmptyInput>
+    where
+        A: Float;
+
+    /// Estimates the [differential entropy] of a continuous distribution from the
+    /// observations (rows) of `self`, as the [`differential_entropy`] of their
+    /// histogram over `grid`, using a logarithm of the given `base`.
+    ///
+    /// Observations outside the grid are ignored: if there are none inside it,
+    /// the estimate is NaN.
+    ///
+    /// If the array has no rows, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if the number of columns of `self` is different from `grid.ndim()`,
+    /// if `base` is not a valid logarithm base (see [`LogBase`]), or if the type cast
+    /// from `A` to `f64` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::{array, Axis};
+    /// use ndarray_stats::histogram::{Bins, Edges, Grid};
+    /// use ndarray_stats::{EntropyEstimationExt, LogBase};
+    ///
+    /// let observations = array![1, 3, 5, 7].insert_axis(Axis(1));
+    /// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 4, 8]))]);
+    /// // Uniform on [0, 8)
+    /// assert_abs_diff_eq!(
+    ///     observations.histogram_entropy(grid, LogBase::Two).unwrap(),
+    ///     3.,
+    ///     epsilon = 1e-12
+    /// );
+    /// ```
+    ///
+    /// [differential entropy]: https://en.wikipedia.org/wiki/Differential_entropy
+    /// [`differential_entropy`]: histogram/struct.Histogram.html#method.differential_entropy
+    /// [`LogBase`]: enum.LogBase.html
+    fn histogram_entropy(&self, grid: Grid<A>, base: LogBase<f64>) -> Result<f64, EmptyInput>
+    where
+        A: Ord + Clone + ToPrimitive;
+
+    private_decl! {}
+}
+
+impl<A, S> EntropyEstimationExt<A, S> for ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    fn discrete_entropy(
+        &self,
+        estimator: DiscreteEntropyEstimator,
+        base: LogBase<f64>,
+    ) -> Result<f64, EmptyInput>
+    where
+        A: Eq + Hash,
+    {
+        if self.nrows() == 0 {
+            return Err(EmptyInput);
+        }
+        let mut counts = HashMap::new();
+        for observation in self.outer_iter() {
+            *counts.entry(observation).or_insert(0usize) += 1;
+        }
+        let n = self.nrows() as f64;
+        let plug_in = || {
+            -counts
+                .values()
+                .map(|&count| {
+                    let p = count as f64 / n;
+                    p * p.ln()
+                })
+                .sum::<f64>()
+        };
+        let entropy = match estimator {
+            DiscreteEntropyEstimator::PlugIn => plug_in(),
+            DiscreteEntropyEstimator::MillerMadow => {
+                plug_in() + (counts.len() - 1) as f64 / (2. * n)
+            }
+            DiscreteEntropyEstimator::ChaoShen => {
+                let n_singletons = counts.values().filter(|&&count| count == 1).count();
+                // The coverage would be zero if all values were observed once
+                let n_singletons = if n_singletons == self.nrows() {
+                    n_singletons - 1
+                } else {
+                    n_singletons
+                };
+                let coverage = 1. - n_singletons as f64 / n;
+                -counts
+                    .values()
+                    .map(|&count| {
+                        let p = coverage * count as f64 / n;
+                        let inclusion_probability = 1. - (1. - p).powf(n);
+                        p * p.ln() / inclusion_probability
+                    })
+                    .sum::<f64>()
+            }
+        };
+        Ok(base.from_nats(entropy))
+    }
+
+    fn kozachenko_leonenko_entropy(&self, k: usize, base: LogBase<A>) -> Result<A, EmptyInput>
+    where
+        A: Float,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        let (n, d) = self.dim();
+        assert!(
+            k > 0 && k < n,
+            "`k` must be positive and less than the number of observations."
+        );
+
+        let mut distances = Vec::with_capacity(n - 1);
+        let mut sum_ln_distances = A::zero();
+        for (i, x) in self.outer_iter().enumerate() {
+            distances.clear();
+            for (j, y) in self.outer_iter().enumerate() {
+                if i != j {
+                    let sq_distance = x
+                        .iter()
+                        .zip(&y)
+                        .fold(A::zero(), |acc, (&a, &b)| acc + (a - b) * (a - b));
+                    if sq_distance.is_nan() {
+                        return Ok(A::nan());
+                    }
+                    distances.push(sq_distance);
+                }
+            }
+            distances.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            let kth_sq_distance = distances[k - 1];
+            sum_ln_distances = sum_ln_distances + kth_sq_distance.ln() / A::from(2.).unwrap();
+        }
+
+        // ln V_d = (d/2) ln(π) - ln Γ(d/2 + 1)
+        let half_d = d as f64 / 2.;
+        let ln_unit_ball_volume = half_d * std::f64::consts::PI.ln() - ln_gamma(half_d + 1.);
+        let constant = digamma(n as f64) - digamma(k as f64) + ln_unit_ball_volume;
+        let entropy = A::from(constant).unwrap()
+            + A::from(d).unwrap() * sum_ln_distances / A::from(n).unwrap();
+        Ok(base.from_nats(entropy))
+    }
+
+    fn histogram_entropy(&self, grid: Grid<A>, base: LogBase<f64>) -> Result<f64, EmptyInput>
+    where
+        A: Ord + Clone + ToPrimitive,
+    {
+        if self.nrows() == 0 {
+            return Err(EmptyInput);
+        }
+        Ok(self.histogram(grid).differential_entropy(base))
+    }
+
+    private_impl! {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiscreteEntropyEstimator, EntropyEstimationExt};
+    use crate::entropy::{EntropyExt, LogBase};
+    use crate::errors::EmptyInput;
+    use crate::histogram::{Bins, Edges, Grid};
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array, Array2, Axis};
+    use ndarray_rand::RandomExt;
+    use rand::distributions::Uniform;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::f64;
+
+    #[test]
+    fn test_discrete_entropy() -> Result<(), EmptyInput> {
+        let sample = array![3, 1, 4, 1, 5, 9, 2, 6].insert_axis(Axis(1));
+        let frequencies = array![2., 1., 1., 1., 1., 1., 1.] / 8.;
+        let plug_in = sample.discrete_entropy(DiscreteEntropyEstimator::PlugIn, LogBase::E)?;
+        assert_abs_diff_eq!(plug_in, frequencies.entropy()?, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            sample.discrete_entropy(DiscreteEntropyEstimator::MillerMadow, LogBase::E)?,
+            plug_in + 6. / 16.,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            sample.discrete_entropy(DiscreteEntropyEstimator::PlugIn, LogBase::Two)?,
+            plug_in / 2f64.ln(),
+            epsilon = 1e-12
+        );
+
+        let sample = array![1, 1, 2].insert_axis(Axis(1));
+        assert_abs_diff_eq!(
+            sample.discrete_entropy(DiscreteEntropyEstimator::ChaoShen, LogBase::E)?,
+            1.0662472192320789,
+            epsilon = 1e-12
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_discrete_entropy_of_multivariate_observations() -> Result<(), EmptyInput> {
+        let sample = array![
+            [0, 1],
+            [1, 1],
+            [0, 1],
+            [1, 0],
+            [2, 2],
+            [0, 1],
+            [1, 1],
+            [0, 0]
+        ];
+        let frequencies = array![3., 2., 1., 1., 1.] / 8.;
+        assert_abs_diff_eq!(
+            sample.discrete_entropy(DiscreteEntropyEstimator::PlugIn, LogBase::E)?,
+            frequencies.entropy()?,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            sample.discrete_entropy(DiscreteEntropyEstimator::ChaoShen, LogBase::E)?,
+            2.024995058470513,
+            epsilon = 1e-12
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_discrete_entropy_edge_cases() -> Result<(), EmptyInput> {
+        let estimators = [
+            DiscreteEntropyEstimator::PlugIn,
+            DiscreteEntropyEstimator::MillerMadow,
+            DiscreteEntropyEstimator::ChaoShen,
+        ];
+        let constant = array![[7], [7], [7]];
+        let single = array![[7]];
+        for &estimator in &estimators {
+            assert_eq!(constant.discrete_entropy(estimator, LogBase::E)?, 0.);
+            assert_eq!(single.discrete_entropy(estimator, LogBase::E)?, 0.);
+        }
+        // All values observed once: the coverage is estimated as 1/n
+        let singletons = array![1, 2, 3, 4].insert_axis(Axis(1));
+        let entropy =
+            singletons.discrete_entropy(DiscreteEntropyEstimator::ChaoShen, LogBase::E)?;
+        let p = 1f64 / 16.;
+        assert_abs_diff_eq!(
+            entropy,
+            -4. * p * p.ln() / (1. - (1. - p).powi(4)),
+            epsilon = 1e-12
+        );
+        assert!(entropy > 4f64.ln());
+
+        let empty: Array2<i32> = Array2::zeros((0, 1));
+        for &estimator in &estimators {
+            assert_eq!(
+                empty.discrete_entropy(estimator, LogBase::E),
+                Err(EmptyInput)
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_kozachenko_leonenko_entropy() -> Result<(), EmptyInput> {
+        let mut rng = StdRng::seed_from_u64(0);
+        // Uniform distribution on [0, 1)²: the entropy is zero
+        let sample = Array::random_using((1000, 2), Uniform::new(0., 1.), &mut rng);
+        let entropy = sample.kozachenko_leonenko_entropy(3, LogBase::E)?;
+        assert_abs_diff_eq!(entropy, 0., epsilon = 0.1);
+
+        // Scaling the observations by c adds d ln(c) to the estimate
+        let scaled = &sample * 4.;
+        assert_abs_diff_eq!(
+            scaled.kozachenko_leonenko_entropy(3, LogBase::E)?,
+            entropy + 2. * 4f64.ln(),
+            epsilon = 1e-10
+        );
+        assert_abs_diff_eq!(
+            scaled.kozachenko_leonenko_entropy(3, LogBase::Two)?,
+            entropy / 2f64.ln() + 4.,
+            epsilon = 1e-10
+        );
+
+        // Uniform distribution on [0, 8): the entropy is 3 bits
+        let sample = Array::random_using((1000, 1), Uniform::new(0., 8.), &mut rng);
+        assert_abs_diff_eq!(
+            sample.kozachenko_leonenko_entropy(1, LogBase::Two)?,
+            3.,
+            epsilon = 0.15
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_kozachenko_leonenko_entropy_edge_cases() {
+        let with_nan = array![[0.], [f64::NAN], [1.], [3.]];
+        assert!(with_nan
+            .kozachenko_leonenko_entropy(1, LogBase::E)
+            .unwrap()
+            .is_nan());
+        let with_duplicates = array![[0.], [0.], [1.], [3.]];
+        assert_eq!(
+            with_duplicates.kozachenko_leonenko_entropy(1, LogBase::E),
+            Ok(f64::NEG_INFINITY)
+        );
+        assert!(with_duplicates
+            .kozachenko_leonenko_entropy(2, LogBase::E)
+            .unwrap()
+            .is_finite());
+        let empty: Array2<f64> = Array2::zeros((0, 2));
+        assert_eq!(
+            empty.kozachenko_leonenko_entropy(1, LogBase::E),
+            Err(EmptyInput)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_kozachenko_leonenko_entropy_with_zero_neighbours_panics() {
+        let _ = array![[0.], [1.]].kozachenko_leonenko_entropy(0, LogBase::E);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_kozachenko_leonenko_entropy_with_too_many_neighbours_panics() {
+        let _ = array![[0.], [1.]].kozachenko_leonenko_entropy(2, LogBase::E);
+    }
+
+    #[test]
+    fn test_histogram_entropy() -> Result<(), EmptyInput> {
+        let bins = Bins::new(Edges::from(vec![0, 1, 3]));
+        let grid = Grid::from(vec![bins.clone(), bins]);
+        // The observation outside the grid is ignored
+        let sample = array![[0, 0], [2, 2], [1, 0], [0, 2], [5, 0]];
+        let p = 0.25f64;
+        let expected = -p * (p / 1.).ln() - 2. * p * (p / 2.).ln() - p * (p / 4.).ln();
+        assert_abs_diff_eq!(
+            sample.histogram_entropy(grid.clone(), LogBase::E)?,
+            expected,
+            epsilon = 1e-12
+        );
+        assert!(array![[5, 5]]
+            .histogram_entropy(grid.clone(), LogBase::E)?
+            .is_nan());
+        let empty: Array2<i32> = Array2::zeros((0, 2));
+        assert_eq!(empty.histogram_entropy(grid, LogBase::E), Err(EmptyInput));
+        Ok(())
+    }
+}
@@ -141,3 +141,71 @@ impl From<EmptyInput> for QuantileError {
         QuantileError::EmptyInput
     }
 }
+
+/// An error computing a statistic that requires the factorisation
+/// of a covariance matrix (e.g. its inverse).
+#[derive(Clone, Debug, PartialEq)]
+pub enum CovarianceError {
+    /// The input was empty.
+    EmptyInput,
+    /// The covariance matrix is singular (i.e. not positive definite),
+    /// hence it cannot be inverted.
+    NotPositiveDefinite,
+    /// The shapes of the covariance matrix and of the other inputs are not compatible.
+    ShapeMismatch(ShapeMismatch),
+}
+
+impl CovarianceError {
+    /// Returns whether `self` is the `EmptyInput` variant.
+    pub fn is_empty_input(&self) -> bool {
+        match self {
+            CovarianceError::EmptyInput => true,
+            _ => false,
+        }
+    }
+
+    /// Returns whether `self` is the `NotPositiveDefinite` variant.
+    pub fn is_not_positive_definite(&self) -> bool {
+        match self {
+            CovarianceError::NotPositiveDefinite => true,
+            _ => false,
+        }
+    }
+
+    /// Returns whether `self` is the `ShapeMismatch` variant.
+    pub fn is_shape_mismatch(&self) -> bool {
+        match self {
+            CovarianceError::ShapeMismatch(_) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for CovarianceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CovarianceError::EmptyInput => write!(f, "Empty input."),
+            CovarianceError::NotPositiveDefinite => {
+                write!(
+                    f,
+                    "The covariance matrix is singular (not positive definite)."
+                )
+            }
+            CovarianceError::ShapeMismatch(e) => write!(f, "Shape mismatch: {}", e),
+        }
+    }
+}
+
+impl Error for CovarianceError {}
+
+impl From<EmptyInput> for CovarianceError {
+    fn from(_: EmptyInput) -> CovarianceError {
+        CovarianceError::EmptyInput
+    }
+}
+
+impl From<ShapeMismatch> for CovarianceError {
+    fn from(err: ShapeMismatch) -> CovarianceError {
+        CovarianceError::ShapeMismatch(err)
+    }
+}
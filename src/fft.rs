@@ -0,0 +1,245 @@
+//! A minimal radix-2 [fast Fourier transform], used to compute lagged sums of
+//! products (e.g. autocovariances) in *O(n log n)* time.
+//!
+//! [fast Fourier transform]: https://en.wikipedia.org/wiki/Cooley%E2%80%93Tukey_FFT_algorithm
+use num_traits::{Float, FromPrimitive};
+
+/// In-place iterative Cooley–Tukey transform of the complex sequence stored in
+/// `re` (real parts) and `im` (imaginary parts).
+///
+/// If `inverse` is `true`, the inverse transform is computed, including the
+/// `1/n` normalisation.
+///
+/// **Panics** if `re` and `im` don't have the same length or if their length
+/// is not a power of two.
+pub(crate) fn fft_in_place<A>(re: &mut [A], im: &mut [A], inverse: bool)
+where
+    A: Float + FromPrimitive,
+{
+    let n = re.len();
+    assert_eq!(n, im.len());
+    assert!(
+        n.is_power_of_two(),
+        "The FFT length must be a power of two."
+    );
+
+    // Bit-reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { 1. } else { -1. };
+    let mut len = 2;
+    while len <= n {
+        let angle = sign * 2. * std::f64::consts::PI / (len as f64);
+        let (w_re, w_im) = (
+            A::from_f64(angle.cos()).unwrap(),
+            A::from_f64(angle.sin()).unwrap(),
+        );
+        for start in (0..n).step_by(len) {
+            let (mut t_re, mut t_im) = (A::one(), A::zero());
+            for k in 0..len / 2 {
+                let (a, b) = (start + k, start + k + len / 2);
+                let u_re = re[a];
+                let u_im = im[a];
+                let v_re = re[b] * t_re - im[b] * t_im;
+                let v_im = re[b] * t_im + im[b] * t_re;
+                re[a] = u_re + v_re;
+                im[a] = u_im + v_im;
+                re[b] = u_re - v_re;
+                im[b] = u_im - v_im;
+                let next_re = t_re * w_re - t_im * w_im;
+                t_im = t_re * w_im + t_im * w_re;
+                t_re = next_re;
+            }
+        }
+        len <<= 1;
+    }
+
+    if inverse {
+        let scale = A::from_usize(n).unwrap();
+        for (x, y) in re.iter_mut().zip(im.iter_mut()) {
+            *x = *x / scale;
+            *y = *y / scale;
+        }
+    }
+}
+
+/// Returns the lagged sums of products of two real sequences for all lags
+/// from `min_lag` to `max_lag` (extremes included):
+///
+/// ```text
+/// c_k = ∑ xₜ₊ₖ yₜ
+///       ₜ
+/// ```
+///
+/// where the sum runs over all `t` such that both `t` and `t + k` are valid indices.
+///
+/// The sums are computed directly when few lags are requested and using the
+/// fast Fourier transform otherwise.
+///
+/// **Panics** if `x` or `y` is empty or if `min_lag..=max_lag` is not contained in
+/// `-(y.len() - 1)..=(x.len() - 1)`.
+pub(crate) fn lagged_products<A>(x: &[A], y: &[A], min_lag: isize, max_lag: isize) -> Vec<A>
+where
+    A: Float + FromPrimitive,
+{
+    assert!(!x.is_empty() && !y.is_empty());
+    assert!(min_lag <= max_lag);
+    assert!(min_lag > -(y.len() as isize) && max_lag < x.len() as isize);
+    let n_lags = (max_lag - min_lag) as usize + 1;
+    let n_products = x.len().min(y.len()).saturating_mul(n_lags);
+    if prefer_fft(n_products, x.len() + y.len() - 1) {
+        fft_lagged_products(x, y, min_lag, max_lag)
+    } else {
+        direct_lagged_products(x, y, min_lag, max_lag)
+    }
+}
+
+/// Returns `true` if computing `n_products` products directly is expected
+/// to be slower than a linear correlation of length `len` using the FFT.
+fn prefer_fft(n_products: usize, len: usize) -> bool {
+    let size = len.next_power_of_two();
+    let log2_size = size.trailing_zeros() as usize;
+    // Three transforms of `size / 2 · log2(size)` butterflies each,
+    // a butterfly costing roughly three multiply-adds
+    n_products > 4 * size * log2_size.max(1)
+}
+
+fn direct_lagged_products<A>(x: &[A], y: &[A], min_lag: isize, max_lag: isize) -> Vec<A>
+where
+    A: Float,
+{
+    (min_lag..=max_lag)
+        .map(|k| {
+            let (x, y) = if k >= 0 {
+                (&x[k as usize..], y)
+            } else {
+                (x, &y[(-k) as usize..])
+            };
+            x.iter().zip(y).fold(A::zero(), |acc, (&a, &b)| acc + a * b)
+        })
+        .collect()
+}
+
+/// Computes the lagged sums of products as a circular correlation, zero-padding
+/// both sequences so that the circular and linear correlations coincide.
+fn fft_lagged_products<A>(x: &[A], y: &[A], min_lag: isize, max_lag: isize) -> Vec<A>
+where
+    A: Float + FromPrimitive,
+{
+    let size = (x.len() + y.len() - 1).next_power_of_two();
+
+    let mut x_re = vec![A::zero(); size];
+    let mut x_im = vec![A::zero(); size];
+    x_re[..x.len()].copy_from_slice(x);
+    fft_in_place(&mut x_re, &mut x_im, false);
+
+    let mut y_re = vec![A::zero(); size];
+    let mut y_im = vec![A::zero(); size];
+    y_re[..y.len()].copy_from_slice(y);
+    fft_in_place(&mut y_re, &mut y_im, false);
+
+    // X · conj(Y)
+    for i in 0..size {
+        let re = x_re[i] * y_re[i] + x_im[i] * y_im[i];
+        let im = x_im[i] * y_re[i] - x_re[i] * y_im[i];
+        x_re[i] = re;
+        x_im[i] = im;
+    }
+    fft_in_place(&mut x_re, &mut x_im, true);
+    // Negative lags wrap around to the end of the buffer
+    (min_lag..=max_lag)
+        .map(|k| x_re[(k + size as isize) as usize % size])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use quickcheck_macros::quickcheck;
+
+    fn naive_lagged_products(x: &[f64], y: &[f64], min_lag: isize, max_lag: isize) -> Vec<f64> {
+        (min_lag..=max_lag)
+            .map(|k| {
+                (0..y.len() as isize)
+                    .filter(|&t| t + k >= 0 && t + k < x.len() as isize)
+                    .map(|t| x[(t + k) as usize] * y[t as usize])
+                    .sum()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_fft_round_trip() {
+        let mut re = vec![1., -2., 3., 0.5, 0., 7., -1., 2.];
+        let mut im = vec![0.; 8];
+        let original = re.clone();
+        fft_in_place(&mut re, &mut im, false);
+        fft_in_place(&mut re, &mut im, true);
+        for (x, y) in re.iter().zip(&original) {
+            assert_abs_diff_eq!(*x, *y, epsilon = 1e-12);
+        }
+        for x in im {
+            assert_abs_diff_eq!(x, 0., epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_fft_of_impulse_is_constant() {
+        let mut re = vec![1., 0., 0., 0.];
+        let mut im = vec![0.; 4];
+        fft_in_place(&mut re, &mut im, false);
+        assert_eq!(re, vec![1., 1., 1., 1.]);
+        assert_eq!(im, vec![0., 0., 0., 0.]);
+    }
+
+    #[quickcheck]
+    fn lagged_products_match_naive_computation(x: Vec<i8>, y: Vec<i8>) -> bool {
+        if x.is_empty() || y.is_empty() {
+            return true;
+        }
+        let x: Vec<f64> = x.into_iter().map(f64::from).collect();
+        let y: Vec<f64> = y.into_iter().map(|v| f64::from(v) * 0.5).collect();
+        let (min_lag, max_lag) = (1 - y.len() as isize, x.len() as isize - 1);
+        let naive = naive_lagged_products(&x, &y, min_lag, max_lag);
+        let close = |a: &[f64]| {
+            a.iter()
+                .zip(&naive)
+                .all(|(a, b)| (a - b).abs() < 1e-6 * (1. + b.abs()))
+        };
+        close(&fft_lagged_products(&x, &y, min_lag, max_lag))
+            && close(&direct_lagged_products(&x, &y, min_lag, max_lag))
+    }
+
+    #[test]
+    fn test_lagged_products_subset_of_lags() {
+        let x = [1., 2., 3.];
+        let y = [0., 1., 0.5];
+        assert_eq!(
+            fft_lagged_products(&x, &y, -2, 2)
+                .into_iter()
+                .map(|v: f64| (v * 1e12).round() / 1e12)
+                .collect::<Vec<_>>(),
+            vec![0.5, 2., 3.5, 3., 0.]
+        );
+        assert_eq!(direct_lagged_products(&x, &y, -1, 1), vec![2., 3.5, 3.]);
+    }
+
+    #[test]
+    fn test_prefer_fft() {
+        assert!(prefer_fft(300 * 300, 599));
+        assert!(!prefer_fft(300 * 3, 599));
+    }
+}
@@ -1,7 +1,12 @@
 use super::errors::BinNotFound;
 use super::grid::Grid;
+use crate::distribution_distance::wasserstein_on_points;
+use crate::entropy::LogBase;
+use crate::errors::EmptyInput;
 use ndarray::prelude::*;
 use ndarray::Data;
+use num_traits::ToPrimitive;
+use std::f64;
 
 /// Histogram data structure.
 pub struct Histogram<A: Ord> {
@@ -73,6 +78,127 @@ impl<A: Ord> Histogram<A> {
     pub fn grid(&self) -> &Grid<A> {
         &self.grid
     }
+
+    /// Computes the [Wasserstein-p distance] between the distributions described by two
+    /// one-dimensional histograms over the same bins, placing the observations of each bin
+    /// at its midpoint.
+    ///
+    /// If either histogram has no observations, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if the histograms are not one-dimensional or don't have the same grid,
+    /// if `p` is less than 1 or not finite, or if the type cast from `A` to `f64` fails.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::histogram::{Edges, Bins, Histogram, Grid};
+    ///
+    /// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 2, 4, 6]))]);
+    /// let mut before = Histogram::new(grid.clone());
+    /// let mut after = Histogram::new(grid);
+    /// before.add_observation(&array![1])?;
+    /// after.add_observation(&array![5])?;
+    ///
+    /// assert_eq!(before.wasserstein_distance(&after, 1.)?, 4.);
+    /// # Ok::<(), Box<std::error::Error>>(())
+    /// ```
+    ///
+    /// [Wasserstein-p distance]: https://en.wikipedia.org/wiki/Wasserstein_metric
+    pub fn wasserstein_distance(&self, other: &Histogram<A>, p: f64) -> Result<f64, EmptyInput>
+    where
+        A: Clone + ToPrimitive,
+    {
+        assert_eq!(self.ndim(), 1, "The histograms must be one-dimensional.");
+        assert!(
+            self.grid == other.grid,
+            "The histograms must have the same grid."
+        );
+        let bins = &self.grid.projections()[0];
+        let midpoints: Vec<f64> = (0..bins.len())
+            .map(|i| {
+                let bin = bins.index(i);
+                (bin.start.to_f64().unwrap() + bin.end.to_f64().unwrap()) / 2.
+            })
+            .collect();
+        let counts = |histogram: &Histogram<A>| -> Vec<f64> {
+            histogram.counts.iter().map(|&c| c as f64).collect()
+        };
+        if self.counts.sum() == 0 || other.counts.sum() == 0 {
+            return Err(EmptyInput);
+        }
+        Ok(wasserstein_on_points(
+            &midpoints,
+            &counts(self),
+            &counts(other),
+            p,
+        ))
+    }
+
+    /// Computes the [differential entropy] of the distribution described by the histogram,
+    /// using a logarithm of the given `base`, assuming that the observations are uniformly
+    /// distributed within each bin:
+    ///
+    /// ```text
+    ///        ⎲  nᵢ      nᵢ
+    /// H = -  ⎳  ―― log(――――)
+    ///        i  N      N Vᵢ
+    /// ```
+    ///
+    /// where *nᵢ* and *Vᵢ* are the count and the volume of the *i*-th bin and *N* is the
+    /// number of observations. Empty bins are ignored.
+    ///
+    /// It is NaN if the histogram has no observations.
+    ///
+    /// **Panics** if `base` is not a valid logarithm base (see [`LogBase`]) or if the
+    /// type cast from `A` to `f64` fails.
+    ///
+    /// # Example:
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::histogram::{Edges, Bins, Histogram, Grid};
+    /// use ndarray_stats::LogBase;
+    ///
+    /// let grid = Grid::from(vec![Bins::new(Edges::from(vec![0, 1, 4]))]);
+    /// let mut histogram = Histogram::new(grid);
+    /// histogram.add_observation(&array![0])?;
+    /// histogram.add_observation(&array![2])?;
+    ///
+    /// // Half of the mass is uniform on [0, 1), half of it on [1, 4)
+    /// assert_abs_diff_eq!(
+    ///     histogram.differential_entropy(LogBase::Two),
+    ///     1. + 0.5 * 3f64.log2(),
+    ///     epsilon = 1e-12
+    /// );
+    /// # Ok::<(), Box<std::error::Error>>(())
+    /// ```
+    ///
+    /// [differential entropy]: https://en.wikipedia.org/wiki/Differential_entropy
+    /// [`LogBase`]: ../enum.LogBase.html
+    pub fn differential_entropy(&self, base: LogBase<f64>) -> f64
+    where
+        A: Clone + ToPrimitive,
+    {
+        let n_observations = self.counts.sum();
+        if n_observations == 0 {
+            return f64::NAN;
+        }
+        let n_observations = n_observations as f64;
+        let mut entropy = 0.;
+        for (index, &count) in self.counts.indexed_iter() {
+            if count > 0 {
+                let volume: f64 = self
+                    .grid
+                    .index(index.slice())
+                    .iter()
+                    .map(|bin| bin.end.to_f64().unwrap() - bin.start.to_f64().unwrap())
+                    .product();
+                let p = count as f64 / n_observations;
+                entropy -= p * (p / volume).ln();
+            }
+        }
+        base.from_nats(entropy)
+    }
 }
 
 /// Extension trait for `ArrayBase` providing methods to compute histograms.
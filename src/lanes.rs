@@ -0,0 +1,37 @@
+//! Helpers to apply a statistic of two 1-dimensional arrays along an axis of
+//! two n-dimensional arrays (e.g. `*_axis` variants of deviations and divergences).
+use crate::errors::MultiInputError;
+use ndarray::{Array, ArrayBase, ArrayView1, Axis, Data, RemoveAxis};
+
+/// Applies `f` to each pair of corresponding lanes of `a` and `b` along `axis`.
+///
+/// The following **errors** may be returned:
+///
+/// * `MultiInputError::EmptyInput` if `a` is empty
+/// * `MultiInputError::ShapeMismatch` if `a` and `b` don't have the same shape
+/// * any error returned by `f`
+pub(crate) fn map_lanes<A, S, T, D, B, F>(
+    a: &ArrayBase<S, D>,
+    b: &ArrayBase<T, D>,
+    axis: Axis,
+    f: F,
+) -> Result<Array<B, D::Smaller>, MultiInputError>
+where
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+    D: RemoveAxis,
+    F: Fn(ArrayView1<A>, ArrayView1<A>) -> Result<B, MultiInputError>,
+{
+    return_err_if_empty!(a);
+    return_err_unless_same_shape!(a, b);
+
+    let values = a
+        .lanes(axis)
+        .into_iter()
+        .zip(b.lanes(axis))
+        .map(|(a, b)| f(a, b))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Array::from_shape_vec(a.raw_dim().remove_axis(axis), values)
+        .expect("there is one lane for each index of the remaining axes"))
+}
@@ -5,9 +5,21 @@
 //! - [order statistics] (minimum, maximum, median, quantiles, etc.);
 //! - [summary statistics] (mean, skewness, kurtosis, central moments, etc.)
 //! - [partitioning];
-//! - [correlation analysis] (covariance, pearson correlation);
-//! - [measures from information theory] (entropy, KL divergence, etc.);
-//! - [measures of deviation] (count equal, L1, L2 distances, mean squared err etc.)
+//! - [correlation analysis] (covariance, pearson and rank correlation, partial correlation,
+//!   significance tests, [cross-correlation], [distance correlation]);
+//! - [time series analysis] (autocovariance, autocorrelation, partial autocorrelation, portmanteau tests);
+//! - [measures from information theory] (Shannon, Rényi and Tsallis entropies, KL, Jensen-Shannon,
+//!   Rényi and chi-square divergences, Hellinger, Bhattacharyya and total variation distances,
+//!   etc.), [mutual information] (joint and conditional entropies, normalized mutual information,
+//!   variation of information) and [entropy estimation] from samples;
+//! - [distances between distributions] (Wasserstein and energy distances);
+//! - [measures of deviation] (count equal, count equal within a tolerance, L1, L2 distances,
+//!   mean squared err, concordance correlation, Bland–Altman agreement etc.)
+//!   [pairwise distance matrices], [Mahalanobis distances] and [dynamic time warping];
+//! - [regression metrics] (R², explained variance, percentage errors, deviances etc.);
+//! - [classification metrics] (confusion matrix, accuracy, precision, recall, F-score,
+//!   ROC and precision–recall curves etc.);
+//! - [image similarity] (structural similarity index, multi-scale SSIM);
 //! - [histogram computation].
 //!
 //! Please feel free to contribute new functionality! A roadmap can be found [here].
@@ -22,20 +34,50 @@
 //! [partitioning]: trait.Sort1dExt.html
 //! [summary statistics]: trait.SummaryStatisticsExt.html
 //! [correlation analysis]: trait.CorrelationExt.html
+//! [cross-correlation]: trait.CrossCorrelationExt.html
+//! [distance correlation]: trait.DistanceCorrelationExt.html
+//! [time series analysis]: trait.AutocorrelationExt.html
 //! [measures of deviation]: trait.DeviationExt.html
+//! [pairwise distance matrices]: trait.PairwiseDistanceExt.html
+//! [Mahalanobis distances]: trait.MahalanobisExt.html
+//! [dynamic time warping]: trait.DynamicTimeWarpingExt.html
+//! [regression metrics]: trait.RegressionMetricsExt.html
+//! [classification metrics]: trait.ClassificationExt.html
+//! [image similarity]: trait.StructuralSimilarityExt.html
 //! [measures from information theory]: trait.EntropyExt.html
+//! [mutual information]: trait.MutualInformationExt.html
+//! [entropy estimation]: trait.EntropyEstimationExt.html
+//! [distances between distributions]: trait.DistributionDistanceExt.html
 //! [histogram computation]: histogram/index.html
 //! [here]: https://github.com/rust-ndarray/ndarray-stats/issues/1
 //! [`NumPy`]: https://docs.scipy.org/doc/numpy-1.14.1/reference/routines.statistics.html
 //! [`StatsBase.jl`]: https://juliastats.github.io/StatsBase.jl/latest/
 
-pub use crate::correlation::CorrelationExt;
-pub use crate::deviation::DeviationExt;
-pub use crate::entropy::EntropyExt;
+pub use crate::autocorrelation::{
+    AutocorrelationExt, AutocovarianceNormalization, PortmanteauTest,
+};
+pub use crate::classification::{
+    Average, ClassificationExt, ConfusionMatrix, PrecisionRecallCurve, RocCurve,
+};
+pub use crate::correlation::{
+    CorrelationExt, CorrelationTest, CrossCorrelation, CrossCorrelationExt, CrossCorrelationMode,
+    PairwiseComplete,
+};
+pub use crate::deviation::{BlandAltman, DeviationExt, Mismatch, MismatchReport, Tolerance};
+pub use crate::distance_correlation::{DistanceCorrelationExt, DistanceCorrelationTest};
+pub use crate::distribution_distance::DistributionDistanceExt;
+pub use crate::dtw::{Dtw, DynamicTimeWarpingExt};
+pub use crate::entropy::{EntropyExt, LogBase};
+pub use crate::entropy_estimation::{DiscreteEntropyEstimator, EntropyEstimationExt};
 pub use crate::histogram::HistogramExt;
+pub use crate::mahalanobis::MahalanobisExt;
 pub use crate::maybe_nan::{MaybeNan, MaybeNanExt};
+pub use crate::mutual_information::{MutualInformationExt, NmiNormalization};
+pub use crate::pairwise::{DistanceMetric, PairwiseDistanceExt};
 pub use crate::quantile::{interpolate, Quantile1dExt, QuantileExt};
+pub use crate::regression::RegressionMetricsExt;
 pub use crate::sort::Sort1dExt;
+pub use crate::ssim::{Ssim, SsimParams, SsimWindow, StructuralSimilarityExt};
 pub use crate::summary_statistics::SummaryStatisticsExt;
 
 #[cfg(test)]
@@ -52,7 +94,7 @@ mod multi_input_error_macros {
         };
     }
     macro_rules! return_err_unless_same_shape {
-        ($arr_a:expr, $arr_b:expr) => {
+        ($arr_a:expr, $arr_b:expr) => {{
             use crate::errors::{MultiInputError, ShapeMismatch};
             if $arr_a.shape() != $arr_b.shape() {
                 return Err(MultiInputError::ShapeMismatch(ShapeMismatch {
@@ -61,7 +103,7 @@ mod multi_input_error_macros {
                 })
                 .into());
             }
-        };
+        }};
     }
 }
 
@@ -98,12 +140,27 @@ mod private {
     }
 }
 
+mod autocorrelation;
+mod classification;
 mod correlation;
 mod deviation;
+mod distance_correlation;
+mod distribution_distance;
+mod dtw;
 mod entropy;
+mod entropy_estimation;
 pub mod errors;
+mod fft;
 pub mod histogram;
+mod lanes;
+mod linalg;
+mod mahalanobis;
 mod maybe_nan;
+mod mutual_information;
+mod pairwise;
 mod quantile;
+mod regression;
 mod sort;
+mod special;
+mod ssim;
 mod summary_statistics;
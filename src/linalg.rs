@@ -0,0 +1,171 @@
+//! Small, self-contained linear algebra routines (e.g. Cholesky factorisation)
+//! used by statistics that need to invert a covariance matrix, without relying
+//! on a LAPACK backend.
+use ndarray::{Array1, Array2, ArrayBase, Data, Ix1, Ix2};
+use num_traits::{Float, FromPrimitive};
+
+/// The [Cholesky factorisation] `M = L Lᵀ` of a symmetric positive-definite matrix `M`,
+/// where `L` is lower triangular with a strictly positive diagonal.
+///
+/// [Cholesky factorisation]: https://en.wikipedia.org/wiki/Cholesky_decomposition
+#[derive(Clone, Debug)]
+pub(crate) struct Cholesky<A> {
+    lower: Array2<A>,
+}
+
+impl<A> Cholesky<A>
+where
+    A: Float + FromPrimitive,
+{
+    /// Computes the factorisation of the square matrix `m`, reading only its
+    /// lower triangle.
+    ///
+    /// Returns `None` if `m` is not (numerically) positive definite, i.e. if one of
+    /// the pivots is not larger than `n · ε · max(mᵢᵢ)` or is not finite.
+    ///
+    /// **Panics** if `m` is not square.
+    pub(crate) fn new<S>(m: &ArrayBase<S, Ix2>) -> Option<Self>
+    where
+        S: Data<Elem = A>,
+    {
+        let n = m.nrows();
+        assert_eq!(
+            n,
+            m.ncols(),
+            "Cholesky factorisation requires a square matrix."
+        );
+        let max_diag = m.diag().fold(A::zero(), |acc, &x| acc.max(x));
+        let tolerance = A::epsilon() * A::from_usize(n).unwrap() * max_diag;
+
+        let mut lower = Array2::zeros((n, n));
+        for j in 0..n {
+            let mut pivot = m[(j, j)];
+            for k in 0..j {
+                pivot = pivot - lower[(j, k)] * lower[(j, k)];
+            }
+            if !pivot.is_finite() || pivot <= tolerance {
+                return None;
+            }
+            let pivot = pivot.sqrt();
+            lower[(j, j)] = pivot;
+            for i in (j + 1)..n {
+                let mut value = m[(i, j)];
+                for k in 0..j {
+                    value = value - lower[(i, k)] * lower[(j, k)];
+                }
+                lower[(i, j)] = value / pivot;
+            }
+        }
+        Some(Cholesky { lower })
+    }
+
+    /// Solves `L y = b` by forward substitution.
+    ///
+    /// **Panics** if the length of `b` does not match the dimension of `L`.
+    pub(crate) fn solve_lower<S>(&self, b: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        let n = self.lower.nrows();
+        assert_eq!(n, b.len());
+        let mut y = b.to_owned();
+        for i in 0..n {
+            let mut value = y[i];
+            for k in 0..i {
+                value = value - self.lower[(i, k)] * y[k];
+            }
+            y[i] = value / self.lower[(i, i)];
+        }
+        y
+    }
+
+    /// Solves `Lᵀ x = y` by backward substitution.
+    ///
+    /// **Panics** if the length of `y` does not match the dimension of `L`.
+    pub(crate) fn solve_upper<S>(&self, y: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        let n = self.lower.nrows();
+        assert_eq!(n, y.len());
+        let mut x = y.to_owned();
+        for i in (0..n).rev() {
+            let mut value = x[i];
+            for k in (i + 1)..n {
+                value = value - self.lower[(k, i)] * x[k];
+            }
+            x[i] = value / self.lower[(i, i)];
+        }
+        x
+    }
+
+    /// Solves `M x = b`, where `M = L Lᵀ` is the factorised matrix.
+    ///
+    /// **Panics** if the length of `b` does not match the dimension of `M`.
+    pub(crate) fn solve<S>(&self, b: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+    {
+        self.solve_upper(&self.solve_lower(b))
+    }
+
+    /// Returns the inverse `M⁻¹` of the factorised matrix.
+    pub(crate) fn inverse(&self) -> Array2<A> {
+        let n = self.lower.nrows();
+        let mut inverse = Array2::zeros((n, n));
+        let mut unit = Array1::zeros(n);
+        for j in 0..n {
+            unit[j] = A::one();
+            inverse.column_mut(j).assign(&self.solve(&unit));
+            unit[j] = A::zero();
+        }
+        inverse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cholesky;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array2};
+
+    #[test]
+    fn test_cholesky_factor() {
+        let m = array![[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]];
+        let cholesky = Cholesky::new(&m).unwrap();
+        let expected = array![[2., 0., 0.], [6., 1., 0.], [-8., 5., 3.]];
+        assert_abs_diff_eq!(cholesky.lower, expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_cholesky_inverse() {
+        let m = array![[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]];
+        let inverse = Cholesky::new(&m).unwrap().inverse();
+        assert_abs_diff_eq!(m.dot(&inverse), Array2::eye(3), epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_cholesky_solve() {
+        let m = array![[2., 1.], [1., 3.]];
+        let x = Cholesky::new(&m).unwrap().solve(&array![3., 5.]);
+        assert_abs_diff_eq!(x, array![0.8, 1.4], epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_cholesky_singular() {
+        let m = array![[1., 2.], [2., 4.]];
+        assert!(Cholesky::new(&m).is_none());
+    }
+
+    #[test]
+    fn test_cholesky_indefinite() {
+        let m = array![[1., 2.], [2., 1.]];
+        assert!(Cholesky::new(&m).is_none());
+    }
+
+    #[test]
+    fn test_cholesky_empty() {
+        let m = Array2::<f64>::zeros((0, 0));
+        assert_eq!(Cholesky::new(&m).unwrap().inverse().shape(), &[0, 0]);
+    }
+}
@@ -0,0 +1,330 @@
+use crate::errors::{CovarianceError, ShapeMismatch};
+use crate::linalg::Cholesky;
+use ndarray::prelude::*;
+use ndarray::Data;
+use num_traits::{Float, FromPrimitive};
+
+/// Extension trait for 2-dimensional arrays of observations providing functions
+/// to compute [Mahalanobis distances].
+///
+/// As for [`CorrelationExt`], each row of the array contains all the observations
+/// of a certain random variable, i.e. each column is an observation.
+///
+/// The covariance matrix is factorised with a Cholesky decomposition, hence it
+/// must be symmetric and positive definite: only its lower triangle is read.
+///
+/// [Mahalanobis distances]: https://en.wikipedia.org/wiki/Mahalanobis_distance
+/// [`CorrelationExt`]: trait.CorrelationExt.html
+pub trait MahalanobisExt<A, S>
+where
+    S: Data<Elem = A>,
+{
+    /// Returns the squared Mahalanobis distance of each observation `x` (column of `self`)
+    /// to a distribution with mean `mean` and covariance matrix `cov`:
+    ///
+    /// ```text
+    /// D² = (x - μ)ᵀ Σ⁻¹ (x - μ)
+    /// ```
+    ///
+    /// where `mean` is `μ` and `cov` is `Σ`.
+    ///
+    /// If the observations are the sample used to estimate `mean` and `cov`, the squared
+    /// distances are a common criterion to flag outliers: for normally distributed data
+    /// they approximately follow a χ² distribution with as many degrees of freedom as
+    /// random variables.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `CovarianceError::EmptyInput` if `self` is empty
+    /// * `CovarianceError::ShapeMismatch` if `cov` is not a square matrix with as many
+    ///   rows as `self`, or if the length of `mean` is not the number of rows of `self`
+    /// * `CovarianceError::NotPositiveDefinite` if `cov` is singular
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::{arr2, Axis};
+    /// use ndarray_stats::{CorrelationExt, MahalanobisExt};
+    ///
+    /// let a = arr2(&[[2., 4., 6., 8., 5.],
+    ///                [1., 3., 2., 6., 3.]]);
+    /// let mean = a.mean_axis(Axis(1)).unwrap();
+    /// let cov = a.cov(1.).unwrap();
+    /// let distances = a.sq_mahalanobis_distances(&mean, &cov).unwrap();
+    /// // The last observation is the mean itself
+    /// assert_abs_diff_eq!(distances[4], 0.);
+    /// // The third observation goes against the trend of the others
+    /// assert_abs_diff_eq!(distances[2], 62. / 21., epsilon = 1e-12);
+    /// ```
+    fn sq_mahalanobis_distances<T, U>(
+        &self,
+        mean: &ArrayBase<U, Ix1>,
+        cov: &ArrayBase<T, Ix2>,
+    ) -> Result<Array1<A>, CovarianceError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+        U: Data<Elem = A>;
+
+    /// Returns the Mahalanobis distance of each observation (column of `self`)
+    /// to a distribution with mean `mean` and covariance matrix `cov`,
+    /// i.e. the square root of [`sq_mahalanobis_distances`].
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `CovarianceError::EmptyInput` if `self` is empty
+    /// * `CovarianceError::ShapeMismatch` if `cov` is not a square matrix with as many
+    ///   rows as `self`, or if the length of `mean` is not the number of rows of `self`
+    /// * `CovarianceError::NotPositiveDefinite` if `cov` is singular
+    ///
+    /// [`sq_mahalanobis_distances`]: #tymethod.sq_mahalanobis_distances
+    fn mahalanobis_distances<T, U>(
+        &self,
+        mean: &ArrayBase<U, Ix1>,
+        cov: &ArrayBase<T, Ix2>,
+    ) -> Result<Array1<A>, CovarianceError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+        U: Data<Elem = A>;
+
+    /// Returns the matrix of the Mahalanobis distances between every pair of
+    /// observations (columns of `self`) with respect to the covariance matrix `cov`:
+    ///
+    /// ```text
+    /// dᵢⱼ = √((xᵢ - xⱼ)ᵀ Σ⁻¹ (xᵢ - xⱼ))
+    /// ```
+    ///
+    /// where `cov` is `Σ`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `CovarianceError::EmptyInput` if `self` is empty
+    /// * `CovarianceError::ShapeMismatch` if `cov` is not a square matrix with as many
+    ///   rows as `self`
+    /// * `CovarianceError::NotPositiveDefinite` if `cov` is singular
+    fn pairwise_mahalanobis_distances<T>(
+        &self,
+        cov: &ArrayBase<T, Ix2>,
+    ) -> Result<Array2<A>, CovarianceError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>;
+
+    private_decl! {}
+}
+
+impl<A, S> MahalanobisExt<A, S> for ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    fn sq_mahalanobis_distances<T, U>(
+        &self,
+        mean: &ArrayBase<U, Ix1>,
+        cov: &ArrayBase<T, Ix2>,
+    ) -> Result<Array1<A>, CovarianceError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+        U: Data<Elem = A>,
+    {
+        let cholesky = factorise(self, cov)?;
+        if mean.len() != self.nrows() {
+            return Err(ShapeMismatch {
+                first_shape: self.shape().to_vec(),
+                second_shape: mean.shape().to_vec(),
+            }
+            .into());
+        }
+        Ok(self
+            .gencolumns()
+            .into_iter()
+            .map(|x| {
+                cholesky
+                    .solve_lower(&(&x - mean))
+                    .fold(A::zero(), |acc, &z| acc + z * z)
+            })
+            .collect())
+    }
+
+    fn mahalanobis_distances<T, U>(
+        &self,
+        mean: &ArrayBase<U, Ix1>,
+        cov: &ArrayBase<T, Ix2>,
+    ) -> Result<Array1<A>, CovarianceError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+        U: Data<Elem = A>,
+    {
+        Ok(self.sq_mahalanobis_distances(mean, cov)?.mapv(A::sqrt))
+    }
+
+    fn pairwise_mahalanobis_distances<T>(
+        &self,
+        cov: &ArrayBase<T, Ix2>,
+    ) -> Result<Array2<A>, CovarianceError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+    {
+        let cholesky = factorise(self, cov)?;
+        // With Σ = L Lᵀ, the Mahalanobis distance is the Euclidean distance
+        // between the whitened observations L⁻¹x
+        let whitened: Vec<Array1<A>> = self
+            .gencolumns()
+            .into_iter()
+            .map(|x| cholesky.solve_lower(&x))
+            .collect();
+        let n = whitened.len();
+        let mut distances = Array2::zeros((n, n));
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let d = whitened[i]
+                    .iter()
+                    .zip(&whitened[j])
+                    .fold(A::zero(), |acc, (&a, &b)| acc + (a - b) * (a - b))
+                    .sqrt();
+                distances[(i, j)] = d;
+                distances[(j, i)] = d;
+            }
+        }
+        Ok(distances)
+    }
+
+    private_impl! {}
+}
+
+/// Validates the inputs and computes the Cholesky factorisation of `cov`.
+fn factorise<A, S, T>(
+    observations: &ArrayBase<S, Ix2>,
+    cov: &ArrayBase<T, Ix2>,
+) -> Result<Cholesky<A>, CovarianceError>
+where
+    A: Float + FromPrimitive,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    if observations.is_empty() {
+        return Err(CovarianceError::EmptyInput);
+    }
+    let n_variables = observations.nrows();
+    if cov.shape() != [n_variables, n_variables] {
+        return Err(ShapeMismatch {
+            first_shape: observations.shape().to_vec(),
+            second_shape: cov.shape().to_vec(),
+        }
+        .into());
+    }
+    Cholesky::new(cov).ok_or(CovarianceError::NotPositiveDefinite)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CorrelationExt;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    #[test]
+    fn test_sq_mahalanobis_distances_to_sample_mean() {
+        let a = array![[2., 4., 6., 8., 5.], [1., 3., 2., 6., 3.]];
+        let mean = a.mean_axis(Axis(1)).unwrap();
+        let cov = a.cov(1.).unwrap();
+        let distances = a.sq_mahalanobis_distances(&mean, &cov).unwrap();
+        assert_abs_diff_eq!(
+            distances,
+            array![
+                1.8095238095238093,
+                0.6666666666666666,
+                2.9523809523809526,
+                2.5714285714285694,
+                0.
+            ],
+            epsilon = 1e-12
+        );
+        // With ddof = 1 the squared distances sum to (n - 1) times the number of variables
+        assert_abs_diff_eq!(distances.sum(), 8., epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            a.mahalanobis_distances(&mean, &cov).unwrap(),
+            distances.mapv(f64::sqrt),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_identity_covariance_gives_euclidean_distances() {
+        let a = array![[0., 3., 1.], [0., 4., 1.]];
+        let identity = Array2::eye(2);
+        assert_abs_diff_eq!(
+            a.mahalanobis_distances(&array![0., 0.], &identity).unwrap(),
+            array![0., 5., 2f64.sqrt()],
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            a.pairwise_mahalanobis_distances(&identity).unwrap(),
+            array![
+                [0., 5., 2f64.sqrt()],
+                [5., 0., 13f64.sqrt()],
+                [2f64.sqrt(), 13f64.sqrt(), 0.]
+            ],
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_pairwise_mahalanobis_distances() {
+        let a = array![[2., 4., 6., 8., 5.], [1., 3., 2., 6., 3.]];
+        let cov = a.cov(1.).unwrap();
+        let distances = a.pairwise_mahalanobis_distances(&cov).unwrap();
+        assert_abs_diff_eq!(distances[(0, 3)], 2.7945525240230875, epsilon = 1e-12);
+        assert_abs_diff_eq!(distances[(2, 1)], 2.5071326821120348, epsilon = 1e-12);
+        assert_abs_diff_eq!(distances, distances.t(), epsilon = 1e-12);
+        assert_eq!(distances.diag(), Array1::<f64>::zeros(5));
+    }
+
+    #[test]
+    fn test_singular_covariance() {
+        // The second variable is a multiple of the first one
+        let a = array![[1., 2., 3., 4.], [2., 4., 6., 8.]];
+        let mean = a.mean_axis(Axis(1)).unwrap();
+        let cov = a.cov(1.).unwrap();
+        assert_eq!(
+            a.sq_mahalanobis_distances(&mean, &cov),
+            Err(CovarianceError::NotPositiveDefinite)
+        );
+        assert_eq!(
+            a.pairwise_mahalanobis_distances(&cov),
+            Err(CovarianceError::NotPositiveDefinite)
+        );
+    }
+
+    #[test]
+    fn test_shape_mismatch() {
+        let a = array![[1., 2., 3.], [2., 1., 4.]];
+        let cov = Array2::<f64>::eye(3);
+        assert!(a
+            .pairwise_mahalanobis_distances(&cov)
+            .unwrap_err()
+            .is_shape_mismatch());
+        let cov = Array2::<f64>::eye(2);
+        assert_eq!(
+            a.sq_mahalanobis_distances(&array![0., 0., 0.], &cov),
+            Err(CovarianceError::ShapeMismatch(ShapeMismatch {
+                first_shape: vec![2, 3],
+                second_shape: vec![3],
+            }))
+        );
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let a = Array2::<f64>::zeros((2, 0));
+        let cov = Array2::<f64>::eye(2);
+        assert_eq!(
+            a.mahalanobis_distances(&array![0., 0.], &cov),
+            Err(CovarianceError::EmptyInput)
+        );
+    }
+}
@@ -0,0 +1,468 @@
+//! Information theory of pairs of random variables (e.g. joint and conditional entropy,
+//! mutual information, etc.), computed from their joint distribution.
+use crate::entropy::{EntropyExt, LogBase};
+use crate::errors::EmptyInput;
+use ndarray::prelude::*;
+use ndarray::Data;
+use num_traits::Float;
+
+/// The mean of the marginal entropies *H(X)* and *H(Y)* used by
+/// [`normalized_mutual_information`] to normalise the mutual information.
+///
+/// [`normalized_mutual_information`]: trait.MutualInformationExt.html#tymethod.normalized_mutual_information
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NmiNormalization {
+    /// *min(H(X), H(Y))*.
+    Min,
+    /// *√(H(X) H(Y))*.
+    Geometric,
+    /// *(H(X) + H(Y)) / 2*.
+    Arithmetic,
+    /// *max(H(X), H(Y))*.
+    Max,
+}
+
+/// Extension trait for two-dimensional `ArrayBase` providing methods to compute
+/// information theory quantities of a pair of discrete random variables *(X, Y)*
+/// from their joint distribution.
+///
+/// The array is a contingency table: the element at index *(i, j)* is the
+/// probability (or the number of occurrences) of *X = xᵢ* and *Y = yⱼ*, i.e.
+/// *X* indexes the rows and *Y* indexes the columns. The table is normalised by
+/// the sum of its elements before computing any quantity, so counts can be used
+/// directly; the elements are expected to be non-negative, with a positive sum.
+///
+/// All the quantities depending on the unit of measure of information are computed
+/// using a logarithm of the given `base` (see [`LogBase`]).
+///
+/// [`LogBase`]: enum.LogBase.html
+pub trait MutualInformationExt<A, S>
+where
+    S: Data<Elem = A>,
+{
+    /// Computes the [joint entropy] *H(X,Y)* of the joint distribution, defined as
+    ///
+    /// ```text
+    ///             m   n
+    /// H(X,Y) = -  ∑   ∑ pᵢⱼ log(pᵢⱼ)
+    ///            i=1 j=1
+    /// ```
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `base` is not a valid logarithm base (see [`LogBase`]) or if `ln`
+    /// of any element in the normalised array panics (which can occur for negative
+    /// values for some `A`).
+    ///
+    /// [joint entropy]: https://en.wikipedia.org/wiki/Joint_entropy
+    /// [`LogBase`]: enum.LogBase.html
+    fn joint_entropy(&self, base: LogBase<A>) -> Result<A, EmptyInput>
+    where
+        A: Float;
+
+    /// Computes the [conditional entropy] of one variable given the variable indexed
+    /// along `given`: with `Axis(0)`, it is *H(Y|X)*, with `Axis(1)`, it is *H(X|Y)*.
+    ///
+    /// The conditional entropy *H(Y|X)* is defined as
+    ///
+    /// ```text
+    ///             m   n
+    /// H(Y|X) = -  ∑   ∑ pᵢⱼ log(pᵢⱼ / pᵢ)
+    ///            i=1 j=1
+    /// ```
+    ///
+    /// where *pᵢ* is the marginal probability of *X = xᵢ*, and it is equal to
+    /// *H(X,Y) - H(X)*.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `given` is out of bounds, if `base` is not a valid logarithm
+    /// base (see [`LogBase`]) or if `ln` of any element in the normalised array panics.
+    ///
+    /// [conditional entropy]: https://en.wikipedia.org/wiki/Conditional_entropy
+    /// [`LogBase`]: enum.LogBase.html
+    fn conditional_entropy(&self, given: Axis, base: LogBase<A>) -> Result<A, EmptyInput>
+    where
+        A: Float;
+
+    /// Computes the [mutual information] *I(X;Y)* of the joint distribution, defined as
+    ///
+    /// ```text
+    ///            m   n
+    /// I(X;Y) =   ∑   ∑ pᵢⱼ log(pᵢⱼ / (pᵢ pⱼ))
+    ///           i=1 j=1
+    /// ```
+    ///
+    /// where *pᵢ* and *pⱼ* are the marginal probabilities of *X = xᵢ* and *Y = yⱼ*.
+    /// It is equal to *H(X) + H(Y) - H(X,Y)*, and it is zero if and only if *X* and *Y*
+    /// are independent. Negative values due to rounding errors are clamped to zero.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `base` is not a valid logarithm base (see [`LogBase`]) or if `ln`
+    /// of any element in the normalised array panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::{LogBase, MutualInformationExt};
+    ///
+    /// // Counts of the co-occurrences of two binary features
+    /// let counts = array![[10., 0.], [0., 10.]];
+    /// assert_abs_diff_eq!(counts.mutual_information(LogBase::Two).unwrap(), 1.);
+    ///
+    /// let independent = array![[1., 3.], [2., 6.]];
+    /// assert_abs_diff_eq!(
+    ///     independent.mutual_information(LogBase::E).unwrap(),
+    ///     0.,
+    ///     epsilon = 1e-12
+    /// );
+    /// ```
+    ///
+    /// [mutual information]: https://en.wikipedia.org/wiki/Mutual_information
+    /// [`LogBase`]: enum.LogBase.html
+    fn mutual_information(&self, base: LogBase<A>) -> Result<A, EmptyInput>
+    where
+        A: Float;
+
+    /// Computes the normalized [mutual information] of the joint distribution, i.e.
+    /// *I(X;Y)* divided by a mean of the marginal entropies *H(X)* and *H(Y)*
+    /// chosen by `normalization`.
+    ///
+    /// It lies between 0 (independent variables) and 1 (each variable is a function
+    /// of the other), and it does not depend on the logarithm base. By convention, it
+    /// is 1 if both marginal entropies are zero, and 0 if only the normalizer is zero.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `ln` of any element in the normalised array panics.
+    ///
+    /// [mutual information]: https://en.wikipedia.org/wiki/Mutual_information#Normalized_variants
+    fn normalized_mutual_information(
+        &self,
+        normalization: NmiNormalization,
+    ) -> Result<A, EmptyInput>
+    where
+        A: Float;
+
+    /// Computes the [variation of information] *VI(X;Y)* of the joint distribution,
+    /// defined as
+    ///
+    /// ```text
+    /// VI(X;Y) = H(X|Y) + H(Y|X) = H(X,Y) - I(X;Y)
+    /// ```
+    ///
+    /// It is a metric on partitions, often used to compare clusterings.
+    ///
+    /// If the array is empty, `Err(EmptyInput)` is returned.
+    ///
+    /// **Panics** if `base` is not a valid logarithm base (see [`LogBase`]) or if `ln`
+    /// of any element in the normalised array panics.
+    ///
+    /// [variation of information]: https://en.wikipedia.org/wiki/Variation_of_information
+    /// [`LogBase`]: enum.LogBase.html
+    fn variation_of_information(&self, base: LogBase<A>) -> Result<A, EmptyInput>
+    where
+        A: Float;
+
+    private_decl! {}
+}
+
+impl<A, S> MutualInformationExt<A, S> for ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    fn joint_entropy(&self, base: LogBase<A>) -> Result<A, EmptyInput>
+    where
+        A: Float,
+    {
+        normalize(self)?.entropy_with_base(base)
+    }
+
+    fn conditional_entropy(&self, given: Axis, base: LogBase<A>) -> Result<A, EmptyInput>
+    where
+        A: Float,
+    {
+        assert!(given.index() < 2, "`given` must be `Axis(0)` or `Axis(1)`.");
+        let joint = normalize(self)?;
+        Ok(base.from_nats(conditional_entropy(&joint, given)))
+    }
+
+    fn mutual_information(&self, base: LogBase<A>) -> Result<A, EmptyInput>
+    where
+        A: Float,
+    {
+        let joint = normalize(self)?;
+        Ok(base.from_nats(mutual_information(&joint)))
+    }
+
+    fn normalized_mutual_information(
+        &self,
+        normalization: NmiNormalization,
+    ) -> Result<A, EmptyInput>
+    where
+        A: Float,
+    {
+        let joint = normalize(self)?;
+        let h_x = joint.sum_axis(Axis(1)).entropy()?;
+        let h_y = joint.sum_axis(Axis(0)).entropy()?;
+        if h_x == A::zero() && h_y == A::zero() {
+            return Ok(A::one());
+        }
+        let normalizer = match normalization {
+            NmiNormalization::Min => h_x.min(h_y),
+            NmiNormalization::Geometric => (h_x * h_y).sqrt(),
+            NmiNormalization::Arithmetic => (h_x + h_y) / A::from(2.).unwrap(),
+            NmiNormalization::Max => h_x.max(h_y),
+        };
+        let mutual_information = mutual_information(&joint);
+        if normalizer == A::zero() {
+            Ok(A::zero())
+        } else {
+            Ok(mutual_information / normalizer)
+        }
+    }
+
+    fn variation_of_information(&self, base: LogBase<A>) -> Result<A, EmptyInput>
+    where
+        A: Float,
+    {
+        let joint = normalize(self)?;
+        let variation_of_information =
+            conditional_entropy(&joint, Axis(0)) + conditional_entropy(&joint, Axis(1));
+        Ok(base.from_nats(variation_of_information))
+    }
+
+    private_impl! {}
+}
+
+/// Returns the joint distribution obtained by dividing `table` by the sum of its elements.
+fn normalize<A, S>(table: &ArrayBase<S, Ix2>) -> Result<Array2<A>, EmptyInput>
+where
+    A: Float,
+    S: Data<Elem = A>,
+{
+    if table.is_empty() {
+        return Err(EmptyInput);
+    }
+    let total = table.fold(A::zero(), |acc, &x| acc + x);
+    Ok(table.mapv(|x| x / total))
+}
+
+/// Computes the conditional entropy (in nats) of the variable not indexed along `given`,
+/// given the other one.
+fn conditional_entropy<A>(joint: &Array2<A>, given: Axis) -> A
+where
+    A: Float,
+{
+    let marginal = joint.sum_axis(Axis(1 - given.index()));
+    let mut conditional_entropy = A::zero();
+    for (lane, &p_given) in joint.axis_iter(given).zip(&marginal) {
+        for &p in lane {
+            if p != A::zero() {
+                conditional_entropy = conditional_entropy - p * (p / p_given).ln();
+            }
+        }
+    }
+    conditional_entropy
+}
+
+/// Computes the mutual information (in nats) of the joint distribution.
+fn mutual_information<A>(joint: &Array2<A>) -> A
+where
+    A: Float,
+{
+    let p_x = joint.sum_axis(Axis(1));
+    let p_y = joint.sum_axis(Axis(0));
+    let mut mutual_information = A::zero();
+    for ((i, j), &p) in joint.indexed_iter() {
+        if p != A::zero() {
+            mutual_information = mutual_information + p * (p / (p_x[i] * p_y[j])).ln();
+        }
+    }
+    // Clamp negative values due to rounding errors, propagating NaN values
+    if mutual_information < A::zero() {
+        A::zero()
+    } else {
+        mutual_information
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MutualInformationExt, NmiNormalization};
+    use crate::entropy::{EntropyExt, LogBase};
+    use crate::errors::EmptyInput;
+    use approx::assert_abs_diff_eq;
+    use ndarray::{array, Array2, Axis};
+    use std::f64;
+
+    #[test]
+    fn test_identities() -> Result<(), EmptyInput> {
+        let counts: Array2<f64> = array![[3., 1., 0.], [1., 2., 5.], [0., 4., 2.]];
+        let joint = &counts / counts.sum();
+        let h_x = joint.sum_axis(Axis(1)).entropy()?;
+        let h_y = joint.sum_axis(Axis(0)).entropy()?;
+        let h_xy = counts.joint_entropy(LogBase::E)?;
+        assert_abs_diff_eq!(h_xy, joint.entropy()?, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            counts.conditional_entropy(Axis(0), LogBase::E)?,
+            h_xy - h_x,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            counts.conditional_entropy(Axis(1), LogBase::E)?,
+            h_xy - h_y,
+            epsilon = 1e-12
+        );
+        let mi = counts.mutual_information(LogBase::E)?;
+        assert_abs_diff_eq!(mi, h_x + h_y - h_xy, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            counts.variation_of_information(LogBase::E)?,
+            h_xy - mi,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            counts.normalized_mutual_information(NmiNormalization::Min)?,
+            mi / h_x.min(h_y),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            counts.normalized_mutual_information(NmiNormalization::Geometric)?,
+            mi / (h_x * h_y).sqrt(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            counts.normalized_mutual_information(NmiNormalization::Arithmetic)?,
+            2. * mi / (h_x + h_y),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            counts.normalized_mutual_information(NmiNormalization::Max)?,
+            mi / h_x.max(h_y),
+            epsilon = 1e-12
+        );
+        // The table and its transpose describe the same pair of variables
+        assert_abs_diff_eq!(
+            counts.t().mutual_information(LogBase::E)?,
+            mi,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            counts.t().conditional_entropy(Axis(1), LogBase::E)?,
+            counts.conditional_entropy(Axis(0), LogBase::E)?,
+            epsilon = 1e-12
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_log_base() -> Result<(), EmptyInput> {
+        let joint = array![[0.25, 0.25], [0.5, 0.]];
+        assert_abs_diff_eq!(joint.joint_entropy(LogBase::Two)?, 1.5, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            joint.conditional_entropy(Axis(0), LogBase::Two)?,
+            0.5,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            joint.mutual_information(LogBase::Two)?,
+            joint.mutual_information(LogBase::E)? / 2f64.ln(),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            joint.variation_of_information(LogBase::Ten)?,
+            joint.variation_of_information(LogBase::E)? / 10f64.ln(),
+            epsilon = 1e-12
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_extreme_cases() -> Result<(), EmptyInput> {
+        // Y is a function of X
+        let deterministic = array![[2., 0., 0.], [0., 0., 2.], [0., 4., 0.]];
+        let h_x = deterministic.joint_entropy(LogBase::E)?;
+        assert_abs_diff_eq!(
+            deterministic.mutual_information(LogBase::E)?,
+            h_x,
+            epsilon = 1e-12
+        );
+        assert_eq!(deterministic.conditional_entropy(Axis(0), LogBase::E)?, 0.);
+        assert_eq!(deterministic.variation_of_information(LogBase::E)?, 0.);
+        for &normalization in &[
+            NmiNormalization::Min,
+            NmiNormalization::Geometric,
+            NmiNormalization::Arithmetic,
+            NmiNormalization::Max,
+        ] {
+            assert_abs_diff_eq!(
+                deterministic.normalized_mutual_information(normalization)?,
+                1.,
+                epsilon = 1e-12
+            );
+        }
+
+        // Independent variables
+        let independent = array![[0.1, 0.2, 0.1], [0.15, 0.3, 0.15]];
+        assert_abs_diff_eq!(
+            independent.mutual_information(LogBase::E)?,
+            0.,
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            independent.normalized_mutual_information(NmiNormalization::Arithmetic)?,
+            0.,
+            epsilon = 1e-12
+        );
+
+        // Constant variables
+        let single = array![[5.]];
+        assert_eq!(single.joint_entropy(LogBase::E)?, 0.);
+        assert_eq!(
+            single.normalized_mutual_information(NmiNormalization::Max)?,
+            1.
+        );
+        let constant_x = array![[1., 3.]];
+        assert_eq!(
+            constant_x.normalized_mutual_information(NmiNormalization::Min)?,
+            0.
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_nan_values() -> Result<(), EmptyInput> {
+        let joint = array![[f64::NAN, 0.5], [0.25, 0.25]];
+        assert!(joint.joint_entropy(LogBase::E)?.is_nan());
+        assert!(joint.mutual_information(LogBase::E)?.is_nan());
+        assert!(joint
+            .normalized_mutual_information(NmiNormalization::Max)?
+            .is_nan());
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let empty: Array2<f64> = Array2::zeros((0, 2));
+        assert_eq!(empty.joint_entropy(LogBase::E), Err(EmptyInput));
+        assert_eq!(
+            empty.conditional_entropy(Axis(0), LogBase::E),
+            Err(EmptyInput)
+        );
+        assert_eq!(empty.mutual_information(LogBase::E), Err(EmptyInput));
+        assert_eq!(
+            empty.normalized_mutual_information(NmiNormalization::Min),
+            Err(EmptyInput)
+        );
+        assert_eq!(empty.variation_of_information(LogBase::E), Err(EmptyInput));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_conditional_entropy_axis_out_of_bounds_panics() {
+        let _ = array![[0.5, 0.5]].conditional_entropy(Axis(2), LogBase::E);
+    }
+}
@@ -0,0 +1,587 @@
+use crate::errors::{EmptyInput, MultiInputError, ShapeMismatch};
+use ndarray::prelude::*;
+use ndarray::{Data, LinalgScalar};
+use num_traits::{Float, FromPrimitive};
+
+/// A distance between two vectors `x` and `y` of the same length, used by
+/// [`PairwiseDistanceExt`] (the sums below run over the vector components).
+///
+/// [`PairwiseDistanceExt`]: trait.PairwiseDistanceExt.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DistanceMetric<A> {
+    /// The [L1 distance](https://en.wikipedia.org/wiki/Taxicab_geometry), `∑ |xᵢ - yᵢ|`.
+    L1,
+    /// The [L2 distance](https://en.wikipedia.org/wiki/Euclidean_distance), `√(∑ |xᵢ - yᵢ|²)`.
+    L2,
+    /// The [squared L2 distance](https://en.wikipedia.org/wiki/Euclidean_distance#Squared_Euclidean_distance),
+    /// `∑ |xᵢ - yᵢ|²`.
+    SqL2,
+    /// The [L∞ distance](https://en.wikipedia.org/wiki/Chebyshev_distance), `max |xᵢ - yᵢ|`.
+    Linf,
+    /// The [Minkowski distance](https://en.wikipedia.org/wiki/Minkowski_distance) of order `p`,
+    /// `(∑ |xᵢ - yᵢ|ᵖ)^(1/p)`; an infinite `p` gives the L∞ distance.
+    Minkowski(A),
+    /// The [cosine distance](https://en.wikipedia.org/wiki/Cosine_similarity),
+    /// `1 - x·y / (‖x‖ ‖y‖)`; it is NaN if either vector is zero.
+    Cosine,
+    /// The correlation distance, i.e. the cosine distance between the centered vectors
+    /// `x - x̄` and `y - ȳ` (one minus their Pearson correlation); it is NaN if either
+    /// vector is constant.
+    Correlation,
+    /// The [Canberra distance](https://en.wikipedia.org/wiki/Canberra_distance),
+    /// `∑ |xᵢ - yᵢ| / (|xᵢ| + |yᵢ|)`, where the terms with `xᵢ = yᵢ = 0` are taken to be 0.
+    Canberra,
+    /// The [Bray–Curtis dissimilarity](https://en.wikipedia.org/wiki/Bray%E2%80%93Curtis_dissimilarity),
+    /// `∑ |xᵢ - yᵢ| / ∑ |xᵢ + yᵢ|`.
+    BrayCurtis,
+}
+
+/// Extension trait for 2-dimensional arrays providing functions to compute
+/// the distances between all pairs of rows, each row being an observation.
+pub trait PairwiseDistanceExt<A, S>
+where
+    S: Data<Elem = A>,
+{
+    /// Returns the distances between all pairs of rows of `self` in condensed form,
+    /// i.e. the upper triangle (diagonal excluded) of the distance matrix flattened in
+    /// row-major order: for `n` rows, the distance between rows `i < j` is at index
+    /// `n i - i (i + 1) / 2 + j - i - 1` of the returned array, of length `n (n - 1) / 2`.
+    ///
+    /// See [`DistanceMetric`] for the available metrics. The `L2` and `SqL2` metrics are
+    /// computed from the Gram matrix of the rows, centered on their mean to avoid
+    /// cancellation when the rows are far from the origin, and the `Cosine` and
+    /// `Correlation` metrics from the Gram matrix of the normalised (respectively centered
+    /// and normalised) rows, while all the other metrics are computed from the differences
+    /// of the row components.
+    ///
+    /// If `self` has no rows or no columns, it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if the order `p` of a Minkowski distance is NaN or not positive,
+    /// or if the type cast of the number of columns from `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use ndarray_stats::{DistanceMetric, PairwiseDistanceExt};
+    ///
+    /// let points = array![[0., 0.], [3., 4.], [6., 8.]];
+    /// let distances = points.pdist(DistanceMetric::L1).unwrap();
+    /// // (0, 1), (0, 2) and (1, 2)
+    /// assert_eq!(distances, array![7., 14., 7.]);
+    /// ```
+    ///
+    /// [`DistanceMetric`]: enum.DistanceMetric.html
+    fn pdist(&self, metric: DistanceMetric<A>) -> Result<Array1<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive + LinalgScalar;
+
+    /// Returns the symmetric matrix of the distances between all pairs of rows of `self`,
+    /// with zeros on the diagonal.
+    ///
+    /// See [`pdist`] for more details.
+    ///
+    /// If `self` has no rows or no columns, it returns `Err(EmptyInput)`.
+    ///
+    /// **Panics** if the order `p` of a Minkowski distance is NaN or not positive,
+    /// or if the type cast of the number of columns from `usize` to `A` fails.
+    ///
+    /// [`pdist`]: #tymethod.pdist
+    fn pdist_square(&self, metric: DistanceMetric<A>) -> Result<Array2<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive + LinalgScalar;
+
+    /// Returns the matrix of the distances between each row of `self` (the rows of
+    /// the returned matrix) and each row of `other` (its columns).
+    ///
+    /// See [`pdist`] for more details.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` or `other` has no rows or no columns
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same
+    ///   number of columns
+    ///
+    /// **Panics** if the order `p` of a Minkowski distance is NaN or not positive,
+    /// or if the type cast of the number of columns from `usize` to `A` fails.
+    ///
+    /// [`pdist`]: #tymethod.pdist
+    fn cdist<T>(
+        &self,
+        other: &ArrayBase<T, Ix2>,
+        metric: DistanceMetric<A>,
+    ) -> Result<Array2<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive + LinalgScalar,
+        T: Data<Elem = A>;
+
+    private_decl! {}
+}
+
+impl<A, S> PairwiseDistanceExt<A, S> for ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    fn pdist(&self, metric: DistanceMetric<A>) -> Result<Array1<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive + LinalgScalar,
+    {
+        let square = self.pdist_square(metric)?;
+        let n = square.nrows();
+        let mut condensed = Vec::with_capacity(n * n.saturating_sub(1) / 2);
+        for i in 0..n {
+            condensed.extend(square.slice(s![i, (i + 1)..]).iter().cloned());
+        }
+        Ok(Array1::from(condensed))
+    }
+
+    fn pdist_square(&self, metric: DistanceMetric<A>) -> Result<Array2<A>, EmptyInput>
+    where
+        A: Float + FromPrimitive + LinalgScalar,
+    {
+        if self.is_empty() {
+            return Err(EmptyInput);
+        }
+        let mut distances = pairwise_distances(self, self, metric, true);
+        distances.diag_mut().fill(A::zero());
+        Ok(distances)
+    }
+
+    fn cdist<T>(
+        &self,
+        other: &ArrayBase<T, Ix2>,
+        metric: DistanceMetric<A>,
+    ) -> Result<Array2<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive + LinalgScalar,
+        T: Data<Elem = A>,
+    {
+        return_err_if_empty!(self);
+        return_err_if_empty!(other);
+        if self.ncols() != other.ncols() {
+            return Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+                first_shape: self.shape().to_vec(),
+                second_shape: other.shape().to_vec(),
+            }));
+        }
+        Ok(pairwise_distances(self, other, metric, false))
+    }
+
+    private_impl! {}
+}
+
+/// Computes the distances between each row of `x` and each row of `y`.
+///
+/// If `symmetric` is `true`, `x` and `y` are assumed to be the same array
+/// and only the upper triangle is computed elementwise.
+fn pairwise_distances<A, S, T>(
+    x: &ArrayBase<S, Ix2>,
+    y: &ArrayBase<T, Ix2>,
+    metric: DistanceMetric<A>,
+    symmetric: bool,
+) -> Array2<A>
+where
+    A: Float + FromPrimitive + LinalgScalar,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    match metric {
+        DistanceMetric::SqL2 => sq_euclidean_gram(x, y, symmetric),
+        DistanceMetric::L2 => sq_euclidean_gram(x, y, symmetric).mapv_into(A::sqrt),
+        DistanceMetric::Cosine => cosine_gram(x.to_owned(), y.to_owned()),
+        DistanceMetric::Correlation => cosine_gram(centered_rows(x), centered_rows(y)),
+        DistanceMetric::L1 => elementwise(x, y, symmetric, |a, b| {
+            a.iter()
+                .zip(b)
+                .fold(A::zero(), |acc, (&a, &b)| acc + (a - b).abs())
+        }),
+        DistanceMetric::Linf => elementwise(x, y, symmetric, linf),
+        DistanceMetric::Minkowski(p) => {
+            assert!(
+                p > A::zero(),
+                "The order of the Minkowski distance must be positive."
+            );
+            if p.is_infinite() {
+                elementwise(x, y, symmetric, linf)
+            } else {
+                elementwise(x, y, symmetric, |a, b| {
+                    a.iter()
+                        .zip(b)
+                        .fold(A::zero(), |acc, (&a, &b)| acc + (a - b).abs().powf(p))
+                        .powf(p.recip())
+                })
+            }
+        }
+        DistanceMetric::Canberra => elementwise(x, y, symmetric, |a, b| {
+            a.iter().zip(b).fold(A::zero(), |acc, (&a, &b)| {
+                let denominator = a.abs() + b.abs();
+                if denominator > A::zero() {
+                    acc + (a - b).abs() / denominator
+                } else {
+                    acc
+                }
+            })
+        }),
+        DistanceMetric::BrayCurtis => elementwise(x, y, symmetric, |a, b| {
+            let (difference, sum) = a
+                .iter()
+                .zip(b)
+                .fold((A::zero(), A::zero()), |(d, s), (&a, &b)| {
+                    (d + (a - b).abs(), s + (a + b).abs())
+                });
+            difference / sum
+        }),
+    }
+}
+
+fn linf<A>(a: ArrayView1<A>, b: ArrayView1<A>) -> A
+where
+    A: Float,
+{
+    a.iter()
+        .zip(b)
+        .fold(A::zero(), |acc, (&a, &b)| acc.max((a - b).abs()))
+}
+
+fn elementwise<A, S, T, F>(
+    x: &ArrayBase<S, Ix2>,
+    y: &ArrayBase<T, Ix2>,
+    symmetric: bool,
+    distance: F,
+) -> Array2<A>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+    F: Fn(ArrayView1<A>, ArrayView1<A>) -> A,
+{
+    let mut distances = Array2::zeros((x.nrows(), y.nrows()));
+    for (i, row) in x.outer_iter().enumerate() {
+        let start = if symmetric { i + 1 } else { 0 };
+        for j in start..y.nrows() {
+            distances[(i, j)] = distance(row.view(), y.row(j));
+            if symmetric {
+                distances[(j, i)] = distances[(i, j)];
+            }
+        }
+    }
+    distances
+}
+
+/// Computes the squared Euclidean distances as `‖x‖² + ‖y‖² - 2 x·y` from the Gram
+/// matrix of the rows.
+///
+/// The rows of both arrays are first centered on the mean of all of them, which
+/// leaves the distances unchanged but avoids the catastrophic cancellation of the
+/// formula when the rows are far from the origin relative to their spread.
+fn sq_euclidean_gram<A, S, T>(
+    x: &ArrayBase<S, Ix2>,
+    y: &ArrayBase<T, Ix2>,
+    symmetric: bool,
+) -> Array2<A>
+where
+    A: Float + FromPrimitive + LinalgScalar,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+{
+    let n_rows = A::from_usize(x.nrows() + y.nrows()).unwrap();
+    let mean = (x.sum_axis(Axis(0)) + y.sum_axis(Axis(0))).mapv_into(|sum| sum / n_rows);
+    let x = x - &mean;
+    let y = y - &mean;
+    let sq_norms = |m: &Array2<A>| m.map_axis(Axis(1), |row| row.dot(&row));
+    let (x_sq_norms, y_sq_norms) = (sq_norms(&x), sq_norms(&y));
+
+    let mut distances = x.dot(&y.t());
+    for ((i, j), distance) in distances.indexed_iter_mut() {
+        let sq_distance = x_sq_norms[i] + y_sq_norms[j] - (*distance + *distance);
+        // Rounding errors may still make the distance between (nearly) identical rows negative
+        *distance = if sq_distance < A::zero() {
+            A::zero()
+        } else {
+            sq_distance
+        };
+    }
+    if symmetric {
+        for i in 0..distances.nrows() {
+            distances[(i, i)] = A::zero();
+            for j in (i + 1)..distances.ncols() {
+                distances[(j, i)] = distances[(i, j)];
+            }
+        }
+    }
+    distances
+}
+
+/// Computes the cosine distances as one minus the Gram matrix of the normalised rows.
+fn cosine_gram<A>(mut x: Array2<A>, mut y: Array2<A>) -> Array2<A>
+where
+    A: Float + LinalgScalar,
+{
+    for m in [&mut x, &mut y].iter_mut() {
+        for mut row in m.outer_iter_mut() {
+            let norm = row.fold(A::zero(), |acc, &v| acc + v * v).sqrt();
+            row.mapv_inplace(|v| v / norm);
+        }
+    }
+    // Rounding errors may push the similarities slightly outside of [-1, 1]
+    x.dot(&y.t()).mapv_into(|similarity| {
+        let distance = A::one() - similarity;
+        let two = A::one() + A::one();
+        if distance < A::zero() {
+            A::zero()
+        } else if distance > two {
+            two
+        } else {
+            distance
+        }
+    })
+}
+
+fn centered_rows<A, S>(x: &ArrayBase<S, Ix2>) -> Array2<A>
+where
+    A: Float + FromPrimitive,
+    S: Data<Elem = A>,
+{
+    let mean = x.mean_axis(Axis(1)).unwrap();
+    x - &mean.insert_axis(Axis(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+    use std::f64;
+    use std::f64::consts::SQRT_2;
+
+    fn points() -> Array2<f64> {
+        array![[1., 0., 2.], [0., 3., 1.], [2., 2., 3.], [-1., 1., 0.5]]
+    }
+
+    fn others() -> Array2<f64> {
+        array![[1., 2., 1.], [0., 0., 3.]]
+    }
+
+    #[test]
+    fn test_pdist() {
+        let x = points();
+        let cases = vec![
+            (DistanceMetric::L1, array![5., 4., 4.5, 5., 3.5, 6.5]),
+            (
+                DistanceMetric::L2,
+                array![
+                    3.3166247903554,
+                    2.449489742783178,
+                    2.692582403567252,
+                    3.,
+                    2.29128784747792,
+                    4.031128874149275
+                ],
+            ),
+            (DistanceMetric::SqL2, array![11., 6., 7.25, 9., 5.25, 16.25]),
+            (DistanceMetric::Linf, array![3., 2., 2., 2., 2., 3.]),
+            (
+                DistanceMetric::Minkowski(3.),
+                array![
+                    3.072316825685847,
+                    2.154434690031884,
+                    2.3130325045913707,
+                    2.571281590658235,
+                    2.089669598190616,
+                    3.520290308356736
+                ],
+            ),
+            (
+                DistanceMetric::Cosine,
+                array![
+                    0.717157287525381,
+                    0.13227816872537534,
+                    1.,
+                    0.30973151003736665,
+                    0.26213521262737816,
+                    0.757464374963667
+                ],
+            ),
+            (
+                DistanceMetric::Correlation,
+                array![
+                    1.6546536707079773,
+                    0.13397459621556151,
+                    1.2401922307076307,
+                    1.1889822365046137,
+                    0.10895788878636936,
+                    0.7226499018873855
+                ],
+            ),
+            (
+                DistanceMetric::Canberra,
+                array![
+                    2.3333333333333335,
+                    1.5333333333333332,
+                    2.6,
+                    1.7,
+                    1.8333333333333333,
+                    2.0476190476190474
+                ],
+            ),
+            (
+                DistanceMetric::BrayCurtis,
+                array![
+                    0.7142857142857143,
+                    0.4,
+                    1.2857142857142858,
+                    0.45454545454545453,
+                    0.5384615384615384,
+                    0.8666666666666667
+                ],
+            ),
+        ];
+        for (metric, expected) in cases {
+            assert_abs_diff_eq!(x.pdist(metric).unwrap(), expected, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_pdist_square_matches_condensed_form() {
+        let x = points();
+        for &metric in &[
+            DistanceMetric::L1,
+            DistanceMetric::L2,
+            DistanceMetric::Minkowski(1.5),
+            DistanceMetric::Correlation,
+            DistanceMetric::Canberra,
+        ] {
+            let square = x.pdist_square(metric).unwrap();
+            let condensed = x.pdist(metric).unwrap();
+            assert_eq!(square.diag(), Array1::<f64>::zeros(4));
+            assert_eq!(square, square.t());
+            let mut k = 0;
+            for i in 0..4 {
+                for j in (i + 1)..4 {
+                    assert_eq!(square[(i, j)], condensed[k]);
+                    k += 1;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cdist() {
+        let (x, y) = (points(), others());
+        assert_abs_diff_eq!(
+            x.cdist(&y, DistanceMetric::L2).unwrap(),
+            array![
+                [2.23606797749979, SQRT_2],
+                [SQRT_2, 3.605551275463989],
+                [2.23606797749979, 2.8284271247461903],
+                [2.29128784747792, 2.8722813232690143]
+            ],
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            x.cdist(&y, DistanceMetric::Cosine).unwrap(),
+            array![
+                [0.4522774424948339, 0.10557280900008414],
+                [0.0963038858849361, 0.683772233983162],
+                [0.10886721132099308, 0.272393124891001],
+                [0.591751709536137, 0.6666666666666667]
+            ],
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            x.cdist(&y, DistanceMetric::BrayCurtis).unwrap(),
+            array![
+                [0.42857142857142855, 0.3333333333333333],
+                [0.25, 0.7142857142857143],
+                [0.2727272727272727, 0.4],
+                [0.7777777777777778, 0.8181818181818182]
+            ],
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            x.cdist(&y, DistanceMetric::Minkowski(f64::INFINITY))
+                .unwrap(),
+            x.cdist(&y, DistanceMetric::Linf).unwrap()
+        );
+        assert_abs_diff_eq!(
+            x.cdist(&x, DistanceMetric::SqL2).unwrap(),
+            x.pdist_square(DistanceMetric::SqL2).unwrap(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_euclidean_distances_of_identical_rows_are_zero() {
+        let x = array![[1e8, 1e8 + 1.], [1e8, 1e8 + 1.]];
+        assert_eq!(
+            x.cdist(&x, DistanceMetric::SqL2).unwrap(),
+            Array2::zeros((2, 2))
+        );
+    }
+
+    #[test]
+    fn test_euclidean_distances_with_large_offset() {
+        let x = array![[1e8, 0.], [1e8 + 1., 0.], [1e8 + 4., 3.]];
+        assert_eq!(
+            x.pdist(DistanceMetric::L2).unwrap(),
+            array![1., 5., 18f64.sqrt()]
+        );
+        assert_eq!(
+            x.pdist_square(DistanceMetric::SqL2).unwrap(),
+            array![[0., 1., 25.], [1., 0., 18.], [25., 18., 0.]]
+        );
+        assert_abs_diff_eq!(
+            x.cdist(&x.slice(s![1.., ..]), DistanceMetric::SqL2)
+                .unwrap(),
+            array![[1., 25.], [0., 18.], [18., 0.]],
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_cosine_distances_of_identical_rows_are_not_negative() {
+        let x = array![[0.1, 0.2, 0.3], [1e-3, 7., 1e3], [3., 1.1, 0.7]];
+        let distances = x.cdist(&x, DistanceMetric::Cosine).unwrap();
+        assert!(distances.iter().all(|d| (0. ..=2.).contains(d)));
+        assert_abs_diff_eq!(distances.diag(), Array1::zeros(3), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_canberra_with_zeros() {
+        let x = array![[0., 1.], [0., 3.]];
+        assert_abs_diff_eq!(x.pdist(DistanceMetric::Canberra).unwrap(), array![0.5]);
+    }
+
+    #[test]
+    fn test_single_row() {
+        let x = array![[1., 2.]];
+        assert_eq!(x.pdist(DistanceMetric::L1).unwrap().len(), 0);
+        assert_eq!(x.pdist_square(DistanceMetric::L1).unwrap(), array![[0.]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_non_positive_minkowski_order_panics() {
+        let _ = points().pdist(DistanceMetric::Minkowski(0.));
+    }
+
+    #[test]
+    fn test_errors() {
+        let x = points();
+        assert_eq!(
+            Array2::<f64>::zeros((0, 3)).pdist(DistanceMetric::L2),
+            Err(EmptyInput)
+        );
+        assert_eq!(
+            x.cdist(&Array2::zeros((2, 0)), DistanceMetric::L2),
+            Err(MultiInputError::EmptyInput)
+        );
+        assert_eq!(
+            x.cdist(&Array2::zeros((2, 2)), DistanceMetric::L2),
+            Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+                first_shape: vec![4, 3],
+                second_shape: vec![2, 2],
+            }))
+        );
+    }
+}
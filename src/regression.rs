@@ -0,0 +1,632 @@
+use crate::errors::{MultiInputError, ShapeMismatch};
+use ndarray::{ArrayBase, ArrayView, Data, Dimension, Zip};
+use num_traits::{Float, FromPrimitive};
+
+/// An extension trait for `ArrayBase` providing functions to evaluate
+/// the predictions of a regression model.
+///
+/// As for [`DeviationExt`], each method compares two arrays with the same shape:
+/// `self` holds the predicted values `ŷ` and `other` the true values `y`.
+///
+/// Most methods accept optional sample weights `w`, with the same shape as `self`:
+/// the weighted mean of a quantity `q` is `∑ wᵢ qᵢ / ∑ wᵢ`. Passing `None` gives every
+/// sample a unit weight.
+///
+/// [`DeviationExt`]: trait.DeviationExt.html
+pub trait RegressionMetricsExt<A, S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Computes the [coefficient of determination] R² of the predictions `self`
+    /// with respect to the true values `other`:
+    ///
+    /// ```text
+    ///          ∑ wᵢ (yᵢ - ŷᵢ)²
+    /// R² = 1 - ―――――――――――――――
+    ///          ∑ wᵢ (yᵢ - ȳ)²
+    /// ```
+    ///
+    /// where `ȳ` is the weighted mean of the true values. It is 1 for perfect predictions
+    /// and can be negative for predictions worse than the constant `ȳ`.
+    /// If the true values are constant, the result is 1 for perfect predictions and 0
+    /// otherwise.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `other` or `sample_weight` don't have the
+    ///   same shape as `self`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::array;
+    /// use ndarray_stats::RegressionMetricsExt;
+    ///
+    /// let truth = array![3., -0.5, 2., 7.];
+    /// let prediction = array![2.5, 0., 2., 8.];
+    /// let r2 = prediction.r2_score(&truth, None).unwrap();
+    /// assert_abs_diff_eq!(r2, 0.9486081370449679, epsilon = 1e-12);
+    ///
+    /// let weights = array![1., 2., 0.5, 1.];
+    /// let r2 = prediction.r2_score(&truth, Some(weights.view())).unwrap();
+    /// assert_abs_diff_eq!(r2, 0.9542815674891146, epsilon = 1e-12);
+    /// ```
+    ///
+    /// [coefficient of determination]: https://en.wikipedia.org/wiki/Coefficient_of_determination
+    fn r2_score<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>;
+
+    /// Computes the [adjusted R²] of the predictions `self` of a model with `n_features`
+    /// explanatory variables, with respect to the true values `other`:
+    ///
+    /// ```text
+    ///                   n - 1
+    /// R̄² = 1 - (1 - R²) ―――――――――
+    ///                   n - p - 1
+    /// ```
+    ///
+    /// where `n` is the number of samples, `p` is `n_features` and `R²` is computed
+    /// with [`r2_score`].
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `other` or `sample_weight` don't have the
+    ///   same shape as `self`
+    ///
+    /// **Panics** if `n` is not larger than `n_features + 1`.
+    ///
+    /// [adjusted R²]: https://en.wikipedia.org/wiki/Coefficient_of_determination#Adjusted_R2
+    /// [`r2_score`]: #tymethod.r2_score
+    fn adjusted_r2_score<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        n_features: usize,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>;
+
+    /// Computes the [explained variance] score of the predictions `self` with respect
+    /// to the true values `other`:
+    ///
+    /// ```text
+    ///         Var(y - ŷ)
+    /// 1 - ――――――――――――
+    ///          Var(y)
+    /// ```
+    ///
+    /// where the variances are weighted. Unlike R², it does not penalise a constant
+    /// bias of the predictions. If the true values are constant, the result is 1 if the
+    /// residuals are constant too and 0 otherwise.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `other` or `sample_weight` don't have the
+    ///   same shape as `self`
+    ///
+    /// [explained variance]: https://en.wikipedia.org/wiki/Explained_variation
+    fn explained_variance_score<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>;
+
+    /// Computes the [mean absolute percentage error] of the predictions `self` with
+    /// respect to the true values `other`, as a fraction (not multiplied by 100):
+    ///
+    /// ```text
+    /// mean of |yᵢ - ŷᵢ| / max(|yᵢ|, ε)
+    /// ```
+    ///
+    /// where `ε` is the machine epsilon of `A`, which avoids divisions by zero.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `other` or `sample_weight` don't have the
+    ///   same shape as `self`
+    ///
+    /// [mean absolute percentage error]: https://en.wikipedia.org/wiki/Mean_absolute_percentage_error
+    fn mean_abs_percentage_err<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>;
+
+    /// Computes the [symmetric mean absolute percentage error] of the predictions `self`
+    /// with respect to the true values `other`, as a fraction between 0 and 2:
+    ///
+    /// ```text
+    /// mean of 2 |yᵢ - ŷᵢ| / (|yᵢ| + |ŷᵢ|)
+    /// ```
+    ///
+    /// where the terms with `yᵢ = ŷᵢ = 0` are taken to be 0.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `other` or `sample_weight` don't have the
+    ///   same shape as `self`
+    ///
+    /// [symmetric mean absolute percentage error]: https://en.wikipedia.org/wiki/Symmetric_mean_absolute_percentage_error
+    fn symmetric_mean_abs_percentage_err<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>;
+
+    /// Computes the (weighted) [median] of the absolute errors `|yᵢ - ŷᵢ|` of the
+    /// predictions `self` with respect to the true values `other`.
+    ///
+    /// The weighted median is the smallest error at which the cumulative weight of the
+    /// sorted errors reaches half of the total weight; if it reaches exactly half, the
+    /// median is the average of this error and of the next one (with a non-zero weight).
+    /// With unit weights, this is the usual median.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `other` or `sample_weight` don't have the
+    ///   same shape as `self`
+    ///
+    /// **Panics** if any absolute error is NaN.
+    ///
+    /// [median]: https://en.wikipedia.org/wiki/Weighted_median
+    fn median_abs_err<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>;
+
+    /// Computes the maximum absolute error `max |yᵢ - ŷᵢ|` of the predictions `self`
+    /// with respect to the true values `other` (the L∞ distance, see
+    /// [`DeviationExt::linf_dist`]).
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// [`DeviationExt::linf_dist`]: trait.DeviationExt.html#tymethod.linf_dist
+    fn max_err<T>(&self, other: &ArrayBase<T, D>) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>;
+
+    /// Computes the mean squared logarithmic error of the predictions `self` with
+    /// respect to the true values `other`:
+    ///
+    /// ```text
+    /// mean of (ln(1 + yᵢ) - ln(1 + ŷᵢ))²
+    /// ```
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `other` or `sample_weight` don't have the
+    ///   same shape as `self`
+    ///
+    /// **Panics** if any element of `self` or `other` is negative.
+    fn mean_sq_log_err<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>;
+
+    /// Computes the mean [Tweedie deviance] with power `power` of the predictions `self`
+    /// (the means `μ`) with respect to the true values `other`:
+    ///
+    /// ```text
+    ///          ⎧ (y - μ)²                                              p = 0
+    ///          ⎪ 2 (y ln(y/μ) - y + μ)                                 p = 1
+    /// d(y,μ) = ⎨ 2 (ln(μ/y) + y/μ - 1)                                 p = 2
+    ///          ⎪ 2 (max(y,0)²⁻ᵖ/((1-p)(2-p)) - yμ¹⁻ᵖ/(1-p) + μ²⁻ᵖ/(2-p))  otherwise
+    ///          ⎩
+    /// ```
+    ///
+    /// where `p` is `power` and `y ln(y/μ)` is taken to be 0 for `y = 0`.
+    /// Special cases are the squared error (`p = 0`), the Poisson deviance (`p = 1`)
+    /// and the Gamma deviance (`p = 2`).
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `other` or `sample_weight` don't have the
+    ///   same shape as `self`
+    ///
+    /// **Panics** if `0 < power < 1` (no Tweedie distribution exists) or if the values
+    /// are outside the domain of the deviance: predictions must be strictly positive
+    /// unless `power = 0`, true values must be non-negative if `1 ≤ power < 2` and
+    /// strictly positive if `power ≥ 2`.
+    ///
+    /// [Tweedie deviance]: https://en.wikipedia.org/wiki/Tweedie_distribution#The_Tweedie_deviance
+    fn mean_tweedie_deviance<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        power: A,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>;
+
+    /// Computes the mean Poisson deviance of the predictions `self` with respect to the
+    /// true values `other`, i.e. the mean Tweedie deviance with power 1 (see
+    /// [`mean_tweedie_deviance`]).
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `other` or `sample_weight` don't have the
+    ///   same shape as `self`
+    ///
+    /// **Panics** if any prediction is not strictly positive or any true value is negative.
+    ///
+    /// [`mean_tweedie_deviance`]: #tymethod.mean_tweedie_deviance
+    fn mean_poisson_deviance<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>;
+
+    /// Computes the mean Gamma deviance of the predictions `self` with respect to the
+    /// true values `other`, i.e. the mean Tweedie deviance with power 2 (see
+    /// [`mean_tweedie_deviance`]).
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `other` or `sample_weight` don't have the
+    ///   same shape as `self`
+    ///
+    /// **Panics** if any prediction or true value is not strictly positive.
+    ///
+    /// [`mean_tweedie_deviance`]: #tymethod.mean_tweedie_deviance
+    fn mean_gamma_deviance<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>;
+
+    private_decl! {}
+}
+
+impl<A, S, D> RegressionMetricsExt<A, S, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn r2_score<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+    {
+        let samples = weighted_samples(self, other, sample_weight)?;
+        let mean = weighted_mean(&samples, |_, y| y);
+        let residual = weighted_mean(&samples, |y_hat, y| (y - y_hat) * (y - y_hat));
+        let total = weighted_mean(&samples, |_, y| (y - mean) * (y - mean));
+        Ok(one_minus_ratio(residual, total))
+    }
+
+    fn adjusted_r2_score<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        n_features: usize,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+    {
+        let r2 = self.r2_score(other, sample_weight)?;
+        let n = self.len();
+        assert!(
+            n > n_features + 1,
+            "The number of samples must be larger than `n_features + 1`."
+        );
+        let ratio = A::from_usize(n - 1).unwrap() / A::from_usize(n - n_features - 1).unwrap();
+        Ok(A::one() - (A::one() - r2) * ratio)
+    }
+
+    fn explained_variance_score<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+    {
+        let samples = weighted_samples(self, other, sample_weight)?;
+        let mean = weighted_mean(&samples, |_, y| y);
+        let mean_residual = weighted_mean(&samples, |y_hat, y| y - y_hat);
+        let residual = weighted_mean(&samples, |y_hat, y| {
+            let r = y - y_hat - mean_residual;
+            r * r
+        });
+        let total = weighted_mean(&samples, |_, y| (y - mean) * (y - mean));
+        Ok(one_minus_ratio(residual, total))
+    }
+
+    fn mean_abs_percentage_err<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+    {
+        let samples = weighted_samples(self, other, sample_weight)?;
+        Ok(weighted_mean(&samples, |y_hat, y| {
+            (y - y_hat).abs() / y.abs().max(A::epsilon())
+        }))
+    }
+
+    fn symmetric_mean_abs_percentage_err<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+    {
+        let samples = weighted_samples(self, other, sample_weight)?;
+        Ok(weighted_mean(&samples, |y_hat, y| {
+            let denominator = y.abs() + y_hat.abs();
+            if denominator > A::zero() {
+                let error = (y - y_hat).abs();
+                (error + error) / denominator
+            } else {
+                A::zero()
+            }
+        }))
+    }
+
+    fn median_abs_err<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+    {
+        let samples = weighted_samples(self, other, sample_weight)?;
+        let mut errors: Vec<(A, A)> = samples
+            .iter()
+            .filter(|&&(_, _, w)| w > A::zero())
+            .map(|&(y_hat, y, w)| ((y - y_hat).abs(), w))
+            .collect();
+        errors.sort_by(|a, b| {
+            a.0.partial_cmp(&b.0)
+                .expect("The median is undefined for NaN errors.")
+        });
+        let half = errors.iter().fold(A::zero(), |acc, &(_, w)| acc + w) / A::from_u8(2).unwrap();
+        let mut cumulative = A::zero();
+        for (i, &(error, w)) in errors.iter().enumerate() {
+            cumulative = cumulative + w;
+            if cumulative == half && i + 1 < errors.len() {
+                return Ok((error + errors[i + 1].0) / A::from_u8(2).unwrap());
+            }
+            if cumulative >= half {
+                return Ok(error);
+            }
+        }
+        // Only reachable if all the weights are zero
+        Ok(A::nan())
+    }
+
+    fn max_err<T>(&self, other: &ArrayBase<T, D>) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+    {
+        let samples = weighted_samples(self, other, None)?;
+        Ok(samples
+            .iter()
+            .fold(A::zero(), |acc, &(y_hat, y, _)| acc.max((y - y_hat).abs())))
+    }
+
+    fn mean_sq_log_err<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+    {
+        let samples = weighted_samples(self, other, sample_weight)?;
+        assert!(
+            samples
+                .iter()
+                .all(|&(y_hat, y, _)| y_hat >= A::zero() && y >= A::zero()),
+            "The mean squared logarithmic error is undefined for negative values."
+        );
+        Ok(weighted_mean(&samples, |y_hat, y| {
+            let d = y.ln_1p() - y_hat.ln_1p();
+            d * d
+        }))
+    }
+
+    fn mean_tweedie_deviance<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        power: A,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+    {
+        let samples = weighted_samples(self, other, sample_weight)?;
+        let (zero, one, two) = (A::zero(), A::one(), A::from_u8(2).unwrap());
+        assert!(
+            power <= zero || power >= one,
+            "The Tweedie deviance is undefined for powers between 0 and 1."
+        );
+        let valid = |y_hat: A, y: A| {
+            if power == zero {
+                true
+            } else if power < zero {
+                y_hat > zero
+            } else if power < two {
+                y_hat > zero && y >= zero
+            } else {
+                y_hat > zero && y > zero
+            }
+        };
+        assert!(
+            samples.iter().all(|&(y_hat, y, _)| valid(y_hat, y)),
+            "The values are outside the domain of the Tweedie deviance with power {}.",
+            power.to_f64().unwrap()
+        );
+        Ok(weighted_mean(&samples, |mu, y| {
+            if power == zero {
+                (y - mu) * (y - mu)
+            } else if power == one {
+                let y_ln = if y > zero { y * (y / mu).ln() } else { zero };
+                two * (y_ln - y + mu)
+            } else if power == two {
+                two * ((mu / y).ln() + y / mu - one)
+            } else {
+                let (one_minus_p, two_minus_p) = (one - power, two - power);
+                two * (y.max(zero).powf(two_minus_p) / (one_minus_p * two_minus_p)
+                    - y * mu.powf(one_minus_p) / one_minus_p
+                    + mu.powf(two_minus_p) / two_minus_p)
+            }
+        }))
+    }
+
+    fn mean_poisson_deviance<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+    {
+        self.mean_tweedie_deviance(other, A::one(), sample_weight)
+    }
+
+    fn mean_gamma_deviance<T>(
+        &self,
+        other: &ArrayBase<T, D>,
+        sample_weight: Option<ArrayView<A, D>>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+    {
+        self.mean_tweedie_deviance(other, A::from_u8(2).unwrap(), sample_weight)
+    }
+
+    private_impl! {}
+}
+
+/// Validates the inputs and returns the triples `(ŷᵢ, yᵢ, wᵢ)`.
+fn weighted_samples<A, S, T, D>(
+    prediction: &ArrayBase<S, D>,
+    truth: &ArrayBase<T, D>,
+    sample_weight: Option<ArrayView<A, D>>,
+) -> Result<Vec<(A, A, A)>, MultiInputError>
+where
+    A: Float,
+    S: Data<Elem = A>,
+    T: Data<Elem = A>,
+    D: Dimension,
+{
+    return_err_if_empty!(prediction);
+    return_err_unless_same_shape!(prediction, truth);
+
+    let mut samples = Vec::with_capacity(prediction.len());
+    match sample_weight {
+        Some(weights) => {
+            if weights.shape() != prediction.shape() {
+                return Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+                    first_shape: prediction.shape().to_vec(),
+                    second_shape: weights.shape().to_vec(),
+                }));
+            }
+            Zip::from(prediction)
+                .and(truth)
+                .and(&weights)
+                .apply(|&y_hat, &y, &w| samples.push((y_hat, y, w)));
+        }
+        None => Zip::from(prediction)
+            .and(truth)
+            .apply(|&y_hat, &y| samples.push((y_hat, y, A::one()))),
+    }
+    Ok(samples)
+}
+
+/// Computes `∑ wᵢ f(ŷᵢ, yᵢ) / ∑ wᵢ`.
+fn weighted_mean<A, F>(samples: &[(A, A, A)], f: F) -> A
+where
+    A: Float,
+    F: Fn(A, A) -> A,
+{
+    let (sum, total_weight) = samples
+        .iter()
+        .fold((A::zero(), A::zero()), |(sum, total), &(y_hat, y, w)| {
+            (sum + w * f(y_hat, y), total + w)
+        });
+    sum / total_weight
+}
+
+/// Computes `1 - numerator / denominator`, returning 1 if both are zero
+/// and 0 if only the denominator is zero.
+fn one_minus_ratio<A>(numerator: A, denominator: A) -> A
+where
+    A: Float,
+{
+    if denominator == A::zero() {
+        if numerator == A::zero() {
+            A::one()
+        } else {
+            A::zero()
+        }
+    } else {
+        A::one() - numerator / denominator
+    }
+}
@@ -0,0 +1,412 @@
+//! Special functions and cumulative distribution functions
+//! (e.g. incomplete gamma and beta functions, χ², Student's t and normal
+//! distributions) needed to compute the p-values of statistical tests.
+//!
+//! Everything is computed in `f64`, following the algorithms in
+//! [Numerical Recipes, 3rd edition], chapter 6.
+//!
+//! [Numerical Recipes, 3rd edition]: http://numerical.recipes/book/book.html
+use std::f64;
+
+const LANCZOS_G: f64 = 7.;
+const LANCZOS_COEFFICIENTS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// Maximum number of iterations for series and continued fraction expansions.
+const MAX_ITERATIONS: usize = 1_000;
+
+/// Natural logarithm of the gamma function, `ln Γ(x)`, for `x > 0`,
+/// computed using the [Lanczos approximation].
+///
+/// [Lanczos approximation]: https://en.wikipedia.org/wiki/Lanczos_approximation
+pub(crate) fn ln_gamma(x: f64) -> f64 {
+    if x < 0.5 {
+        // Reflection formula: Γ(x)Γ(1-x) = π / sin(πx)
+        (f64::consts::PI / (f64::consts::PI * x).sin()).abs().ln() - ln_gamma(1. - x)
+    } else {
+        let x = x - 1.;
+        let t = x + LANCZOS_G + 0.5;
+        let series = LANCZOS_COEFFICIENTS
+            .iter()
+            .enumerate()
+            .skip(1)
+            .fold(LANCZOS_COEFFICIENTS[0], |acc, (i, &c)| {
+                acc + c / (x + i as f64)
+            });
+        0.5 * (2. * f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+    }
+}
+
+/// [Digamma function] `ψ(x) = d ln Γ(x) / dx`, for `x > 0`, computed using the
+/// recurrence `ψ(x) = ψ(x + 1) - 1/x` and its asymptotic expansion for large `x`.
+///
+/// [Digamma function]: https://en.wikipedia.org/wiki/Digamma_function
+pub(crate) fn digamma(x: f64) -> f64 {
+    let mut x = x;
+    let mut result = 0.;
+    while x < 10. {
+        result -= 1. / x;
+        x += 1.;
+    }
+    let inv_sq = 1. / (x * x);
+    result + x.ln()
+        - 0.5 / x
+        - inv_sq
+            * (1. / 12.
+                - inv_sq
+                    * (1. / 120. - inv_sq * (1. / 252. - inv_sq * (1. / 240. - inv_sq / 132.))))
+}
+
+/// Regularized upper incomplete gamma function `Q(a, x) = Γ(a, x) / Γ(a)`,
+/// for `a > 0` and `x ≥ 0`.
+pub(crate) fn regularized_gamma_q(a: f64, x: f64) -> f64 {
+    if x.is_nan() || a.is_nan() {
+        f64::NAN
+    } else if x <= 0. {
+        1.
+    } else if x < a + 1. {
+        1. - gamma_series(a, x)
+    } else {
+        gamma_continued_fraction(a, x)
+    }
+}
+
+/// Series expansion of `P(a, x)`, converging quickly for `x < a + 1`.
+fn gamma_series(a: f64, x: f64) -> f64 {
+    let mut denominator = a;
+    let mut term = 1. / a;
+    let mut sum = term;
+    for _ in 0..MAX_ITERATIONS {
+        denominator += 1.;
+        term *= x / denominator;
+        sum += term;
+        if term.abs() < sum.abs() * f64::EPSILON {
+            break;
+        }
+    }
+    sum * (-x + a * x.ln() - ln_gamma(a)).exp()
+}
+
+/// Continued fraction expansion of `Q(a, x)` (modified Lentz's method),
+/// converging quickly for `x ≥ a + 1`.
+fn gamma_continued_fraction(a: f64, x: f64) -> f64 {
+    let tiny = f64::MIN_POSITIVE / f64::EPSILON;
+    let mut b = x + 1. - a;
+    let mut c = 1. / tiny;
+    let mut d = 1. / b;
+    let mut h = d;
+    for i in 1..MAX_ITERATIONS {
+        let an = -(i as f64) * (i as f64 - a);
+        b += 2.;
+        d = an * d + b;
+        if d.abs() < tiny {
+            d = tiny;
+        }
+        c = b + an / c;
+        if c.abs() < tiny {
+            c = tiny;
+        }
+        d = 1. / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.).abs() < f64::EPSILON {
+            break;
+        }
+    }
+    (-x + a * x.ln() - ln_gamma(a)).exp() * h
+}
+
+/// Survival function (i.e. `1 - CDF`) of the [χ² distribution] with
+/// `degrees_of_freedom` degrees of freedom, evaluated at `x`.
+///
+/// [χ² distribution]: https://en.wikipedia.org/wiki/Chi-squared_distribution
+pub(crate) fn chi_squared_sf(x: f64, degrees_of_freedom: f64) -> f64 {
+    regularized_gamma_q(degrees_of_freedom / 2., x / 2.)
+}
+
+/// Regularized incomplete beta function `Iₓ(a, b) = B(x; a, b) / B(a, b)`,
+/// for `a > 0`, `b > 0` and `0 ≤ x ≤ 1`.
+pub(crate) fn regularized_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x.is_nan() || a.is_nan() || b.is_nan() {
+        return f64::NAN;
+    }
+    if x <= 0. {
+        return 0.;
+    }
+    if x >= 1. {
+        return 1.;
+    }
+    let ln_front = ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1. - x).ln();
+    // The continued fraction converges rapidly for x < (a + 1) / (a + b + 2),
+    // otherwise we use the symmetry relation Iₓ(a, b) = 1 - I₁₋ₓ(b, a)
+    if x < (a + 1.) / (a + b + 2.) {
+        ln_front.exp() * beta_continued_fraction(a, b, x) / a
+    } else {
+        1. - ln_front.exp() * beta_continued_fraction(b, a, 1. - x) / b
+    }
+}
+
+/// Continued fraction expansion of the incomplete beta function
+/// (modified Lentz's method).
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    let tiny = f64::MIN_POSITIVE / f64::EPSILON;
+    let (qab, qap, qam) = (a + b, a + 1., a - 1.);
+    let mut c = 1.;
+    let mut d = 1. - qab * x / qap;
+    if d.abs() < tiny {
+        d = tiny;
+    }
+    d = 1. / d;
+    let mut h = d;
+    for m in 1..MAX_ITERATIONS {
+        let m = m as f64;
+        let m2 = 2. * m;
+        // Even step
+        let aa = m * (b - m) * x / ((qam + m2) * (a + m2));
+        d = 1. + aa * d;
+        if d.abs() < tiny {
+            d = tiny;
+        }
+        c = 1. + aa / c;
+        if c.abs() < tiny {
+            c = tiny;
+        }
+        d = 1. / d;
+        h *= d * c;
+        // Odd step
+        let aa = -(a + m) * (qab + m) * x / ((a + m2) * (qap + m2));
+        d = 1. + aa * d;
+        if d.abs() < tiny {
+            d = tiny;
+        }
+        c = 1. + aa / c;
+        if c.abs() < tiny {
+            c = tiny;
+        }
+        d = 1. / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.).abs() < f64::EPSILON {
+            break;
+        }
+    }
+    h
+}
+
+/// Two-sided p-value of the statistic `t` under a [Student's t-distribution]
+/// with `degrees_of_freedom` degrees of freedom, i.e. `P(|T| ≥ |t|)`.
+///
+/// [Student's t-distribution]: https://en.wikipedia.org/wiki/Student%27s_t-distribution
+pub(crate) fn student_t_two_sided_p_value(t: f64, degrees_of_freedom: f64) -> f64 {
+    if t.is_infinite() {
+        return 0.;
+    }
+    regularized_beta(
+        degrees_of_freedom / 2.,
+        0.5,
+        degrees_of_freedom / (degrees_of_freedom + t * t),
+    )
+}
+
+/// Complementary error function `erfc(x) = 1 - erf(x)`.
+pub(crate) fn erfc(x: f64) -> f64 {
+    if x < 0. {
+        2. - erfc(-x)
+    } else {
+        regularized_gamma_q(0.5, x * x)
+    }
+}
+
+/// Two-sided p-value of the statistic `z` under the standard
+/// [normal distribution], i.e. `P(|Z| ≥ |z|)`.
+///
+/// [normal distribution]: https://en.wikipedia.org/wiki/Normal_distribution
+pub(crate) fn normal_two_sided_p_value(z: f64) -> f64 {
+    erfc(z.abs() / f64::consts::SQRT_2)
+}
+
+/// Quantile function (i.e. inverse CDF) of the standard normal distribution,
+/// for `0 < p < 1`.
+///
+/// Uses [Acklam's rational approximation], refined with one step of Halley's method.
+///
+/// [Acklam's rational approximation]: https://web.archive.org/web/20151030215612/http://home.online.no/~pjacklam/notes/invnorm/
+pub(crate) fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const P_LOW: f64 = 0.024_25;
+
+    if p.is_nan() || p <= 0. || p >= 1. {
+        return match p {
+            0. => f64::NEG_INFINITY,
+            1. => f64::INFINITY,
+            _ => f64::NAN,
+        };
+    }
+    let x = if p < P_LOW {
+        let q = (-2. * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.)
+    } else if p <= 1. - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.)
+    } else {
+        let q = (-2. * (1. - p).ln()).sqrt();
+        -(((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.)
+    };
+    // Halley's refinement step
+    let error = 0.5 * erfc(-x / f64::consts::SQRT_2) - p;
+    let u = error * (2. * f64::consts::PI).sqrt() * (x * x / 2.).exp();
+    x - u / (1. + x * u / 2.)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_ln_gamma() {
+        assert_abs_diff_eq!(ln_gamma(1.), 0., epsilon = 1e-13);
+        assert_abs_diff_eq!(ln_gamma(2.), 0., epsilon = 1e-13);
+        assert_abs_diff_eq!(ln_gamma(5.), 24f64.ln(), epsilon = 1e-12);
+        assert_abs_diff_eq!(ln_gamma(0.5), f64::consts::PI.sqrt().ln(), epsilon = 1e-13);
+        assert_abs_diff_eq!(ln_gamma(0.1), 2.252712651734206, epsilon = 1e-12);
+        assert_abs_diff_eq!(ln_gamma(100.), 359.1342053695754, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_digamma() {
+        let euler_gamma = 0.577_215_664_901_533;
+        assert_abs_diff_eq!(digamma(1.), -euler_gamma, epsilon = 1e-13);
+        assert_abs_diff_eq!(digamma(0.5), -euler_gamma - 2. * 2f64.ln(), epsilon = 1e-13);
+        assert_abs_diff_eq!(digamma(10.), 2.251752589066721, epsilon = 1e-13);
+        assert_abs_diff_eq!(digamma(123.4), 4.811373775116277, epsilon = 1e-12);
+        for &x in &[0.3f64, 2.7, 9.] {
+            assert_abs_diff_eq!(digamma(x + 1.), digamma(x) + 1. / x, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_regularized_gamma() {
+        for &x in &[0.1f64, 1., 2.5, 4.2, 10.] {
+            // Q(1, x) = e⁻ˣ
+            assert_abs_diff_eq!(regularized_gamma_q(1., x), (-x).exp(), epsilon = 1e-14);
+            // Q(3, x) = e⁻ˣ (1 + x + x²/2)
+            assert_abs_diff_eq!(
+                regularized_gamma_q(3., x),
+                (-x).exp() * (1. + x + x * x / 2.),
+                epsilon = 1e-14
+            );
+        }
+        assert_eq!(regularized_gamma_q(2., 0.), 1.);
+    }
+
+    #[test]
+    fn test_chi_squared_sf() {
+        // With 2 degrees of freedom, the survival function is e^(-x/2)
+        assert_abs_diff_eq!(chi_squared_sf(3., 2.), (-1.5f64).exp(), epsilon = 1e-14);
+        // 95% critical values of the χ² distribution with 1 and 10 degrees of freedom
+        assert_abs_diff_eq!(chi_squared_sf(3.841458820694124, 1.), 0.05, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            chi_squared_sf(18.307038053275146, 10.),
+            0.05,
+            epsilon = 1e-12
+        );
+        assert!(chi_squared_sf(f64::NAN, 3.).is_nan());
+    }
+
+    #[test]
+    fn test_regularized_beta() {
+        // I_x(1, 1) = x and I_x(a, 1) = xᵃ
+        for &x in &[0.1f64, 0.5, 0.73] {
+            assert_abs_diff_eq!(regularized_beta(1., 1., x), x, epsilon = 1e-14);
+            assert_abs_diff_eq!(regularized_beta(3.5, 1., x), x.powf(3.5), epsilon = 1e-14);
+            assert_abs_diff_eq!(
+                regularized_beta(2., 5., x) + regularized_beta(5., 2., 1. - x),
+                1.,
+                epsilon = 1e-14
+            );
+        }
+        assert_eq!(regularized_beta(2., 3., 0.), 0.);
+        assert_eq!(regularized_beta(2., 3., 1.), 1.);
+    }
+
+    #[test]
+    fn test_student_t_two_sided_p_value() {
+        for &t in &[0.3f64, 1., 2.5, -4.] {
+            // With 1 degree of freedom, t is Cauchy-distributed
+            let cauchy = 1. - 2. * t.abs().atan() / f64::consts::PI;
+            assert_abs_diff_eq!(student_t_two_sided_p_value(t, 1.), cauchy, epsilon = 1e-13);
+            // With 2 degrees of freedom, P(|T| ≥ t) = 1 - t / √(2 + t²)
+            let expected = 1. - t.abs() / (2. + t * t).sqrt();
+            assert_abs_diff_eq!(
+                student_t_two_sided_p_value(t, 2.),
+                expected,
+                epsilon = 1e-13
+            );
+        }
+        assert_eq!(student_t_two_sided_p_value(0., 7.), 1.);
+        assert_eq!(student_t_two_sided_p_value(f64::INFINITY, 7.), 0.);
+    }
+
+    #[test]
+    fn test_normal_distribution() {
+        assert_abs_diff_eq!(erfc(0.), 1., epsilon = 1e-15);
+        assert_abs_diff_eq!(erfc(0.5), 0.4795001221869535, epsilon = 1e-14);
+        assert_abs_diff_eq!(erfc(-1.), 1.8427007929497148, epsilon = 1e-14);
+        assert_abs_diff_eq!(
+            normal_two_sided_p_value(1.959963984540054),
+            0.05,
+            epsilon = 1e-14
+        );
+        assert_abs_diff_eq!(normal_quantile(0.975), 1.959963984540054, epsilon = 1e-14);
+        assert_abs_diff_eq!(normal_quantile(0.5), 0., epsilon = 1e-15);
+        assert_abs_diff_eq!(normal_quantile(1e-10), -6.361340902404056, epsilon = 1e-9);
+        for &p in &[0.001, 0.02, 0.3, 0.9, 0.999] {
+            let x = normal_quantile(p);
+            assert_abs_diff_eq!(0.5 * erfc(-x / f64::consts::SQRT_2), p, epsilon = 1e-15);
+        }
+        assert_eq!(normal_quantile(1.), f64::INFINITY);
+        assert!(normal_quantile(1.5).is_nan());
+    }
+}
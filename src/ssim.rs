@@ -0,0 +1,573 @@
+use crate::errors::MultiInputError;
+use ndarray::prelude::*;
+use ndarray::Data;
+use num_traits::{Float, FromPrimitive};
+
+/// The weights of the five scales of [`ms_ssim`], as estimated by Wang et al.
+///
+/// [`ms_ssim`]: trait.StructuralSimilarityExt.html#tymethod.ms_ssim
+const MS_SSIM_WEIGHTS: [f64; 5] = [0.0448, 0.2856, 0.3001, 0.2363, 0.1333];
+
+/// The sliding window over which the local statistics of [`StructuralSimilarityExt`]
+/// are computed.
+///
+/// [`StructuralSimilarityExt`]: trait.StructuralSimilarityExt.html
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SsimWindow<A> {
+    /// A square window of side `size` with Gaussian weights of standard deviation `sigma`
+    /// (in pixels), centered on the window.
+    Gaussian { size: usize, sigma: A },
+    /// A square window of the given side with equal weights.
+    Uniform(usize),
+}
+
+/// The parameters of the structural similarity index.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SsimParams<A> {
+    /// The sliding window.
+    pub window: SsimWindow<A>,
+    /// The dynamic range `L` of the pixel values, e.g. 255 for 8-bit images.
+    pub data_range: A,
+    /// The constant `K₁` stabilising the luminance term, `C₁ = (K₁ L)²`.
+    pub k1: A,
+    /// The constant `K₂` stabilising the contrast-structure term, `C₂ = (K₂ L)²`.
+    pub k2: A,
+}
+
+impl<A> SsimParams<A>
+where
+    A: Float + FromPrimitive,
+{
+    /// Returns the parameters of Wang et al. for the given dynamic range: an 11×11
+    /// Gaussian window with a standard deviation of 1.5, `K₁ = 0.01` and `K₂ = 0.03`.
+    ///
+    /// **Panics** if the type cast from `f64` to `A` fails.
+    pub fn new(data_range: A) -> Self {
+        SsimParams {
+            window: SsimWindow::Gaussian {
+                size: 11,
+                sigma: A::from_f64(1.5).unwrap(),
+            },
+            data_range,
+            k1: A::from_f64(0.01).unwrap(),
+            k2: A::from_f64(0.03).unwrap(),
+        }
+    }
+}
+
+/// The result of [`ssim`](trait.StructuralSimilarityExt.html#tymethod.ssim).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ssim<A> {
+    /// The mean of the SSIM map over the pixels whose window fits entirely within the
+    /// arrays, i.e. without the borders of `map` computed from reflected pixels.
+    pub mean: A,
+    /// The SSIM of each pixel, with the same shape as the arrays.
+    ///
+    /// For a window of side `k`, the SSIM of pixel `(i, j)` is computed over the rows
+    /// `i - (k - 1) / 2` to `i + k / 2` and the columns `j - (k - 1) / 2` to `j + k / 2`
+    /// (integer divisions, extremes included). Near the borders, where the window does
+    /// not fit within the arrays, they are extended by reflecting them about their
+    /// edges (`c b a | a b c | c b a`).
+    pub map: Array2<A>,
+}
+
+/// Extension trait for 2-dimensional arrays (e.g. grayscale images) providing
+/// functions to compute the [structural similarity index] (SSIM) with
+/// another array of the same shape.
+///
+/// [structural similarity index]: https://en.wikipedia.org/wiki/Structural_similarity
+pub trait StructuralSimilarityExt<A, S>
+where
+    S: Data<Elem = A>,
+{
+    /// Computes the structural similarity index between `self` and `other`.
+    ///
+    /// For each position of the sliding window, the weighted means `μx` and `μy`,
+    /// variances `σx²` and `σy²` and covariance `σxy` of the pixels `x` of `self` and `y`
+    /// of `other` within the window give:
+    ///
+    /// ```text
+    ///         (2 μx μy + C₁) (2 σxy + C₂)
+    /// SSIM = ――――――――――――――――――――――――――――――
+    ///        (μx² + μy² + C₁) (σx² + σy² + C₂)
+    /// ```
+    ///
+    /// with the constants `C₁` and `C₂` given by `params` (see [`SsimParams`]).
+    /// The window is centered on each pixel to give the SSIM map, of the same shape as
+    /// the arrays, while the mean SSIM only considers the pixels whose window fits
+    /// entirely within the arrays (see [`Ssim`]).
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if the window is empty or larger than the arrays along any axis,
+    /// if the standard deviation of a Gaussian window is not positive, or if the type
+    /// cast of the window side from `usize` to `A` fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use ndarray::Array2;
+    /// use ndarray_stats::{SsimParams, StructuralSimilarityExt};
+    ///
+    /// let image = Array2::from_shape_fn((16, 16), |(i, j)| ((i * j) % 7) as f64);
+    /// let params = SsimParams::new(6.);
+    /// let ssim = image.ssim(&image, &params).unwrap();
+    /// assert_eq!(ssim.map.dim(), (16, 16));
+    /// assert_abs_diff_eq!(ssim.mean, 1.);
+    ///
+    /// let brighter = &image + 1.;
+    /// assert!(image.ssim(&brighter, &params).unwrap().mean < 1.);
+    /// ```
+    ///
+    /// [`SsimParams`]: struct.SsimParams.html
+    /// [`Ssim`]: struct.Ssim.html
+    fn ssim<T>(
+        &self,
+        other: &ArrayBase<T, Ix2>,
+        params: &SsimParams<A>,
+    ) -> Result<Ssim<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>;
+
+    /// Computes the [multi-scale structural similarity index][ms-ssim] between
+    /// `self` and `other`.
+    ///
+    /// The arrays are repeatedly downsampled by averaging 2×2 blocks (dropping the last
+    /// row or column if their number is odd), giving `M` scales, where `M` is the length
+    /// of `weights`. Writing `cs` for the mean of the contrast-structure term of [`ssim`]
+    ///
+    /// ```text
+    ///        2 σxy + C₂
+    /// cs = ――――――――――――――
+    ///      σx² + σy² + C₂
+    /// ```
+    ///
+    /// the index is
+    ///
+    /// ```text
+    /// MS-SSIM = SSIMᴹ^wᴹ · ∏ⱼ₌₁ᴹ⁻¹ csⱼ^wⱼ
+    /// ```
+    ///
+    /// where `SSIMᴹ` is the mean SSIM at the coarsest scale. Negative values of `csⱼ` and
+    /// `SSIMᴹ` are clamped to zero. If `weights` is `None`, the five weights estimated by
+    /// Wang et al. are used, `[0.0448, 0.2856, 0.3001, 0.2363, 0.1333]`.
+    ///
+    /// The following **errors** may be returned:
+    ///
+    /// * `MultiInputError::EmptyInput` if `self` is empty
+    /// * `MultiInputError::ShapeMismatch` if `self` and `other` don't have the same shape
+    ///
+    /// **Panics** if `weights` is empty, if the window is empty or larger than the arrays
+    /// at the coarsest scale, if the standard deviation of a Gaussian window is not positive,
+    /// or if any type cast to `A` fails.
+    ///
+    /// [ms-ssim]: https://doi.org/10.1109/ACSSC.2003.1292216
+    /// [`ssim`]: #tymethod.ssim
+    fn ms_ssim<T>(
+        &self,
+        other: &ArrayBase<T, Ix2>,
+        params: &SsimParams<A>,
+        weights: Option<&[A]>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>;
+
+    private_decl! {}
+}
+
+impl<A, S> StructuralSimilarityExt<A, S> for ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    fn ssim<T>(
+        &self,
+        other: &ArrayBase<T, Ix2>,
+        params: &SsimParams<A>,
+    ) -> Result<Ssim<A>, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, other);
+
+        let kernel = window_weights(&params.window);
+        let k = kernel.len();
+        let (m, n) = self.dim();
+        assert!(
+            k <= m && k <= n,
+            "The window must not be larger than the arrays."
+        );
+        // Padding the arrays so that the window can be centered on every pixel
+        let (before, after) = ((k - 1) / 2, k / 2);
+        let (map, _) = local_similarity(
+            reflect_pad(self.view(), before, after).view(),
+            reflect_pad(other.view(), before, after).view(),
+            &kernel,
+            params,
+        );
+        let mean = map
+            .slice(s![before..m - after, before..n - after])
+            .mean()
+            .unwrap();
+        Ok(Ssim { mean, map })
+    }
+
+    fn ms_ssim<T>(
+        &self,
+        other: &ArrayBase<T, Ix2>,
+        params: &SsimParams<A>,
+        weights: Option<&[A]>,
+    ) -> Result<A, MultiInputError>
+    where
+        A: Float + FromPrimitive,
+        T: Data<Elem = A>,
+    {
+        return_err_if_empty!(self);
+        return_err_unless_same_shape!(self, other);
+
+        let default_weights: Vec<A>;
+        let weights = match weights {
+            Some(weights) => weights,
+            None => {
+                default_weights = MS_SSIM_WEIGHTS
+                    .iter()
+                    .map(|&w| A::from_f64(w).unwrap())
+                    .collect();
+                &default_weights
+            }
+        };
+        assert!(!weights.is_empty(), "`weights` must not be empty.");
+
+        let kernel = window_weights(&params.window);
+        let mut x = self.to_owned();
+        let mut y = other.to_owned();
+        let mut index = A::one();
+        for (scale, &weight) in weights.iter().enumerate() {
+            if scale > 0 {
+                x = downsample(&x);
+                y = downsample(&y);
+            }
+            let (ssim, cs) = local_similarity(x.view(), y.view(), &kernel, params);
+            let value = if scale + 1 == weights.len() {
+                ssim.mean().unwrap()
+            } else {
+                cs.mean().unwrap()
+            };
+            index = index * value.max(A::zero()).powf(weight);
+        }
+        Ok(index)
+    }
+
+    private_impl! {}
+}
+
+/// Returns the normalised one-dimensional weights of the window, whose outer product
+/// with themselves gives the two-dimensional weights.
+fn window_weights<A>(window: &SsimWindow<A>) -> Vec<A>
+where
+    A: Float + FromPrimitive,
+{
+    let weights: Vec<A> = match *window {
+        SsimWindow::Gaussian { size, sigma } => {
+            assert!(sigma > A::zero(), "`sigma` must be positive.");
+            let center = A::from_usize(size).unwrap() - A::one();
+            let two = A::one() + A::one();
+            (0..size)
+                .map(|i| {
+                    let d = two * A::from_usize(i).unwrap() - center;
+                    // exp(-(i - c)² / 2σ²) with c = (size - 1) / 2
+                    (-(d * d) / (two * two * two * sigma * sigma)).exp()
+                })
+                .collect()
+        }
+        SsimWindow::Uniform(size) => vec![A::one(); size],
+    };
+    assert!(!weights.is_empty(), "The window must not be empty.");
+    let total = weights.iter().fold(A::zero(), |acc, &w| acc + w);
+    weights.into_iter().map(|w| w / total).collect()
+}
+
+/// Returns the weighted sums of `image` over all the positions of the separable window
+/// with one-dimensional weights `kernel` that fit within `image`.
+fn filter<A>(image: &Array2<A>, kernel: &[A]) -> Array2<A>
+where
+    A: Float,
+{
+    let k = kernel.len();
+    let (m, n) = image.dim();
+    assert!(
+        k <= m && k <= n,
+        "The window must not be larger than the arrays."
+    );
+    let rows = Array2::from_shape_fn((m, n - k + 1), |(i, j)| {
+        kernel
+            .iter()
+            .enumerate()
+            .fold(A::zero(), |acc, (t, &w)| acc + w * image[(i, j + t)])
+    });
+    Array2::from_shape_fn((m - k + 1, n - k + 1), |(i, j)| {
+        kernel
+            .iter()
+            .enumerate()
+            .fold(A::zero(), |acc, (t, &w)| acc + w * rows[(i + t, j)])
+    })
+}
+
+/// Returns the SSIM map and the contrast-structure map of `x` and `y`.
+fn local_similarity<A>(
+    x: ArrayView2<A>,
+    y: ArrayView2<A>,
+    kernel: &[A],
+    params: &SsimParams<A>,
+) -> (Array2<A>, Array2<A>)
+where
+    A: Float,
+{
+    let c1 = (params.k1 * params.data_range).powi(2);
+    let c2 = (params.k2 * params.data_range).powi(2);
+    let two = A::one() + A::one();
+
+    let mean_x = filter(&x.to_owned(), kernel);
+    let mean_y = filter(&y.to_owned(), kernel);
+    let mean_xx = filter(&(&x * &x), kernel);
+    let mean_yy = filter(&(&y * &y), kernel);
+    let mean_xy = filter(&(&x * &y), kernel);
+
+    let mut cs = Array2::zeros(mean_x.raw_dim());
+    let mut ssim = Array2::zeros(mean_x.raw_dim());
+    for (index, cs) in cs.indexed_iter_mut() {
+        let (mx, my) = (mean_x[index], mean_y[index]);
+        let var_x = mean_xx[index] - mx * mx;
+        let var_y = mean_yy[index] - my * my;
+        let cov = mean_xy[index] - mx * my;
+        *cs = (two * cov + c2) / (var_x + var_y + c2);
+        ssim[index] = (two * mx * my + c1) / (mx * mx + my * my + c1) * *cs;
+    }
+    (ssim, cs)
+}
+
+/// Extends `image` by reflecting `before` rows (columns) about its first row (column)
+/// and `after` rows (columns) about its last one, including the edges themselves.
+///
+/// **Panics** if `before` or `after` is larger than the number of rows or columns.
+fn reflect_pad<A>(image: ArrayView2<A>, before: usize, after: usize) -> Array2<A>
+where
+    A: Clone,
+{
+    let (m, n) = image.dim();
+    assert!(before <= m.min(n) && after <= m.min(n));
+    // Maps an index of the padded array to an index of `image` along an axis of length `len`
+    let reflect = |index: usize, len: usize| {
+        if index < before {
+            before - 1 - index
+        } else if index < before + len {
+            index - before
+        } else {
+            2 * len + before - 1 - index
+        }
+    };
+    Array2::from_shape_fn((m + before + after, n + before + after), |(i, j)| {
+        image[(reflect(i, m), reflect(j, n))].clone()
+    })
+}
+
+/// Averages the 2×2 blocks of `image`, dropping its last row (column) if the number of
+/// rows (columns) is odd.
+fn downsample<A>(image: &Array2<A>) -> Array2<A>
+where
+    A: Float,
+{
+    let (m, n) = image.dim();
+    let four = A::one() + A::one() + A::one() + A::one();
+    Array2::from_shape_fn((m / 2, n / 2), |(i, j)| {
+        (image[(2 * i, 2 * j)]
+            + image[(2 * i + 1, 2 * j)]
+            + image[(2 * i, 2 * j + 1)]
+            + image[(2 * i + 1, 2 * j + 1)])
+            / four
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use ndarray::array;
+
+    fn image(shape: (usize, usize)) -> Array2<f64> {
+        Array2::from_shape_fn(shape, |(i, j)| {
+            ((i * 7 + j * 13) % 17) as f64 + (i as f64 / 3.).sin() * 4.
+        })
+    }
+
+    #[test]
+    fn test_ssim_single_window() {
+        let x = array![[1., 2.], [3., 4.]];
+        let y = array![[2., 2.], [3., 5.]];
+        let params = SsimParams {
+            window: SsimWindow::Uniform(2),
+            ..SsimParams::new(4.)
+        };
+        // μx = 2.5, μy = 3, σx² = 1.25, σy² = 1.5, σxy = 1.25, C₁ = 0.0016, C₂ = 0.0144
+        let expected = (15.0016 * 2.5144) / (15.2516 * 2.7644);
+        let ssim = x.ssim(&y, &params).unwrap();
+        assert_eq!(ssim.map.dim(), (2, 2));
+        assert_abs_diff_eq!(ssim.map[(0, 0)], expected, epsilon = 1e-12);
+        assert_abs_diff_eq!(ssim.mean, expected, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_ssim_map_matches_single_windows() {
+        let x = image((9, 12));
+        let y = x.mapv(|v| (v * 1.3 - 2.).round());
+        let params = SsimParams {
+            window: SsimWindow::Gaussian {
+                size: 5,
+                sigma: 1.2,
+            },
+            ..SsimParams::new(20.)
+        };
+        let ssim = x.ssim(&y, &params).unwrap();
+        assert_eq!(ssim.map.dim(), (9, 12));
+        let interior = ssim.map.slice(s![2..7, 2..10]);
+        for ((i, j), &value) in interior.indexed_iter() {
+            let window = s![i..i + 5, j..j + 5];
+            let single = x.slice(window).ssim(&y.slice(window), &params).unwrap();
+            assert_abs_diff_eq!(single.mean, value, epsilon = 1e-12);
+        }
+        assert_abs_diff_eq!(ssim.mean, interior.mean().unwrap(), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_ssim_properties() {
+        let x = image((20, 20));
+        let y = x.mapv(|v| v + ((v * 3.) % 5.) - 2.);
+        let params = SsimParams::new(20.);
+        let identical = x.ssim(&x, &params).unwrap();
+        assert_abs_diff_eq!(identical.map, Array2::ones((20, 20)), epsilon = 1e-12);
+        let xy = x.ssim(&y, &params).unwrap();
+        let yx = y.ssim(&x, &params).unwrap();
+        assert_abs_diff_eq!(xy.map, yx.map, epsilon = 1e-12);
+        assert!(xy.mean < 1.);
+        assert!(xy.map.iter().all(|v| (-1. ..=1.).contains(v)));
+        // Inverting the contrast gives a negative structural similarity
+        let inverted = x.mapv(|v| 20. - v);
+        assert!(x.ssim(&inverted, &params).unwrap().mean < 0.);
+    }
+
+    #[test]
+    fn test_ssim_map_reflects_borders() {
+        let x = image((6, 7));
+        let y = x.mapv(|v| v * 0.8 + 1.);
+        let params = SsimParams {
+            window: SsimWindow::Uniform(3),
+            ..SsimParams::new(20.)
+        };
+        let ssim = x.ssim(&y, &params).unwrap();
+        // The window centered on the corner (5, 0) covers the rows 4, 5, 5
+        // and the columns 0, 0, 1
+        let corner = |a: &Array2<f64>| a.select(Axis(0), &[4, 5, 5]).select(Axis(1), &[0, 0, 1]);
+        let single = corner(&x).ssim(&corner(&y), &params).unwrap();
+        assert_abs_diff_eq!(ssim.map[(5, 0)], single.mean, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_reflect_pad() {
+        let x = array![[1, 2, 3], [4, 5, 6]];
+        assert_eq!(
+            reflect_pad(x.view(), 1, 2),
+            array![
+                [1, 1, 2, 3, 3, 2],
+                [1, 1, 2, 3, 3, 2],
+                [4, 4, 5, 6, 6, 5],
+                [4, 4, 5, 6, 6, 5],
+                [1, 1, 2, 3, 3, 2]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ms_ssim() {
+        let x = image((48, 48));
+        let params = SsimParams {
+            window: SsimWindow::Uniform(3),
+            ..SsimParams::new(20.)
+        };
+        assert_abs_diff_eq!(x.ms_ssim(&x, &params, None).unwrap(), 1., epsilon = 1e-12);
+
+        let y = x.mapv(|v| v + ((v * 3.) % 5.) - 2.);
+        let ms_ssim = x.ms_ssim(&y, &params, None).unwrap();
+        assert!(ms_ssim > 0. && ms_ssim < 1.);
+        // A single scale gives the SSIM
+        assert_abs_diff_eq!(
+            x.ms_ssim(&y, &params, Some(&[1.])).unwrap(),
+            x.ssim(&y, &params).unwrap().mean,
+            epsilon = 1e-12
+        );
+        // Two scales: the contrast-structure term at full resolution
+        // and the SSIM at half resolution
+        let (_, cs) = local_similarity(x.view(), y.view(), &[1. / 3.; 3], &params);
+        let coarse = downsample(&x).ssim(&downsample(&y), &params).unwrap();
+        assert_abs_diff_eq!(
+            x.ms_ssim(&y, &params, Some(&[0.4, 0.6])).unwrap(),
+            cs.mean().unwrap().powf(0.4) * coarse.mean.powf(0.6),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_downsample() {
+        let x = array![
+            [1., 2., 3., 4., 5.],
+            [3., 4., 5., 6., 7.],
+            [0., 0., 0., 0., 0.]
+        ];
+        assert_eq!(downsample(&x), array![[2.5, 4.5]]);
+    }
+
+    #[test]
+    fn test_gaussian_window_weights() {
+        let weights = window_weights(&SsimWindow::Gaussian { size: 3, sigma: 1. });
+        let e = (-0.5f64).exp();
+        assert_abs_diff_eq!(
+            &weights[..],
+            &[e / (1. + 2. * e), 1. / (1. + 2. * e), e / (1. + 2. * e)][..],
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_window_larger_than_arrays_panics() {
+        let x = image((10, 20));
+        let _ = x.ssim(&x, &SsimParams::new(20.));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ms_ssim_window_larger_than_coarsest_scale_panics() {
+        let x = image((40, 40));
+        let _ = x.ms_ssim(&x, &SsimParams::new(20.), None);
+    }
+
+    #[test]
+    fn test_errors() {
+        let params = SsimParams::new(1.);
+        let empty = Array2::<f64>::zeros((0, 3));
+        assert_eq!(
+            empty.ssim(&empty, &params),
+            Err(MultiInputError::EmptyInput)
+        );
+        assert!(image((12, 12))
+            .ms_ssim(&image((12, 13)), &params, None)
+            .unwrap_err()
+            .is_shape_mismatch());
+    }
+}
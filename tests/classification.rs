@@ -0,0 +1,281 @@
+use ndarray_stats::errors::{MultiInputError, ShapeMismatch};
+use ndarray_stats::{Average, ClassificationExt, ConfusionMatrix, PrecisionRecallCurve, RocCurve};
+
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1};
+
+use std::f64;
+
+fn animals() -> ConfusionMatrix<&'static str> {
+    let truth = array!["cat", "dog", "cat", "bird", "dog", "cat", "bird", "cat"];
+    let prediction = array!["cat", "cat", "cat", "bird", "dog", "dog", "cat", "cat"];
+    prediction.confusion_matrix(&truth).unwrap()
+}
+
+#[test]
+fn test_confusion_matrix() {
+    let confusion = animals();
+    assert_eq!(
+        confusion.labels().keys().cloned().collect::<Vec<_>>(),
+        vec!["cat", "dog", "bird"]
+    );
+    assert_eq!(confusion.counts(), array![[3, 1, 0], [1, 1, 0], [1, 0, 1]]);
+    assert_eq!(confusion.get(&"cat", &"dog"), 1);
+    assert_eq!(confusion.get(&"bird", &"dog"), 0);
+    assert_eq!(confusion.get(&"fish", &"dog"), 0);
+    assert_eq!(confusion.n_samples(), 8);
+    assert_eq!(confusion.support(), array![4, 2, 2]);
+}
+
+#[test]
+fn test_labels_only_predicted_come_last() {
+    let truth = array![[1, 2], [2, 1]];
+    let prediction = array![[3, 2], [2, 1]];
+    let confusion = prediction.confusion_matrix(&truth).unwrap();
+    assert_eq!(
+        confusion.labels().keys().cloned().collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    assert_eq!(confusion.support(), array![2, 2, 0]);
+    assert_eq!(confusion.precision_per_class(), array![1., 1., 0.]);
+    assert_eq!(confusion.recall_per_class(), array![0.5, 1., 0.]);
+    // The class that never appears among the true labels is ignored
+    assert_eq!(confusion.balanced_accuracy(), 0.75);
+}
+
+#[test]
+fn test_precision_recall_f_score() {
+    let confusion = animals();
+    assert_abs_diff_eq!(confusion.accuracy(), 0.625);
+    assert_abs_diff_eq!(
+        confusion.precision_per_class(),
+        array![0.6, 0.5, 1.],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        confusion.recall_per_class(),
+        array![0.75, 0.5, 0.5],
+        epsilon = 1e-12
+    );
+
+    for &average in &[Average::Micro, Average::Macro, Average::Weighted] {
+        let expected = match average {
+            Average::Micro => (0.625, 0.625, 0.625),
+            Average::Macro => (0.7, 0.5833333333333334, 0.611111111111111),
+            Average::Weighted => (0.675, 0.625, 0.625),
+        };
+        assert_abs_diff_eq!(confusion.precision(average), expected.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(confusion.recall(average), expected.1, epsilon = 1e-12);
+        assert_abs_diff_eq!(confusion.f1_score(average), expected.2, epsilon = 1e-12);
+    }
+    assert_abs_diff_eq!(
+        confusion.f_beta_score(2., Average::Macro),
+        0.58994708994709,
+        epsilon = 1e-12
+    );
+    // β = 0 gives the precision
+    assert_abs_diff_eq!(
+        confusion.f_beta_score_per_class(0.),
+        confusion.precision_per_class(),
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_balanced_accuracy() {
+    assert_abs_diff_eq!(
+        animals().balanced_accuracy(),
+        0.5833333333333334,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_matthews_corrcoef_and_cohen_kappa() {
+    let confusion = animals();
+    assert_abs_diff_eq!(
+        confusion.matthews_corrcoef(),
+        0.3796283011826483,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(confusion.cohen_kappa(), 0.3684210526315789, epsilon = 1e-12);
+
+    let labels = array![true, false, true, true];
+    let perfect = labels.confusion_matrix(&labels).unwrap();
+    assert_abs_diff_eq!(perfect.matthews_corrcoef(), 1.);
+    assert_abs_diff_eq!(perfect.cohen_kappa(), 1.);
+    let inverted = labels.mapv(|l| !l).confusion_matrix(&labels).unwrap();
+    assert_abs_diff_eq!(inverted.matthews_corrcoef(), -1.);
+
+    // A constant classifier
+    let constant = Array1::from_elem(4, true)
+        .confusion_matrix(&labels)
+        .unwrap();
+    assert_eq!(constant.matthews_corrcoef(), 0.);
+    assert_eq!(constant.cohen_kappa(), 0.);
+}
+
+#[test]
+#[should_panic]
+fn test_negative_beta_panics() {
+    let _ = animals().f_beta_score(-1., Average::Macro);
+}
+
+#[test]
+fn test_confusion_matrix_errors() {
+    let a: Array1<i32> = array![];
+    let b = array![1];
+    assert_eq!(a.confusion_matrix(&b), Err(MultiInputError::EmptyInput));
+    assert_eq!(
+        b.confusion_matrix(&a),
+        Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+            first_shape: vec![1],
+            second_shape: vec![0],
+        }))
+    );
+}
+
+#[test]
+fn test_roc_curve() {
+    let labels = array![false, false, true, true];
+    let scores = array![0.1, 0.4, 0.35, 0.8];
+    assert_eq!(
+        scores.roc_curve(&labels, None).unwrap(),
+        RocCurve {
+            false_positive_rate: array![0., 0., 0.5, 0.5, 1.],
+            true_positive_rate: array![0., 0.5, 0.5, 1., 1.],
+            thresholds: array![f64::INFINITY, 0.8, 0.4, 0.35, 0.1],
+        }
+    );
+    assert_eq!(scores.roc_auc_score(&labels, None).unwrap(), 0.75);
+}
+
+#[test]
+fn test_precision_recall_curve() {
+    let labels = array![false, false, true, true];
+    let scores = array![0.1, 0.4, 0.35, 0.8];
+    let curve = scores.precision_recall_curve(&labels, None).unwrap();
+    assert_abs_diff_eq!(
+        curve.precision,
+        array![1., 1., 0.5, 2. / 3., 0.5],
+        epsilon = 1e-12
+    );
+    assert_eq!(curve.recall, array![0., 0.5, 0.5, 1., 1.]);
+    assert_eq!(curve.thresholds, array![f64::INFINITY, 0.8, 0.4, 0.35, 0.1]);
+    assert_abs_diff_eq!(
+        scores.average_precision_score(&labels, None).unwrap(),
+        5. / 6.,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_curves_with_tied_scores() {
+    let labels = array![false, true, true, false, true];
+    let scores = array![0.5, 0.5, 0.9, 0.2, 0.2];
+    let roc = scores.roc_curve(&labels, None).unwrap();
+    assert_eq!(roc.thresholds, array![f64::INFINITY, 0.9, 0.5, 0.2]);
+    assert_eq!(roc.false_positive_rate, array![0., 0., 0.5, 1.]);
+    assert_abs_diff_eq!(
+        roc.true_positive_rate,
+        array![0., 1. / 3., 2. / 3., 1.],
+        epsilon = 1e-12
+    );
+    // Fraction of (positive, negative) pairs correctly ordered, ties counting for one half
+    assert_abs_diff_eq!(
+        scores.roc_auc_score(&labels, None).unwrap(),
+        4. / 6.,
+        epsilon = 1e-12
+    );
+    let curve = scores.precision_recall_curve(&labels, None).unwrap();
+    assert_abs_diff_eq!(
+        curve.precision,
+        array![1., 1., 2. / 3., 0.6],
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        scores.average_precision_score(&labels, None).unwrap(),
+        (1. + 2. / 3. + 0.6) / 3.,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_curves_with_sample_weights() {
+    let labels = array![false, false, true, true, true];
+    let scores = array![0.1, 0.4, 0.35, 0.8, 0.3];
+    let weights = array![2., 1., 1., 1., 0.];
+    // Integer weights are equivalent to repeating the samples
+    let repeated_labels = array![false, false, false, true, true];
+    let repeated_scores = array![0.1, 0.1, 0.4, 0.35, 0.8];
+
+    let roc = scores.roc_curve(&labels, Some(weights.view())).unwrap();
+    assert_eq!(
+        roc.thresholds,
+        array![f64::INFINITY, 0.8, 0.4, 0.35, 0.3, 0.1]
+    );
+    assert_eq!(
+        roc.false_positive_rate,
+        array![0., 0., 1. / 3., 1. / 3., 1. / 3., 1.]
+    );
+    assert_eq!(
+        scores.roc_auc_score(&labels, Some(weights.view())),
+        repeated_scores.roc_auc_score(&repeated_labels, None)
+    );
+    assert_abs_diff_eq!(
+        scores
+            .average_precision_score(&labels, Some(weights.view()))
+            .unwrap(),
+        repeated_scores
+            .average_precision_score(&repeated_labels, None)
+            .unwrap(),
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        scores.roc_auc_score(&labels, Some(weights.view())).unwrap(),
+        5. / 6.,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_curves_without_positives() {
+    let labels = array![false, false];
+    let scores = array![0.3_f64, 0.7];
+    let roc = scores.roc_curve(&labels, None).unwrap();
+    assert!(roc.true_positive_rate.iter().all(|x: &f64| x.is_nan()));
+    assert!(scores.roc_auc_score(&labels, None).unwrap().is_nan());
+    let curve: PrecisionRecallCurve<f64> = scores.precision_recall_curve(&labels, None).unwrap();
+    assert_eq!(curve.precision, array![1., 0., 0.]);
+}
+
+#[test]
+#[should_panic]
+fn test_nan_score_panics() {
+    let _ = array![0.3, f64::NAN].roc_curve(&array![true, false], None);
+}
+
+#[test]
+fn test_curves_errors() {
+    let labels = array![true, false];
+    let scores = array![0.3, 0.7];
+    let empty: Array1<f64> = array![];
+    assert_eq!(
+        empty.roc_curve(&array![], None),
+        Err(MultiInputError::EmptyInput)
+    );
+    assert_eq!(
+        scores.average_precision_score(&array![true], None),
+        Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+            first_shape: vec![2],
+            second_shape: vec![1],
+        }))
+    );
+    assert_eq!(
+        scores.roc_auc_score(&labels, Some(array![1.].view())),
+        Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+            first_shape: vec![2],
+            second_shape: vec![1],
+        }))
+    );
+}
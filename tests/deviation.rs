@@ -1,12 +1,13 @@
 use ndarray_stats::errors::{MultiInputError, ShapeMismatch};
-use ndarray_stats::DeviationExt;
+use ndarray_stats::{DeviationExt, Mismatch, Tolerance};
 
 use approx::assert_abs_diff_eq;
-use ndarray::{array, Array1};
+use ndarray::{array, Array1, Array2, Array3, Axis};
 use num_bigint::BigInt;
 use num_traits::Float;
 
 use std::f64;
+use std::u64;
 
 #[test]
 fn test_deviation_computation_for_mixed_ownership() {
@@ -24,6 +25,8 @@ fn test_deviation_computation_for_mixed_ownership() {
     let _ = a.mean_sq_err(&b.view());
     let _ = a.root_mean_sq_err(&b.view());
     let _ = a.peak_signal_to_noise_ratio(&b.view(), 10.);
+    let _ = a.concordance_correlation(&b.view());
+    let _ = a.bland_altman(&b.view());
 }
 
 #[test]
@@ -56,6 +59,121 @@ fn test_count_neq() -> Result<(), MultiInputError> {
     Ok(())
 }
 
+#[test]
+fn test_count_close() -> Result<(), MultiInputError> {
+    let a = array![1., 1e-3, 100., f64::NAN, f64::INFINITY, -2.];
+    let b = array![1. + 1e-12, 2e-3, 100.5, f64::NAN, f64::INFINITY, -2.];
+
+    assert_eq!(a.count_eq(&b)?, 2);
+    assert_eq!(a.count_close(&b, Tolerance::Absolute(1e-2))?, 4);
+    assert_eq!(a.count_close(&b, Tolerance::Relative(1e-2))?, 4);
+    assert_eq!(a.count_close(&b, Tolerance::Relative(0.6))?, 5);
+    assert_eq!(
+        a.count_close(
+            &b,
+            Tolerance::AbsoluteOrRelative {
+                absolute: 1e-2,
+                relative: 1e-2
+            }
+        )?,
+        5
+    );
+    assert_eq!(a.count_not_close(&b, Tolerance::Absolute(1e-2))?, 2);
+    assert_eq!(a.count_not_close(&b, Tolerance::Absolute(0.))?, 4);
+
+    Ok(())
+}
+
+#[test]
+fn test_tolerance_in_ulps() {
+    let close = |a: f64, b: f64, ulps| Tolerance::Ulps(ulps).is_close(a, b);
+    let one_up = f64::from_bits(1f64.to_bits() + 1);
+    assert!(close(1., one_up, 1));
+    assert!(!close(1., one_up, 0));
+    assert!(close(0.1 + 0.2, 0.3, 1));
+    // Across a power of two
+    let below_two = f64::from_bits(2f64.to_bits() - 1);
+    let above_two = f64::from_bits(2f64.to_bits() + 1);
+    assert!(close(below_two, above_two, 2));
+    assert!(!close(below_two, above_two, 1));
+    // Across zero and subnormal numbers
+    let tiny = f64::from_bits(1);
+    assert!(close(0., -0., 0));
+    assert!(close(-0., tiny, 1));
+    assert!(close(-tiny, tiny, 2));
+    assert!(!close(-tiny, tiny, 1));
+    assert!(close(
+        f64::MIN_POSITIVE,
+        f64::from_bits(f64::MIN_POSITIVE.to_bits() - 1),
+        1
+    ));
+    assert!(!close(f64::MAX, f64::INFINITY, 1));
+    assert!(close(f64::INFINITY, f64::INFINITY, 0));
+    assert!(!close(f64::NEG_INFINITY, f64::INFINITY, u64::MAX));
+    assert!(!close(f64::NAN, f64::NAN, u64::MAX));
+    assert!(close(f64::MIN, f64::MAX, u64::MAX));
+
+    let one_up = f32::from_bits(1f32.to_bits() + 1);
+    assert!(Tolerance::Ulps(1).is_close(1f32, one_up));
+    assert!(Tolerance::Ulps(3).is_close(-f32::from_bits(1), f32::from_bits(2)));
+    assert!(!Tolerance::Ulps(2).is_close(-f32::from_bits(1), f32::from_bits(2)));
+}
+
+#[test]
+#[should_panic]
+fn test_negative_tolerance_panics() {
+    let _ = Tolerance::Absolute(-1.).is_close(1., 2.);
+}
+
+#[test]
+#[should_panic]
+fn test_negative_tolerance_panics_for_equal_values() {
+    let _ = Tolerance::Relative(-1.).is_close(1., 1.);
+}
+
+#[test]
+#[should_panic]
+fn test_nan_relative_tolerance_panics_within_absolute_tolerance() {
+    let tolerance = Tolerance::AbsoluteOrRelative {
+        absolute: 1.,
+        relative: f64::NAN,
+    };
+    let _ = tolerance.is_close(1., 1.5);
+}
+
+#[test]
+fn test_mismatch_report() -> Result<(), MultiInputError> {
+    let a = array![[1., 2., 3.], [4., 5., 6.]];
+    let b = array![[1., 2.5, 3.], [f64::NAN, 5.000001, 0.]];
+    let report = a.mismatch_report(&b, Tolerance::Relative(1e-3), 2)?;
+    assert_eq!(report.n_mismatches, 3);
+    assert_eq!(report.n_elements, 6);
+    assert_eq!(report.largest.len(), 2);
+    assert_eq!(report.largest[0].index, (1, 0));
+    assert!(report.largest[0].abs_diff.is_nan());
+    assert_eq!(
+        report.largest[1],
+        Mismatch {
+            index: (1, 2),
+            value: 6.,
+            other: 0.,
+            abs_diff: 6.,
+            rel_diff: 1.,
+        }
+    );
+
+    let report = a.mismatch_report(&b, Tolerance::Relative(1e-3), 10)?;
+    assert_eq!(report.largest.len(), 3);
+    assert_eq!(report.largest[2].index, (0, 1));
+    assert_eq!(report.largest[2].rel_diff, 0.2);
+
+    let report = a.mismatch_report(&a, Tolerance::Ulps(0), 10)?;
+    assert_eq!(report.n_mismatches, 0);
+    assert!(report.largest.is_empty());
+
+    Ok(())
+}
+
 #[test]
 fn test_sq_l2_dist() -> Result<(), MultiInputError> {
     let a = array![0., 1., 4., 2.];
@@ -155,6 +273,119 @@ fn test_peak_signal_to_noise_ratio() -> Result<(), MultiInputError> {
     Ok(())
 }
 
+#[test]
+fn test_concordance_correlation() -> Result<(), MultiInputError> {
+    let a = array![1., 2., 3., 4., 5.];
+    let b = array![1.5, 2., 2.5, 4.5, 6.];
+
+    assert_abs_diff_eq!(a.concordance_correlation(&a)?, 1.);
+    assert_abs_diff_eq!(a.concordance_correlation(&b)?, 0.9292929292929292);
+    assert_abs_diff_eq!(b.concordance_correlation(&a)?, 0.9292929292929292);
+
+    // Perfectly correlated, but not in agreement
+    let c = a.mapv(|x| x + 1.);
+    assert_abs_diff_eq!(a.concordance_correlation(&c)?, 0.8);
+    let d = a.mapv(|x| -x);
+    assert_abs_diff_eq!(a.concordance_correlation(&d)?, -0.1);
+
+    Ok(())
+}
+
+#[test]
+fn test_bland_altman() -> Result<(), MultiInputError> {
+    let a = array![1., 2., 3., 4., 5.];
+    let b = array![1.5, 2., 2.5, 4.5, 6.];
+
+    let agreement = a.bland_altman(&b)?;
+    assert_abs_diff_eq!(agreement.bias, -0.3, epsilon = 1e-12);
+    assert_abs_diff_eq!(agreement.std_dev, 0.570087712549569, epsilon = 1e-12);
+    assert_abs_diff_eq!(agreement.lower_limit, -1.4173719165971552, epsilon = 1e-12);
+    assert_abs_diff_eq!(agreement.upper_limit, 0.8173719165971551, epsilon = 1e-12);
+
+    let single = array![[2]].bland_altman(&array![[1]])?;
+    assert_eq!(single.bias, 1.);
+    assert!(single.std_dev.is_nan());
+
+    Ok(())
+}
+
+#[test]
+fn test_deviations_along_axis() -> Result<(), MultiInputError> {
+    let a = array![[1., 10.], [2., 20.], [3., 30.]];
+    let b = array![[1., 12.], [2., 18.], [4., 30.]];
+
+    assert_eq!(a.count_eq_axis(&b, Axis(0))?, array![2, 1]);
+    assert_eq!(a.count_neq_axis(&b, Axis(0))?, array![1, 2]);
+    assert_eq!(a.count_eq_axis(&b, Axis(1))?, array![1, 1, 1]);
+
+    assert_eq!(a.sq_l2_dist_axis(&b, Axis(0))?, array![1., 8.]);
+    assert_abs_diff_eq!(a.l2_dist_axis(&b, Axis(0))?, array![1., 8.0.sqrt()]);
+    assert_eq!(a.l1_dist_axis(&b, Axis(0))?, array![1., 4.]);
+    assert_eq!(a.linf_dist_axis(&b, Axis(1))?, array![2., 2., 1.]);
+
+    assert_abs_diff_eq!(a.mean_abs_err_axis(&b, Axis(0))?, array![1. / 3., 4. / 3.]);
+    assert_abs_diff_eq!(a.mean_sq_err_axis(&b, Axis(1))?, array![2., 2., 0.5]);
+    assert_abs_diff_eq!(
+        a.root_mean_sq_err_axis(&b, Axis(1))?,
+        array![2.0.sqrt(), 2.0.sqrt(), 0.5.sqrt()]
+    );
+    assert_abs_diff_eq!(
+        a.peak_signal_to_noise_ratio_axis(&b, 30., Axis(0))?,
+        array![
+            a.column(0).peak_signal_to_noise_ratio(&b.column(0), 30.)?,
+            a.column(1).peak_signal_to_noise_ratio(&b.column(1), 30.)?
+        ]
+    );
+    assert_abs_diff_eq!(
+        a.concordance_correlation_axis(&b, Axis(0))?,
+        array![
+            a.column(0).concordance_correlation(&b.column(0))?,
+            a.column(1).concordance_correlation(&b.column(1))?
+        ]
+    );
+    let agreement = a.bland_altman_axis(&b, Axis(0))?;
+    assert_eq!(agreement.len(), 2);
+    assert_eq!(agreement[1], a.column(1).bland_altman(&b.column(1))?);
+
+    Ok(())
+}
+
+#[test]
+fn test_deviations_along_axis_of_3d_arrays() -> Result<(), MultiInputError> {
+    let a = Array3::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 12 + j * 4 + k) as i32);
+    let b = Array3::<i32>::zeros((2, 3, 4));
+
+    for axis in 0..3 {
+        let l1 = a.l1_dist_axis(&b, Axis(axis))?;
+        let expected = a.sum_axis(Axis(axis));
+        assert_eq!(l1, expected);
+    }
+    // Non-standard memory layout
+    let t = a.t();
+    let bt = b.t();
+    assert_eq!(t.l1_dist_axis(&bt, Axis(1))?, t.sum_axis(Axis(1)));
+
+    Ok(())
+}
+
+#[test]
+fn test_deviations_along_axis_errors() {
+    let a = Array2::<f64>::zeros((2, 0));
+    assert_eq!(
+        a.mean_sq_err_axis(&a, Axis(0)),
+        Err(MultiInputError::EmptyInput)
+    );
+    let b = Array2::<f64>::zeros((2, 3));
+    let c = Array2::<f64>::zeros((3, 2));
+    assert_eq!(
+        b.l1_dist_axis(&c, Axis(1)),
+        Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+            first_shape: vec![2, 3],
+            second_shape: vec![3, 2],
+        }))
+    );
+}
+
 #[test]
 fn test_deviations_with_n_by_m_ints() -> Result<(), MultiInputError> {
     let a = array![[0, 1], [4, 2]];
@@ -183,6 +414,18 @@ fn test_deviations_with_empty_receiver() {
 
     assert_eq!(a.count_eq(&b), Err(MultiInputError::EmptyInput));
     assert_eq!(a.count_neq(&b), Err(MultiInputError::EmptyInput));
+    assert_eq!(
+        a.count_close(&b, Tolerance::Absolute(1.)),
+        Err(MultiInputError::EmptyInput)
+    );
+    assert_eq!(
+        a.count_not_close(&b, Tolerance::Absolute(1.)),
+        Err(MultiInputError::EmptyInput)
+    );
+    assert_eq!(
+        a.mismatch_report(&b, Tolerance::Absolute(1.), 1),
+        Err(MultiInputError::EmptyInput)
+    );
 
     assert_eq!(a.sq_l2_dist(&b), Err(MultiInputError::EmptyInput));
     assert_eq!(a.l2_dist(&b), Err(MultiInputError::EmptyInput));
@@ -196,6 +439,11 @@ fn test_deviations_with_empty_receiver() {
         a.peak_signal_to_noise_ratio(&b, 0.),
         Err(MultiInputError::EmptyInput)
     );
+    assert_eq!(
+        a.concordance_correlation(&b),
+        Err(MultiInputError::EmptyInput)
+    );
+    assert_eq!(a.bland_altman(&b), Err(MultiInputError::EmptyInput));
 }
 
 #[test]
@@ -233,6 +481,15 @@ fn test_deviations_with_empty_argument() {
 
     assert_eq!(a.count_eq(&b), expected_err_usize);
     assert_eq!(a.count_neq(&b), expected_err_usize);
+    assert_eq!(a.count_close(&b, Tolerance::Ulps(1)), expected_err_usize);
+    assert_eq!(
+        a.count_not_close(&b, Tolerance::Ulps(1)),
+        expected_err_usize
+    );
+    assert!(a
+        .mismatch_report(&b, Tolerance::Ulps(1), 1)
+        .unwrap_err()
+        .is_shape_mismatch());
 
     assert_eq!(a.sq_l2_dist(&b), expected_err_f64);
     assert_eq!(a.l2_dist(&b), expected_err_f64);
@@ -243,6 +500,8 @@ fn test_deviations_with_empty_argument() {
     assert_eq!(a.mean_sq_err(&b), expected_err_f64);
     assert_eq!(a.root_mean_sq_err(&b), expected_err_f64);
     assert_eq!(a.peak_signal_to_noise_ratio(&b, 0.), expected_err_f64);
+    assert_eq!(a.concordance_correlation(&b), expected_err_f64);
+    assert!(a.bland_altman(&b).unwrap_err().is_shape_mismatch());
 }
 
 #[test]
@@ -0,0 +1,238 @@
+use ndarray_stats::errors::{MultiInputError, ShapeMismatch};
+use ndarray_stats::RegressionMetricsExt;
+
+use approx::assert_abs_diff_eq;
+use ndarray::{array, Array1};
+
+#[test]
+fn test_r2_score() -> Result<(), MultiInputError> {
+    let truth = array![3., -0.5, 2., 7.];
+    let prediction = array![2.5, 0., 2., 8.];
+
+    assert_abs_diff_eq!(
+        prediction.r2_score(&truth, None)?,
+        0.9486081370449679,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(truth.r2_score(&truth, None)?, 1.);
+    let mean = Array1::from_elem(4, truth.mean().unwrap());
+    assert_abs_diff_eq!(mean.r2_score(&truth, None)?, 0., epsilon = 1e-12);
+
+    let weights = array![1., 2., 0.5, 1.];
+    assert_abs_diff_eq!(
+        prediction.r2_score(&truth, Some(weights.view()))?,
+        0.9542815674891146,
+        epsilon = 1e-12
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_r2_score_with_constant_truth() -> Result<(), MultiInputError> {
+    let truth = array![2., 2., 2.];
+    assert_eq!(truth.r2_score(&truth, None)?, 1.);
+    assert_eq!(array![1., 2., 3.].r2_score(&truth, None)?, 0.);
+
+    Ok(())
+}
+
+#[test]
+fn test_adjusted_r2_score() -> Result<(), MultiInputError> {
+    let truth = array![3., -0.5, 2., 7.];
+    let prediction = array![2.5, 0., 2., 8.];
+
+    assert_abs_diff_eq!(
+        prediction.adjusted_r2_score(&truth, 1, None)?,
+        0.9229122055674519,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        prediction.adjusted_r2_score(&truth, 0, None)?,
+        prediction.r2_score(&truth, None)?,
+        epsilon = 1e-12
+    );
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_adjusted_r2_score_with_too_many_features_panics() {
+    let _ = array![1., 2., 3.].adjusted_r2_score(&array![1., 2., 4.], 2, None);
+}
+
+#[test]
+fn test_explained_variance_score() -> Result<(), MultiInputError> {
+    let truth = array![3., -0.5, 2., 7.];
+    let prediction = array![2.5, 0., 2., 8.];
+
+    assert_abs_diff_eq!(
+        prediction.explained_variance_score(&truth, None)?,
+        0.9571734475374732,
+        epsilon = 1e-12
+    );
+    let weights = array![1., 2., 0.5, 1.];
+    assert_abs_diff_eq!(
+        prediction.explained_variance_score(&truth, Some(weights.view()))?,
+        0.967343976777939,
+        epsilon = 1e-12
+    );
+    // A constant bias is not penalised
+    let biased = truth.mapv(|y| y + 1.);
+    assert_abs_diff_eq!(biased.explained_variance_score(&truth, None)?, 1.);
+
+    Ok(())
+}
+
+#[test]
+fn test_percentage_errors() -> Result<(), MultiInputError> {
+    let truth = array![3., -0.5, 2., 7.];
+    let prediction = array![2.5, 0., 2., 8.];
+    let weights = array![1., 2., 0.5, 1.];
+
+    assert_abs_diff_eq!(
+        prediction.mean_abs_percentage_err(&truth, None)?,
+        0.3273809523809524,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        prediction.mean_abs_percentage_err(&truth, Some(weights.view()))?,
+        0.5132275132275131,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        prediction.symmetric_mean_abs_percentage_err(&truth, None)?,
+        0.5787878787878787,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        prediction.symmetric_mean_abs_percentage_err(&truth, Some(weights.view()))?,
+        0.958922558922559,
+        epsilon = 1e-12
+    );
+    assert_eq!(
+        array![0., 1.].symmetric_mean_abs_percentage_err(&array![0., 1.], None)?,
+        0.
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_median_abs_err() -> Result<(), MultiInputError> {
+    let truth = array![3., -0.5, 2., 7.];
+    let prediction = array![2.5, 0., 2., 8.];
+    assert_eq!(prediction.median_abs_err(&truth, None)?, 0.5);
+
+    let zeros = array![0., 0., 0., 0.];
+    let errors = array![1., 2., 3., 4.];
+    assert_eq!(errors.median_abs_err(&zeros, None)?, 2.5);
+    let weights = array![3., 1., 1., 1.];
+    assert_eq!(errors.median_abs_err(&zeros, Some(weights.view()))?, 1.5);
+    let weights = array![1., 1., 1., 5.];
+    assert_eq!(errors.median_abs_err(&zeros, Some(weights.view()))?, 4.);
+    let weights = array![0., 1., 1., 0.];
+    assert_eq!(errors.median_abs_err(&zeros, Some(weights.view()))?, 2.5);
+
+    Ok(())
+}
+
+#[test]
+fn test_max_err() -> Result<(), MultiInputError> {
+    let truth = array![[3., 2.], [7., 1.]];
+    let prediction = array![[9., 2.], [3., 1.]];
+    assert_eq!(prediction.max_err(&truth)?, 6.);
+
+    Ok(())
+}
+
+#[test]
+fn test_mean_sq_log_err() -> Result<(), MultiInputError> {
+    let truth = array![3., 5., 2.5, 7.];
+    let prediction = array![2.5, 5., 4., 8.];
+    assert_abs_diff_eq!(
+        prediction.mean_sq_log_err(&truth, None)?,
+        0.03973012298459379,
+        epsilon = 1e-12
+    );
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_mean_sq_log_err_with_negative_values_panics() {
+    let _ = array![1., -2.].mean_sq_log_err(&array![1., 2.], None);
+}
+
+#[test]
+fn test_deviances() -> Result<(), MultiInputError> {
+    let prediction = array![0.5, 0.5, 2., 2.];
+
+    let truth = array![2., 0., 1., 4.];
+    assert_abs_diff_eq!(
+        prediction.mean_poisson_deviance(&truth, None)?,
+        1.4260151319598084,
+        epsilon = 1e-12
+    );
+    let weights = array![1., 2., 3., 4.];
+    assert_abs_diff_eq!(
+        prediction.mean_poisson_deviance(&truth, Some(weights.view()))?,
+        1.256700413903814,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        prediction.mean_tweedie_deviance(&truth, 1.5, None)?,
+        1.7781745930520232,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        prediction.mean_tweedie_deviance(&truth, 0., None)?,
+        1.875,
+        epsilon = 1e-12
+    );
+
+    let truth = array![2., 0.5, 1., 4.];
+    assert_abs_diff_eq!(
+        prediction.mean_gamma_deviance(&truth, None)?,
+        1.0568528194400546,
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(
+        prediction.mean_tweedie_deviance(&truth, 3., None)?,
+        1.25,
+        epsilon = 1e-12
+    );
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn test_gamma_deviance_with_zero_truth_panics() {
+    let _ = array![1., 2.].mean_gamma_deviance(&array![0., 2.], None);
+}
+
+#[test]
+#[should_panic]
+fn test_tweedie_deviance_with_invalid_power_panics() {
+    let _ = array![1., 2.].mean_tweedie_deviance(&array![1., 2.], 0.5, None);
+}
+
+#[test]
+fn test_regression_metrics_errors() {
+    let a: Array1<f64> = array![];
+    let b: Array1<f64> = array![1.];
+
+    assert_eq!(a.r2_score(&b, None), Err(MultiInputError::EmptyInput));
+    assert_eq!(a.max_err(&b), Err(MultiInputError::EmptyInput));
+
+    let shape_mismatch = Err(MultiInputError::ShapeMismatch(ShapeMismatch {
+        first_shape: vec![1],
+        second_shape: vec![0],
+    }));
+    assert_eq!(b.r2_score(&a, None), shape_mismatch);
+    assert_eq!(b.median_abs_err(&a, None), shape_mismatch);
+    assert_eq!(b.mean_poisson_deviance(&b, Some(a.view())), shape_mismatch);
+}